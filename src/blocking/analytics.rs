@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use tokio::runtime::Runtime;
+
+use crate::internal::analytics;
+use crate::models::analytics::{CommentsPublishedStatus, PostsPublishedStatus, UsersCreatedStatus};
+use crate::models::error::Result;
+
+/// Blocking version of [`AnalyticsApi`](crate::internal::analytics::AnalyticsApi)
+#[derive(Clone)]
+pub struct AnalyticsApi {
+    inner: analytics::AnalyticsApi,
+    runtime: Arc<Runtime>,
+}
+
+impl AnalyticsApi {
+    pub(crate) fn new(inner: analytics::AnalyticsApi, runtime: Arc<Runtime>) -> Self {
+        AnalyticsApi { inner, runtime }
+    }
+
+    /// Blocking version of [`AnalyticsApi::get_users_created`](analytics::AnalyticsApi::get_users_created)
+    pub fn get_users_created(&self) -> Result<Vec<UsersCreatedStatus>> {
+        self.runtime.block_on(self.inner.get_users_created())
+    }
+
+    /// Blocking version of [`AnalyticsApi::get_posts_published`](analytics::AnalyticsApi::get_posts_published)
+    pub fn get_posts_published(&self) -> Result<Vec<PostsPublishedStatus>> {
+        self.runtime.block_on(self.inner.get_posts_published())
+    }
+
+    /// Blocking version of [`AnalyticsApi::get_comments_published`](analytics::AnalyticsApi::get_comments_published)
+    pub fn get_comments_published(&self) -> Result<Vec<CommentsPublishedStatus>> {
+        self.runtime.block_on(self.inner.get_comments_published())
+    }
+}
@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use tokio::runtime::Runtime;
+
+use crate::internal::auth;
+use crate::models::error::Result;
+use crate::models::user::UserSession;
+
+/// Blocking version of [`AuthApi`](crate::internal::auth::AuthApi)
+#[derive(Clone)]
+pub struct AuthApi {
+    inner: auth::AuthApi,
+    runtime: Arc<Runtime>,
+}
+
+impl AuthApi {
+    pub(crate) fn new(inner: auth::AuthApi, runtime: Arc<Runtime>) -> Self {
+        AuthApi { inner, runtime }
+    }
+
+    /// Blocking version of [`AuthApi::get_user_session`](auth::AuthApi::get_user_session)
+    pub fn get_user_session(&self, email: String, password: String) -> Result<UserSession> {
+        self.runtime
+            .block_on(self.inner.get_user_session(email, password))
+    }
+
+    /// Blocking version of [`AuthApi::login`](auth::AuthApi::login)
+    pub fn login(&self, email: String, password: String) -> Result<UserSession> {
+        self.runtime.block_on(self.inner.login(email, password))
+    }
+}
@@ -0,0 +1,125 @@
+//! Synchronous mirror of [`TabnewsClient`](crate::TabnewsClient).
+//!
+//! Every method blocks the current thread until the request completes.
+//! The blocking client drives the async client on its own tokio runtime,
+//! so it shares the models, the [`Error`](crate::Error) type and every
+//! [`HttpClient`] setting (retries, rate limiting, cache...).
+//!
+//! Only available with the `blocking` feature.
+//!
+//! # Panics
+//!
+//! The blocking methods must not be called from within an async runtime.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # use tabnews::blocking::TabnewsClient;
+//! # use tabnews::internal::pagination::Page;
+//! # use tabnews::models::content::Content;
+//! # fn main() -> Result<(), tabnews::Error> {
+//! let client = TabnewsClient::new()?;
+//!
+//! let posts: Page<Content> = client.posts_api.get_homepage_posts(None)?;
+//!
+//! assert!(posts.len() >= 0);
+//! # Ok(())
+//! # }
+//! ```
+
+mod analytics;
+mod auth;
+mod pagination;
+mod posts;
+mod user;
+mod users;
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use tokio::runtime::Runtime;
+
+use crate::internal::http_client::HttpClient;
+use crate::models::error::Result;
+
+pub use analytics::AnalyticsApi;
+pub use auth::AuthApi;
+pub use pagination::Paginated;
+pub use posts::PostsApi;
+pub use user::UserApi;
+pub use users::UsersApi;
+
+/// Blocking version of [`TabnewsClient`](crate::TabnewsClient)
+#[derive(Clone)]
+pub struct TabnewsClient {
+    pub posts_api: PostsApi,
+    pub analytics_api: AnalyticsApi,
+    pub user_api: UserApi,
+    pub users_api: UsersApi,
+    pub auth_api: AuthApi,
+    pub http_client: Arc<HttpClient>,
+    inner: crate::TabnewsClient,
+    runtime: Arc<Runtime>,
+}
+
+impl TabnewsClient {
+    /// Creates a blocking client with the default settings
+    pub fn new() -> Result<Self> {
+        TabnewsClient::from_async(crate::TabnewsClient::default())
+    }
+
+    /// Creates a blocking client that also sends `headers`
+    pub fn with_headers(headers: HashMap<String, String>) -> Result<Self> {
+        TabnewsClient::from_async(crate::TabnewsClient::new(headers)?)
+    }
+
+    /// Creates a blocking client whose API groups share `client`
+    pub fn from_http_client(client: Arc<HttpClient>) -> Result<Self> {
+        TabnewsClient::from_async(crate::TabnewsClient::from_http_client(client))
+    }
+
+    /// Wraps an async client, sharing its [`HttpClient`]
+    pub fn from_async(client: crate::TabnewsClient) -> Result<Self> {
+        let runtime = Arc::new(
+            tokio::runtime::Builder::new_multi_thread()
+                .worker_threads(1)
+                .thread_name("tabnews-blocking")
+                .enable_all()
+                .build()?,
+        );
+
+        Ok(TabnewsClient {
+            posts_api: PostsApi::new(client.posts_api.clone(), Arc::clone(&runtime)),
+            analytics_api: AnalyticsApi::new(client.analytics_api.clone(), Arc::clone(&runtime)),
+            user_api: UserApi::new(client.user_api.clone(), Arc::clone(&runtime)),
+            users_api: UsersApi::new(client.users_api.clone(), Arc::clone(&runtime)),
+            auth_api: AuthApi::new(client.auth_api.clone(), Arc::clone(&runtime)),
+            http_client: Arc::clone(&client.http_client),
+            inner: client,
+            runtime,
+        })
+    }
+
+    /// Blocking version of [`TabnewsClient::use_preview_url`](crate::TabnewsClient::use_preview_url)
+    pub fn use_preview_url(self) -> Result<Self> {
+        let inner = self
+            .runtime
+            .block_on(self.inner.clone().use_preview_url())?;
+
+        Ok(TabnewsClient { inner, ..self })
+    }
+
+    pub fn is_logged(&self) -> bool {
+        self.inner.is_logged()
+    }
+
+    /// Blocking version of [`TabnewsClient::trace_configuration`](crate::TabnewsClient::trace_configuration)
+    pub fn trace_configuration(&self) {
+        self.inner.trace_configuration()
+    }
+
+    #[deprecated(note = "use `trace_configuration`, which goes through `tracing`")]
+    pub fn debug_dump_host(&self) {
+        self.inner.trace_configuration()
+    }
+}
@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use futures::StreamExt;
+use tokio::runtime::Runtime;
+
+use crate::internal::pagination;
+use crate::models::error::Result;
+
+/// Blocking version of [`Paginated`](crate::internal::pagination::Paginated),
+/// iterating over every item of a paginated listing
+pub struct Paginated<T> {
+    inner: pagination::Paginated<T>,
+    runtime: Arc<Runtime>,
+}
+
+impl<T: Send + 'static> Paginated<T> {
+    pub(crate) fn new(inner: pagination::Paginated<T>, runtime: Arc<Runtime>) -> Self {
+        Paginated { inner, runtime }
+    }
+
+    /// See [`Paginated::start_page`](pagination::Paginated::start_page)
+    pub fn start_page(self, page: u64) -> Self {
+        Paginated {
+            inner: self.inner.start_page(page),
+            ..self
+        }
+    }
+
+    /// See [`Paginated::per_page`](pagination::Paginated::per_page)
+    pub fn per_page(self, per_page: u64) -> Self {
+        Paginated {
+            inner: self.inner.per_page(per_page),
+            ..self
+        }
+    }
+
+    /// See [`Paginated::prefetch`](pagination::Paginated::prefetch)
+    pub fn prefetch(self, prefetch: bool) -> Self {
+        Paginated {
+            inner: self.inner.prefetch(prefetch),
+            ..self
+        }
+    }
+
+    /// See [`Paginated::limit`](pagination::Paginated::limit)
+    pub fn limit(self, limit: usize) -> Self {
+        Paginated {
+            inner: self.inner.limit(limit),
+            ..self
+        }
+    }
+}
+
+impl<T: Send + 'static> Iterator for Paginated<T> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.runtime.block_on(self.inner.next())
+    }
+}
@@ -0,0 +1,206 @@
+use std::sync::Arc;
+
+use tokio::runtime::Runtime;
+
+use crate::internal::pagination::Page;
+use crate::internal::posts;
+use crate::models::ancestors::{Ancestors, KnownContents};
+use crate::models::comment_tree::CommentTree;
+use crate::models::content::{Content, ContentPatch, ListingParams, Tabcoins};
+use crate::models::error::Result;
+use crate::models::rss::RssFeed;
+use crate::models::thumbnail::Thumbnail;
+
+use super::Paginated;
+
+/// Blocking version of [`PostsApi`](crate::internal::posts::PostsApi)
+#[derive(Clone)]
+pub struct PostsApi {
+    inner: posts::PostsApi,
+    runtime: Arc<Runtime>,
+}
+
+impl PostsApi {
+    pub(crate) fn new(inner: posts::PostsApi, runtime: Arc<Runtime>) -> Self {
+        PostsApi { inner, runtime }
+    }
+
+    /// Blocking version of [`PostsApi::get_homepage_posts`](posts::PostsApi::get_homepage_posts)
+    pub fn get_homepage_posts(&self, params: impl Into<ListingParams>) -> Result<Page<Content>> {
+        self.runtime.block_on(self.inner.get_homepage_posts(params))
+    }
+
+    /// Blocking version of [`PostsApi::get_posts_by_user`](posts::PostsApi::get_posts_by_user)
+    pub fn get_posts_by_user(
+        &self,
+        username: &str,
+        params: impl Into<ListingParams>,
+    ) -> Result<Page<Content>> {
+        self.runtime
+            .block_on(self.inner.get_posts_by_user(username, params))
+    }
+
+    /// Blocking version of [`PostsApi::stream_homepage_posts`](posts::PostsApi::stream_homepage_posts)
+    pub fn iter_homepage_posts(&self, params: impl Into<ListingParams>) -> Paginated<Content> {
+        Paginated::new(
+            self.inner.stream_homepage_posts(params),
+            Arc::clone(&self.runtime),
+        )
+    }
+
+    /// Blocking version of [`PostsApi::stream_posts_by_user`](posts::PostsApi::stream_posts_by_user)
+    pub fn iter_posts_by_user(
+        &self,
+        username: &str,
+        params: impl Into<ListingParams>,
+    ) -> Paginated<Content> {
+        Paginated::new(
+            self.inner.stream_posts_by_user(username, params),
+            Arc::clone(&self.runtime),
+        )
+    }
+
+    /// Blocking version of [`PostsApi::get_post_details`](posts::PostsApi::get_post_details)
+    pub fn get_post_details(&self, username: &str, slug: &str) -> Result<Content> {
+        self.runtime
+            .block_on(self.inner.get_post_details(username, slug))
+    }
+
+    /// Blocking version of [`PostsApi::get_post_comments`](posts::PostsApi::get_post_comments)
+    pub fn get_post_comments(&self, username: &str, slug: &str) -> Result<Vec<Content>> {
+        self.runtime
+            .block_on(self.inner.get_post_comments(username, slug))
+    }
+
+    /// Blocking version of [`PostsApi::get_comment_tree`](posts::PostsApi::get_comment_tree)
+    pub fn get_comment_tree(&self, username: &str, slug: &str) -> Result<CommentTree> {
+        self.runtime
+            .block_on(self.inner.get_comment_tree(username, slug))
+    }
+
+    /// Blocking version of [`PostsApi::get_post_thumbnail`](posts::PostsApi::get_post_thumbnail)
+    pub fn get_post_thumbnail(&self, username: &str, slug: &str) -> Result<Thumbnail> {
+        self.runtime
+            .block_on(self.inner.get_post_thumbnail(username, slug))
+    }
+
+    /// Blocking version of [`PostsApi::get_thumbnails`](posts::PostsApi::get_thumbnails)
+    pub fn get_thumbnails(
+        &self,
+        contents: &[Content],
+        concurrency: usize,
+    ) -> Vec<Result<Thumbnail>> {
+        self.runtime
+            .block_on(self.inner.get_thumbnails(contents, concurrency))
+    }
+
+    /// Blocking version of [`PostsApi::get_post_parent`](posts::PostsApi::get_post_parent)
+    pub fn get_post_parent(&self, username: &str, slug: &str) -> Result<Content> {
+        self.runtime
+            .block_on(self.inner.get_post_parent(username, slug))
+    }
+
+    /// Blocking version of [`PostsApi::get_post_root`](posts::PostsApi::get_post_root)
+    pub fn get_post_root(&self, username: &str, slug: &str) -> Result<Content> {
+        self.runtime
+            .block_on(self.inner.get_post_root(username, slug))
+    }
+
+    /// Blocking version of [`PostsApi::get_ancestors`](posts::PostsApi::get_ancestors)
+    pub fn get_ancestors(&self, username: &str, slug: &str) -> Result<Ancestors> {
+        self.runtime
+            .block_on(self.inner.get_ancestors(username, slug))
+    }
+
+    /// Blocking version of [`PostsApi::get_ancestors_with`](posts::PostsApi::get_ancestors_with)
+    pub fn get_ancestors_with(
+        &self,
+        username: &str,
+        slug: &str,
+        known: &mut KnownContents,
+    ) -> Result<Ancestors> {
+        self.runtime
+            .block_on(self.inner.get_ancestors_with(username, slug, known))
+    }
+
+    /// Blocking version of [`PostsApi::get_post_tabcoins`](posts::PostsApi::get_post_tabcoins)
+    pub fn get_post_tabcoins(&self, username: &str, slug: &str) -> Result<i64> {
+        self.runtime
+            .block_on(self.inner.get_post_tabcoins(username, slug))
+    }
+
+    /// Blocking version of [`PostsApi::downvote`](posts::PostsApi::downvote)
+    pub fn downvote(&self, username: &str, slug: &str) -> Result<Tabcoins> {
+        self.runtime.block_on(self.inner.downvote(username, slug))
+    }
+
+    /// Blocking version of [`PostsApi::upvote`](posts::PostsApi::upvote)
+    pub fn upvote(&self, username: &str, slug: &str) -> Result<Tabcoins> {
+        self.runtime.block_on(self.inner.upvote(username, slug))
+    }
+
+    /// Blocking version of [`PostsApi::get_rss`](posts::PostsApi::get_rss)
+    pub fn get_rss(&self) -> Result<String> {
+        self.runtime.block_on(self.inner.get_rss())
+    }
+
+    /// Blocking version of [`PostsApi::get_rss_feed`](posts::PostsApi::get_rss_feed)
+    pub fn get_rss_feed(&self) -> Result<RssFeed> {
+        self.runtime.block_on(self.inner.get_rss_feed())
+    }
+
+    /// Blocking version of [`PostsApi::publish_post`](posts::PostsApi::publish_post)
+    pub fn publish_post(&self, content: Content) -> Result<Content> {
+        self.runtime.block_on(self.inner.publish_post(content))
+    }
+
+    /// Blocking version of [`PostsApi::publish_comment`](posts::PostsApi::publish_comment)
+    pub fn publish_comment(&self, content: Content) -> Result<Content> {
+        self.runtime.block_on(self.inner.publish_comment(content))
+    }
+
+    /// Blocking version of [`PostsApi::update_content`](posts::PostsApi::update_content)
+    pub fn update_content(
+        &self,
+        username: &str,
+        slug: &str,
+        patch: ContentPatch,
+    ) -> Result<Content> {
+        self.runtime
+            .block_on(self.inner.update_content(username, slug, patch))
+    }
+
+    /// Blocking version of [`PostsApi::delete_content`](posts::PostsApi::delete_content)
+    pub fn delete_content(&self, username: &str, slug: &str) -> Result<Content> {
+        self.runtime
+            .block_on(self.inner.delete_content(username, slug))
+    }
+
+    /// Blocking version of [`PostsApi::unpublish_content`](posts::PostsApi::unpublish_content)
+    pub fn unpublish_content(&self, username: &str, slug: &str) -> Result<Content> {
+        self.runtime
+            .block_on(self.inner.unpublish_content(username, slug))
+    }
+
+    /// Blocking version of [`PostsApi::create_draft`](posts::PostsApi::create_draft)
+    pub fn create_draft(&self, content: Content) -> Result<Content> {
+        self.runtime.block_on(self.inner.create_draft(content))
+    }
+
+    /// Blocking version of [`PostsApi::list_drafts`](posts::PostsApi::list_drafts)
+    pub fn list_drafts(&self, params: impl Into<ListingParams>) -> Result<Page<Content>> {
+        self.runtime.block_on(self.inner.list_drafts(params))
+    }
+
+    /// Blocking version of [`PostsApi::preview_draft`](posts::PostsApi::preview_draft)
+    pub fn preview_draft(&self, username: &str, slug: &str) -> Result<Content> {
+        self.runtime
+            .block_on(self.inner.preview_draft(username, slug))
+    }
+
+    /// Blocking version of [`PostsApi::publish_draft`](posts::PostsApi::publish_draft)
+    pub fn publish_draft(&self, username: &str, slug: &str) -> Result<Content> {
+        self.runtime
+            .block_on(self.inner.publish_draft(username, slug))
+    }
+}
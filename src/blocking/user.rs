@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use tokio::runtime::Runtime;
+
+use crate::internal::user;
+use crate::models::error::Result;
+use crate::models::user::User;
+
+/// Blocking version of [`UserApi`](crate::internal::user::UserApi)
+#[derive(Clone)]
+pub struct UserApi {
+    inner: user::UserApi,
+    runtime: Arc<Runtime>,
+}
+
+impl UserApi {
+    pub(crate) fn new(inner: user::UserApi, runtime: Arc<Runtime>) -> Self {
+        UserApi { inner, runtime }
+    }
+
+    /// Blocking version of [`UserApi::get_current_user`](user::UserApi::get_current_user)
+    pub fn get_current_user(&self) -> Result<User> {
+        self.runtime.block_on(self.inner.get_current_user())
+    }
+}
@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use tokio::runtime::Runtime;
+
+use crate::internal::pagination::Page;
+use crate::internal::users;
+use crate::models::error::Result;
+use crate::models::user::User;
+
+use super::Paginated;
+
+/// Blocking version of [`UsersApi`](crate::internal::users::UsersApi)
+#[derive(Clone)]
+pub struct UsersApi {
+    inner: users::UsersApi,
+    runtime: Arc<Runtime>,
+}
+
+impl UsersApi {
+    pub(crate) fn new(inner: users::UsersApi, runtime: Arc<Runtime>) -> Self {
+        UsersApi { inner, runtime }
+    }
+
+    /// Blocking version of [`UsersApi::list_all_users`](users::UsersApi::list_all_users)
+    pub fn list_all_users(&self) -> Result<Page<User>> {
+        self.runtime.block_on(self.inner.list_all_users())
+    }
+
+    /// Blocking version of [`UsersApi::list_users_page`](users::UsersApi::list_users_page)
+    pub fn list_users_page(&self, page: u64, per_page: u64) -> Result<Page<User>> {
+        self.runtime
+            .block_on(self.inner.list_users_page(page, per_page))
+    }
+
+    /// Blocking version of [`UsersApi::stream_all_users`](users::UsersApi::stream_all_users)
+    pub fn iter_all_users(&self) -> Paginated<User> {
+        Paginated::new(self.inner.stream_all_users(), Arc::clone(&self.runtime))
+    }
+
+    /// Blocking version of [`UsersApi::get_user`](users::UsersApi::get_user)
+    pub fn get_user(&self, username: &str) -> Result<User> {
+        self.runtime.block_on(self.inner.get_user(username))
+    }
+}
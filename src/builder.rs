@@ -0,0 +1,323 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::{
+    header::{HeaderName, HeaderValue, USER_AGENT},
+    Certificate, Client, Proxy, Url,
+};
+
+use crate::internal::http_client::{
+    cache::HttpCache, middleware::Middleware, rate_limit::RateLimiter, retry::RetryPolicy,
+    HttpClient, ReqwestTransport, Transport,
+};
+use crate::models::error::{Error, Result};
+use crate::TabnewsClient;
+
+const DEFAULT_BASE_URL: &str = "https://www.tabnews.com.br/api/v1";
+const DEFAULT_USER_AGENT: &str = concat!("Tabnews-RS/", env!("CARGO_PKG_VERSION"));
+
+/// Builder of a configured [`TabnewsClient`], created by [`TabnewsClient::builder`].
+///
+/// The settings are only validated by [`TabnewsClientBuilder::build`],
+/// which returns [`Error::InvalidInput`] or [`Error::InvalidHeader`]
+/// instead of panicking.
+///
+/// # Examples
+///
+/// ```
+/// # use std::time::Duration;
+/// # use tabnews::{Error, TabnewsClient};
+/// # fn main() -> Result<(), Error> {
+/// let client = TabnewsClient::builder()
+///     .base_url("https://www.tabnews.com.br/api/v1")
+///     .connect_timeout(Duration::from_secs(5))
+///     .request_timeout(Duration::from_secs(30))
+///     .user_agent("my-app/1.0")
+///     .default_header("Accept-Language", "pt-BR")
+///     .build()?;
+///
+/// assert!(!client.is_logged());
+/// # Ok(())
+/// # }
+/// ```
+pub struct TabnewsClientBuilder {
+    base_url: String,
+    connect_timeout: Option<Duration>,
+    request_timeout: Option<Duration>,
+    user_agent: String,
+    proxy: Option<String>,
+    root_certificates: Vec<Vec<u8>>,
+    headers: Vec<(String, String)>,
+    session_token: Option<String>,
+    transport: Option<Arc<dyn Transport>>,
+    retry_policy: Option<RetryPolicy>,
+    rate_limiter: Option<RateLimiter>,
+    cache: Option<HttpCache>,
+    middlewares: Vec<Arc<dyn Middleware>>,
+}
+
+impl Default for TabnewsClientBuilder {
+    fn default() -> Self {
+        TabnewsClientBuilder {
+            base_url: DEFAULT_BASE_URL.to_owned(),
+            connect_timeout: None,
+            request_timeout: None,
+            user_agent: DEFAULT_USER_AGENT.to_owned(),
+            proxy: None,
+            root_certificates: Vec::new(),
+            headers: Vec::new(),
+            session_token: None,
+            transport: None,
+            retry_policy: None,
+            rate_limiter: None,
+            cache: None,
+            middlewares: Vec::new(),
+        }
+    }
+}
+
+impl TabnewsClientBuilder {
+    /// Sets the url every path is appended to, `https://www.tabnews.com.br/api/v1` by default
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+
+        self
+    }
+
+    /// Sets how long to wait for the connection to the server
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+
+        self
+    }
+
+    /// Sets how long a whole request can take, from connecting until its
+    /// body is read, including the connect timeout
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+
+        self
+    }
+
+    /// Sets the `User-Agent` header, `Tabnews-RS/<version>` by default
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = user_agent.into();
+
+        self
+    }
+
+    /// Sends every request through the proxy at `url`, such as `http://localhost:8080`
+    pub fn proxy(mut self, url: impl Into<String>) -> Self {
+        self.proxy = Some(url.into());
+
+        self
+    }
+
+    /// Trusts the PEM encoded certificate `pem` in addition to the system ones
+    pub fn add_root_certificate(mut self, pem: impl Into<Vec<u8>>) -> Self {
+        self.root_certificates.push(pem.into());
+
+        self
+    }
+
+    /// Adds a header sent with every request
+    pub fn default_header(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((key.into(), value.into()));
+
+        self
+    }
+
+    /// Adds headers sent with every request
+    pub fn default_headers(mut self, headers: HashMap<String, String>) -> Self {
+        self.headers.extend(headers);
+
+        self
+    }
+
+    /// Starts the client logged in with the `token` of an existing
+    /// [`UserSession`](crate::models::user::UserSession)
+    pub fn session_token(mut self, token: impl Into<String>) -> Self {
+        self.session_token = Some(token.into());
+
+        self
+    }
+
+    /// Sends the requests through `transport` instead of [`ReqwestTransport`].
+    ///
+    /// The timeouts, the proxy and the root certificates only apply to
+    /// [`ReqwestTransport`], so they can't be combined with a custom transport.
+    pub fn transport(mut self, transport: Arc<dyn Transport>) -> Self {
+        self.transport = Some(transport);
+
+        self
+    }
+
+    /// Sets the [`RetryPolicy`] of the client
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+
+        self
+    }
+
+    /// Sets the [`RateLimiter`] of the client
+    pub fn rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+
+        self
+    }
+
+    /// Sets the [`HttpCache`] of the client
+    pub fn cache(mut self, cache: HttpCache) -> Self {
+        self.cache = Some(cache);
+
+        self
+    }
+
+    /// Adds `middleware` at the end of the middleware chain of the client
+    pub fn middleware<M>(mut self, middleware: M) -> Self
+    where
+        M: Middleware + 'static,
+    {
+        self.middlewares.push(Arc::new(middleware));
+
+        self
+    }
+
+    /// Validates the settings and creates the [`TabnewsClient`]
+    pub fn build(self) -> Result<TabnewsClient> {
+        let base_url = validate_base_url(&self.base_url)?;
+
+        for (name, timeout) in [
+            ("connect timeout", self.connect_timeout),
+            ("request timeout", self.request_timeout),
+        ] {
+            if timeout == Some(Duration::ZERO) {
+                return Err(Error::InvalidInput(format!("{} can't be zero", name)));
+            }
+        }
+
+        let transport: Arc<dyn Transport> = match self.transport {
+            Some(_)
+                if self.connect_timeout.is_some()
+                    || self.request_timeout.is_some()
+                    || self.proxy.is_some()
+                    || !self.root_certificates.is_empty() =>
+            {
+                return Err(Error::InvalidInput(
+                    "timeouts, proxy and root certificates can't be used with a custom transport"
+                        .to_owned(),
+                ))
+            }
+            Some(transport) => transport,
+            None => Arc::new(ReqwestTransport::new(reqwest_client(
+                self.connect_timeout,
+                self.request_timeout,
+                self.proxy.as_deref(),
+                &self.root_certificates,
+            )?)),
+        };
+
+        let client = HttpClient::with_transport(base_url, transport);
+
+        let user_agent = HeaderValue::try_from(self.user_agent)
+            .map_err(|_| Error::InvalidHeader("invalid User-Agent".to_owned()))?;
+        client.add_header(USER_AGENT, user_agent);
+
+        for (key, value) in self.headers {
+            client.add_header(HeaderName::try_from(key)?, HeaderValue::try_from(value)?);
+        }
+
+        if let Some(token) = self.session_token {
+            let valid = !token.is_empty()
+                && token
+                    .chars()
+                    .all(|c| c.is_ascii_graphic() && c != ';' && c != ',');
+
+            if !valid {
+                return Err(Error::InvalidInput(
+                    "session token must be a non-empty cookie value".to_owned(),
+                ));
+            }
+
+            client.set_session_token(&token)?;
+        }
+
+        if let Some(policy) = self.retry_policy {
+            client.set_retry_policy(policy);
+        }
+
+        if let Some(rate_limiter) = self.rate_limiter {
+            client.set_rate_limiter(rate_limiter);
+        }
+
+        if let Some(cache) = self.cache {
+            client.set_cache(cache);
+        }
+
+        for middleware in self.middlewares {
+            client.add_middleware(middleware);
+        }
+
+        Ok(TabnewsClient::from_http_client(Arc::new(client)))
+    }
+}
+
+fn validate_base_url(base_url: &str) -> Result<String> {
+    let url = Url::parse(base_url)
+        .map_err(|err| Error::InvalidInput(format!("invalid base url {}: {}", base_url, err)))?;
+
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(Error::InvalidInput(format!(
+            "base url {} must use http or https",
+            base_url
+        )));
+    }
+
+    if url.query().is_some() || url.fragment().is_some() {
+        return Err(Error::InvalidInput(format!(
+            "base url {} can't have a query or a fragment",
+            base_url
+        )));
+    }
+
+    Ok(base_url.trim_end_matches('/').to_owned())
+}
+
+fn reqwest_client(
+    connect_timeout: Option<Duration>,
+    request_timeout: Option<Duration>,
+    proxy: Option<&str>,
+    root_certificates: &[Vec<u8>],
+) -> Result<Client> {
+    let mut builder = Client::builder();
+
+    if let Some(timeout) = connect_timeout {
+        builder = builder.connect_timeout(timeout);
+    }
+
+    if let Some(timeout) = request_timeout {
+        builder = builder.timeout(timeout);
+    }
+
+    if let Some(proxy) = proxy {
+        Url::parse(proxy)
+            .map_err(|err| Error::InvalidInput(format!("invalid proxy url {}: {}", proxy, err)))?;
+
+        let proxy = Proxy::all(proxy)
+            .map_err(|err| Error::InvalidInput(format!("invalid proxy url {}: {}", proxy, err)))?;
+
+        builder = builder.proxy(proxy);
+    }
+
+    for pem in root_certificates {
+        let certificate = Certificate::from_pem(pem)
+            .map_err(|err| Error::InvalidInput(format!("invalid root certificate: {}", err)))?;
+
+        builder = builder.add_root_certificate(certificate);
+    }
+
+    builder
+        .build()
+        .map_err(|err| Error::InvalidInput(format!("invalid client settings: {}", err)))
+}
@@ -0,0 +1,432 @@
+//! Atom 1.0, RSS 2.0 and JSON Feed 1.1 documents built from lists of [`Content`]
+//!
+//! TabNews only publishes a feed of the whole site, [`Feed`] builds any other,
+//! such as the posts of a single author.
+
+use std::fmt::Write;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use quick_xml::escape::escape;
+use serde::Serialize;
+
+use crate::internal::dates::{civil_from_days, days_from_civil, days_in_month};
+use crate::models::content::{Content, ContentStatus};
+
+const DEFAULT_SITE_URL: &str = "https://www.tabnews.com.br";
+const DEFAULT_SUMMARY_LENGTH: usize = 280;
+const JSON_FEED_VERSION: &str = "https://jsonfeed.org/version/1.1";
+
+/// Feed of a list of contents
+///
+/// Only published contents are listed. Contents without `owner_username`
+/// or `slug`, which have no permalink, are left out too.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use tabnews::feed::Feed;
+/// # use tabnews::TabnewsClient;
+/// # use tabnews::Error;
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Error> {
+/// let client = TabnewsClient::default();
+///
+/// let posts = client.posts_api.get_posts_by_user("fadiinho", None).await?;
+///
+/// let feed = Feed::new("Posts de fadiinho", posts.into_items())
+///     .feed_url("https://example.com/fadiinho.xml")
+///     .summary_length(140);
+///
+/// std::fs::write("fadiinho.xml", feed.to_atom())?;
+/// std::fs::write("fadiinho.json", feed.to_json_feed())?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Feed {
+    title: String,
+    description: Option<String>,
+    site_url: String,
+    feed_url: Option<String>,
+    summary_length: usize,
+    contents: Vec<Content>,
+}
+
+impl Feed {
+    pub fn new(title: impl Into<String>, contents: Vec<Content>) -> Self {
+        Feed {
+            title: title.into(),
+            description: None,
+            site_url: DEFAULT_SITE_URL.to_owned(),
+            feed_url: None,
+            summary_length: DEFAULT_SUMMARY_LENGTH,
+            contents,
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+
+        self
+    }
+
+    /// Base of the permalinks, `https://www.tabnews.com.br` by default
+    pub fn site_url(mut self, site_url: impl Into<String>) -> Self {
+        self.site_url = site_url.into().trim_end_matches('/').to_owned();
+
+        self
+    }
+
+    /// Address the feed itself is published at
+    pub fn feed_url(mut self, feed_url: impl Into<String>) -> Self {
+        self.feed_url = Some(feed_url.into());
+
+        self
+    }
+
+    /// Maximum number of characters of the summaries, 280 by default.
+    ///
+    /// Summaries are the [`Content::excerpt`] of the body.
+    pub fn summary_length(mut self, summary_length: usize) -> Self {
+        self.summary_length = summary_length;
+
+        self
+    }
+
+    /// Atom 1.0 document
+    pub fn to_atom(&self) -> String {
+        let entries = self.entries();
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+
+        xml.push_str("<feed xmlns=\"http://www.w3.org/2005/Atom\">\n");
+        element(
+            &mut xml,
+            1,
+            "id",
+            self.feed_url.as_ref().unwrap_or(&self.site_url),
+        );
+        element(&mut xml, 1, "title", &self.title);
+        if let Some(description) = &self.description {
+            element(&mut xml, 1, "subtitle", description);
+        }
+        link(&mut xml, 1, None, &self.site_url);
+        if let Some(feed_url) = &self.feed_url {
+            link(&mut xml, 1, Some("self"), feed_url);
+        }
+        let updated = entries.iter().filter_map(Entry::last_change).max();
+        element(
+            &mut xml,
+            1,
+            "updated",
+            &rfc_3339(updated.unwrap_or(UNIX_EPOCH)),
+        );
+
+        for entry in &entries {
+            xml.push_str("  <entry>\n");
+            element(&mut xml, 2, "id", &entry.url);
+            element(&mut xml, 2, "title", entry.title());
+            link(&mut xml, 2, None, &entry.url);
+            if let Some(published) = entry.published {
+                element(&mut xml, 2, "published", &rfc_3339(published));
+            }
+            let updated = entry.last_change().unwrap_or(UNIX_EPOCH);
+            element(&mut xml, 2, "updated", &rfc_3339(updated));
+            xml.push_str("    <author>\n");
+            element(&mut xml, 3, "name", entry.author);
+            element(&mut xml, 3, "uri", &entry.author_url);
+            xml.push_str("    </author>\n");
+            if let Some(source_url) = entry.source_url {
+                link(&mut xml, 2, Some("related"), source_url);
+            }
+            element(&mut xml, 2, "summary", &entry.summary);
+            if let Some(body) = entry.body {
+                let _ = writeln!(xml, "    <content type=\"text\">{}</content>", escape(body));
+            }
+            xml.push_str("  </entry>\n");
+        }
+
+        xml.push_str("</feed>\n");
+
+        xml
+    }
+
+    /// RSS 2.0 document, authors are written as `<dc:creator>`
+    pub fn to_rss(&self) -> String {
+        let entries = self.entries();
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+
+        xml.push_str("<rss version=\"2.0\" xmlns:dc=\"http://purl.org/dc/elements/1.1/\">\n");
+        xml.push_str("  <channel>\n");
+        element(&mut xml, 2, "title", &self.title);
+        element(&mut xml, 2, "link", &self.site_url);
+        element(
+            &mut xml,
+            2,
+            "description",
+            self.description.as_deref().unwrap_or(&self.title),
+        );
+        if let Some(updated) = entries.iter().filter_map(Entry::last_change).max() {
+            element(
+                &mut xml,
+                2,
+                "lastBuildDate",
+                &httpdate::fmt_http_date(updated),
+            );
+        }
+
+        for entry in &entries {
+            xml.push_str("    <item>\n");
+            element(&mut xml, 3, "title", entry.title());
+            element(&mut xml, 3, "link", &entry.url);
+            let _ = writeln!(
+                xml,
+                "      <guid isPermaLink=\"true\">{}</guid>",
+                escape(entry.url.as_str())
+            );
+            if let Some(published) = entry.published {
+                element(&mut xml, 3, "pubDate", &httpdate::fmt_http_date(published));
+            }
+            element(&mut xml, 3, "dc:creator", entry.author);
+            element(&mut xml, 3, "description", &entry.summary);
+            xml.push_str("    </item>\n");
+        }
+
+        xml.push_str("  </channel>\n");
+        xml.push_str("</rss>\n");
+
+        xml
+    }
+
+    /// JSON Feed 1.1 document
+    pub fn to_json_feed(&self) -> String {
+        let entries = self.entries();
+
+        let feed = JsonFeed {
+            version: JSON_FEED_VERSION,
+            title: &self.title,
+            home_page_url: &self.site_url,
+            feed_url: self.feed_url.as_deref(),
+            description: self.description.as_deref(),
+            items: entries
+                .iter()
+                .map(|entry| JsonFeedItem {
+                    id: &entry.url,
+                    url: &entry.url,
+                    external_url: entry.source_url,
+                    title: entry.content.title.as_deref(),
+                    content_text: entry.body.unwrap_or_default(),
+                    summary: &entry.summary,
+                    date_published: entry.published.map(rfc_3339),
+                    date_modified: entry.updated.map(rfc_3339),
+                    authors: [JsonFeedAuthor {
+                        name: entry.author,
+                        url: &entry.author_url,
+                    }],
+                })
+                .collect(),
+        };
+
+        // Only strings and options of strings, which always serialize
+        serde_json::to_string_pretty(&feed).unwrap_or_default()
+    }
+
+    fn entries(&self) -> Vec<Entry<'_>> {
+        self.contents
+            .iter()
+            .filter(|content| matches!(content.status, None | Some(ContentStatus::Published)))
+            .filter_map(|content| {
+                let author = content.owner_username.as_deref()?;
+                let slug = content.slug.as_deref()?;
+                let author_url = format!("{}/{}", self.site_url, author);
+
+                Some(Entry {
+                    content,
+                    url: format!("{}/{}", author_url, slug),
+                    author,
+                    author_url,
+                    published: content
+                        .published_at
+                        .as_deref()
+                        .or(content.created_at.as_deref())
+                        .and_then(parse_rfc_3339),
+                    updated: content.updated_at.as_deref().and_then(parse_rfc_3339),
+                    source_url: content.source_url.as_deref(),
+                    body: content.body.as_deref(),
+                    summary: content.excerpt(self.summary_length),
+                })
+            })
+            .collect()
+    }
+}
+
+/// A content of the feed with everything the formats share
+struct Entry<'a> {
+    content: &'a Content,
+    url: String,
+    author: &'a str,
+    author_url: String,
+    published: Option<SystemTime>,
+    updated: Option<SystemTime>,
+    source_url: Option<&'a str>,
+    body: Option<&'a str>,
+    summary: String,
+}
+
+impl Entry<'_> {
+    fn last_change(&self) -> Option<SystemTime> {
+        self.updated.or(self.published)
+    }
+
+    /// Comments have no title, so their summary is used instead
+    fn title(&self) -> &str {
+        self.content.title.as_deref().unwrap_or(&self.summary)
+    }
+}
+
+#[derive(Serialize)]
+struct JsonFeed<'a> {
+    version: &'static str,
+    title: &'a str,
+    home_page_url: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    feed_url: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<&'a str>,
+    items: Vec<JsonFeedItem<'a>>,
+}
+
+#[derive(Serialize)]
+struct JsonFeedItem<'a> {
+    id: &'a str,
+    url: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_url: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<&'a str>,
+    content_text: &'a str,
+    summary: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    date_published: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    date_modified: Option<String>,
+    authors: [JsonFeedAuthor<'a>; 1],
+}
+
+#[derive(Serialize)]
+struct JsonFeedAuthor<'a> {
+    name: &'a str,
+    url: &'a str,
+}
+
+/// Writes `<name>value</name>` on its own line, indented by `depth` levels
+fn element(xml: &mut String, depth: usize, name: &str, value: &str) {
+    let _ = writeln!(
+        xml,
+        "{:indent$}<{name}>{}</{name}>",
+        "",
+        escape(value),
+        indent = depth * 2,
+        name = name
+    );
+}
+
+fn link(xml: &mut String, depth: usize, rel: Option<&str>, href: &str) {
+    let rel = rel
+        .map(|rel| format!(" rel=\"{}\"", rel))
+        .unwrap_or_default();
+
+    let _ = writeln!(
+        xml,
+        "{:indent$}<link{} href=\"{}\"/>",
+        "",
+        rel,
+        escape(href),
+        indent = depth * 2
+    );
+}
+
+/// Parses the timestamps of TabNews, such as `2023-01-01T00:00:00.000Z`
+fn parse_rfc_3339(date: &str) -> Option<SystemTime> {
+    let (date, time) = date.split_once(['T', 't', ' '])?;
+
+    let mut parts = date.splitn(3, '-');
+    let year: i64 = parts.next()?.parse().ok()?;
+    let month: u32 = parts.next()?.parse().ok()?;
+    let day: u32 = parts.next()?.parse().ok()?;
+
+    let (time, offset) = match time.find(['Z', 'z', '+', '-']) {
+        Some(index) => time.split_at(index),
+        None => return None,
+    };
+    let offset = match offset {
+        "Z" | "z" => 0,
+        _ => {
+            let (hours, minutes) = offset[1..].split_once(':')?;
+            let (hours, minutes): (i64, i64) = (hours.parse().ok()?, minutes.parse().ok()?);
+            if hours > 23 || minutes > 59 {
+                return None;
+            }
+            let seconds = hours * 3_600 + minutes * 60;
+
+            if offset.starts_with('-') {
+                -seconds
+            } else {
+                seconds
+            }
+        }
+    };
+
+    let mut parts = time.splitn(3, ':');
+    let hour: i64 = parts.next()?.parse().ok()?;
+    let minute: i64 = parts.next()?.parse().ok()?;
+    let second = parts.next()?;
+    let (second, fraction) = second.split_once('.').unwrap_or((second, ""));
+    let second: i64 = second.parse().ok()?;
+    let nanos: u32 = match fraction {
+        "" => 0,
+        fraction => format!("{:0<9}", fraction).get(..9)?.parse().ok()?,
+    };
+
+    // Years past 9999 can't be formatted by the RSS and Atom dates
+    if !(1970..=9999).contains(&year)
+        || !(1..=12).contains(&month)
+        || !(1..=days_in_month(year, month)).contains(&day)
+        || hour > 23
+        || minute > 59
+        || second > 60
+    {
+        return None;
+    }
+
+    let seconds = days_from_civil(year, month, day)
+        .checked_mul(86_400)?
+        .checked_add(hour * 3_600 + minute * 60 + second)?
+        .checked_sub(offset)?;
+
+    // The offset can still move the date out of the range
+    if seconds >= days_from_civil(10_000, 1, 1) * 86_400 {
+        return None;
+    }
+
+    UNIX_EPOCH.checked_add(Duration::new(u64::try_from(seconds).ok()?, nanos))
+}
+
+/// Formats a timestamp as `2023-01-01T00:00:00Z`
+fn rfc_3339(time: SystemTime) -> String {
+    let seconds = time
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs());
+
+    let (year, month, day) = civil_from_days((seconds / 86_400) as i64);
+    let in_day = seconds % 86_400;
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        in_day / 3_600,
+        in_day / 60 % 60,
+        in_day % 60
+    )
+}
@@ -0,0 +1,202 @@
+//! TabNews markdown rendered to sanitized HTML, enabled by the `html` feature
+//!
+//! Supports CommonMark with GitHub tables, task lists and strikethrough.
+//! Math is left in `<span class="math math-inline">` and
+//! `<span class="math math-display">` for KaTeX or MathJax, and `mermaid`
+//! code blocks become `<pre class="mermaid">` for Mermaid. Other code blocks
+//! keep a `language-*` class for highlighters such as highlight.js or Prism.
+//!
+//! The output goes through an allowlist sanitizer, so raw HTML written in
+//! the markdown cannot run scripts, and links to other sites get
+//! `rel="nofollow noopener"`.
+
+use std::borrow::Cow;
+
+use ammonia::Builder;
+use pulldown_cmark::{html, CodeBlockKind, CowStr, Event, Tag, TagEnd};
+
+use crate::models::content::Content;
+use crate::models::text;
+
+const EXTERNAL_LINK_REL: &str = "nofollow noopener";
+const TABNEWS_HOST: &str = "tabnews.com.br";
+
+impl Content {
+    /// Renders [`Content::body`] to sanitized HTML, an empty string when
+    /// there is no body
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tabnews::models::content::Content;
+    /// # fn main() {
+    /// let mut content = Content::default();
+    /// content.set_body("Veja o [repositório](https://github.com/fadiinho/tabnews-rs)<script>alert(1)</script>");
+    ///
+    /// assert_eq!(
+    ///     content.render_html(),
+    ///     "<p>Veja o <a href=\"https://github.com/fadiinho/tabnews-rs\" rel=\"nofollow noopener\">repositório</a></p>\n"
+    /// );
+    /// # }
+    /// ```
+    pub fn render_html(&self) -> String {
+        self.body
+            .as_deref()
+            .map(render_markdown)
+            .unwrap_or_default()
+    }
+}
+
+/// Renders TabNews markdown to sanitized HTML
+pub fn render_markdown(markdown: &str) -> String {
+    let mut unsafe_html = String::with_capacity(markdown.len() * 3 / 2);
+    html::push_html(&mut unsafe_html, Mermaid::new(text::parser(markdown)));
+
+    let html = sanitizer().clean(&unsafe_html).to_string();
+
+    add_external_link_rel(&html)
+}
+
+/// Turns ```` ```mermaid ```` code blocks into `<pre class="mermaid">`
+struct Mermaid<I> {
+    events: I,
+    diagram: Option<String>,
+}
+
+impl<I> Mermaid<I> {
+    fn new(events: I) -> Self {
+        Mermaid {
+            events,
+            diagram: None,
+        }
+    }
+}
+
+impl<'a, I: Iterator<Item = Event<'a>>> Iterator for Mermaid<I> {
+    type Item = Event<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let event = self.events.next()?;
+
+            match (&mut self.diagram, event) {
+                (None, Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(language))))
+                    if language.trim() == "mermaid" =>
+                {
+                    self.diagram = Some(String::new());
+                }
+                (Some(diagram), Event::Text(text)) => diagram.push_str(&text),
+                (Some(_), Event::End(TagEnd::CodeBlock)) => {
+                    let diagram = self.diagram.take().unwrap_or_default();
+                    let mut html = String::from("<pre class=\"mermaid\">");
+                    html.push_str(&escape_text(&diagram));
+                    html.push_str("</pre>\n");
+
+                    return Some(Event::Html(CowStr::from(html)));
+                }
+                (Some(_), _) => {}
+                (None, event) => return Some(event),
+            }
+        }
+    }
+}
+
+fn escape_text(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// The default allowlist of ammonia, plus what the markdown renderer emits
+fn sanitizer() -> Builder<'static> {
+    let mut builder = Builder::default();
+
+    builder
+        .link_rel(None)
+        .add_tags(["input"])
+        .add_tag_attributes("input", ["type", "checked", "disabled"])
+        .add_tag_attributes("code", ["class"])
+        .add_tag_attributes("pre", ["class"])
+        .add_tag_attributes("span", ["class"])
+        .add_tag_attributes("th", ["style"])
+        .add_tag_attributes("td", ["style"])
+        .attribute_filter(|element, attribute, value| match (element, attribute) {
+            ("input", "type") => Some(Cow::Borrowed("checkbox")),
+            ("code", "class") => value
+                .starts_with("language-")
+                .then_some(Cow::Borrowed(value)),
+            ("pre", "class") => (value == "mermaid").then_some(Cow::Borrowed(value)),
+            ("span", "class") => matches!(value, "math math-inline" | "math math-display")
+                .then_some(Cow::Borrowed(value)),
+            ("th" | "td", "style") => matches!(
+                value,
+                "text-align: left" | "text-align: center" | "text-align: right"
+            )
+            .then_some(Cow::Borrowed(value)),
+            _ => Some(Cow::Borrowed(value)),
+        });
+
+    builder
+}
+
+/// Adds `rel` to the `<a>` tags of sanitized HTML that point to other sites
+///
+/// The sanitizer serializes every attribute between double quotes and
+/// escapes the double quotes inside them, so tags can be found by
+/// skipping over quoted values.
+fn add_external_link_rel(html: &str) -> String {
+    let mut output = String::with_capacity(html.len());
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<a ") {
+        let tag_length = rest[start..]
+            .char_indices()
+            .scan(false, |quoted, (index, char)| {
+                if char == '"' {
+                    *quoted = !*quoted;
+                }
+                Some((index, char, *quoted))
+            })
+            .find(|(_, char, quoted)| *char == '>' && !quoted)
+            .map_or(rest.len() - start, |(index, _, _)| index);
+
+        let tag = &rest[start..start + tag_length];
+        output.push_str(&rest[..start + tag_length]);
+
+        if href(tag).is_some_and(is_external) {
+            output.push_str(" rel=\"");
+            output.push_str(EXTERNAL_LINK_REL);
+            output.push('"');
+        }
+
+        rest = &rest[start + tag_length..];
+    }
+
+    output.push_str(rest);
+
+    output
+}
+
+fn href(tag: &str) -> Option<&str> {
+    let (_, value) = tag.split_once(" href=\"")?;
+
+    value.split('"').next()
+}
+
+/// Whether `href` is an absolute link to a host other than TabNews
+fn is_external(href: &str) -> bool {
+    let href = href.to_ascii_lowercase();
+
+    let Some(rest) = ["https://", "http://", "//"]
+        .iter()
+        .find_map(|prefix| href.strip_prefix(prefix))
+    else {
+        return false;
+    };
+
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    let host = authority.rsplit('@').next().unwrap_or_default();
+    let host = host.split(':').next().unwrap_or_default();
+
+    host != TABNEWS_HOST && !host.ends_with(&format!(".{}", TABNEWS_HOST))
+}
@@ -1,24 +1,25 @@
 use crate::models::analytics::{CommentsPublishedStatus, PostsPublishedStatus, UsersCreatedStatus};
+use crate::models::error::Result;
 
-use std::cell::RefCell;
-use std::rc::Rc;
+use std::sync::Arc;
 
 use super::http_client::HttpClient;
 
+#[derive(Clone)]
 pub struct AnalyticsApi {
-    http_client: Rc<RefCell<HttpClient>>,
+    http_client: Arc<HttpClient>,
 }
 
 impl Default for AnalyticsApi {
     fn default() -> Self {
-        let http_client = Rc::new(RefCell::new(HttpClient::default()));
+        let http_client = Arc::new(HttpClient::default());
 
         AnalyticsApi::new(http_client)
     }
 }
 
 impl AnalyticsApi {
-    pub fn new(client: Rc<RefCell<HttpClient>>) -> Self {
+    pub fn new(client: Arc<HttpClient>) -> Self {
         AnalyticsApi {
             http_client: client,
         }
@@ -30,28 +31,25 @@ impl AnalyticsApi {
     ///
     /// ```no_run
     /// # use tabnews::models::analytics::UsersCreatedStatus;
-    /// # use tabnews::TabnewsClient;
+    /// # use tabnews::{Error, TabnewsClient};
     /// # #[tokio::main]
-    /// # async fn main() {
+    /// # async fn main() -> Result<(), Error> {
     /// let client = TabnewsClient::default();
     ///
     /// let users_status: Vec<UsersCreatedStatus> = client.analytics_api
-    ///     .get_users_created().await;
+    ///     .get_users_created().await?;
     ///
     /// assert!(!users_status.is_empty());
+    /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_users_created(&self) -> Vec<UsersCreatedStatus> {
-        let _client = self.http_client.borrow();
-
-        let response = _client
+    pub async fn get_users_created(&self) -> Result<Vec<UsersCreatedStatus>> {
+        let response = self
+            .http_client
             .get("/analytics/users-created".to_owned())
-            .await
-            .unwrap();
-
-        let json_response: Vec<UsersCreatedStatus> = response.json().await.unwrap();
+            .await?;
 
-        json_response
+        response.json()
     }
 
     /// Get how many posts have been created per day
@@ -60,27 +58,24 @@ impl AnalyticsApi {
     ///
     /// ```no_run
     /// # use tabnews::models::analytics::PostsPublishedStatus;
-    /// # use tabnews::TabnewsClient;
+    /// # use tabnews::{Error, TabnewsClient};
     /// # #[tokio::main]
-    /// # async fn main() {
+    /// # async fn main() -> Result<(), Error> {
     /// let client = TabnewsClient::default();
     /// let posts_status: Vec<PostsPublishedStatus> = client.analytics_api
-    ///     .get_posts_published().await;
+    ///     .get_posts_published().await?;
     ///
     /// assert!(!posts_status.is_empty());
+    /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_posts_published(&self) -> Vec<PostsPublishedStatus> {
-        let _client = self.http_client.borrow();
-
-        let response = _client
+    pub async fn get_posts_published(&self) -> Result<Vec<PostsPublishedStatus>> {
+        let response = self
+            .http_client
             .get("/analytics/root-content-published".to_owned())
-            .await
-            .unwrap();
+            .await?;
 
-        let json_response: Vec<PostsPublishedStatus> = response.json().await.unwrap();
-
-        json_response
+        response.json()
     }
 
     /// Get how many comments have been created per day
@@ -88,27 +83,24 @@ impl AnalyticsApi {
     /// # Examples
     ///
     /// ```no_run
-    /// # use tabnews::TabnewsClient;
+    /// # use tabnews::{Error, TabnewsClient};
     /// # use tabnews::models::analytics::CommentsPublishedStatus;
     /// # #[tokio::main]
-    /// # async fn main() {
+    /// # async fn main() -> Result<(), Error> {
     /// let client = TabnewsClient::default();
     /// let comments_status: Vec<CommentsPublishedStatus> = client.analytics_api
-    ///     .get_comments_published().await;
+    ///     .get_comments_published().await?;
     ///
     /// assert!(!comments_status.is_empty());
+    /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_comments_published(&self) -> Vec<CommentsPublishedStatus> {
-        let _client = self.http_client.borrow();
-
-        let response = _client
+    pub async fn get_comments_published(&self) -> Result<Vec<CommentsPublishedStatus>> {
+        let response = self
+            .http_client
             .get("/analytics/child-content-published".to_owned())
-            .await
-            .unwrap();
-
-        let json_response: Vec<CommentsPublishedStatus> = response.json().await.unwrap();
+            .await?;
 
-        json_response
+        response.json()
     }
 }
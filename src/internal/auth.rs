@@ -1,17 +1,16 @@
-use std::{cell::RefCell, collections::HashMap, rc::Rc, str::FromStr};
+use std::{collections::HashMap, sync::Arc};
 
-use reqwest::header::{HeaderName, HeaderValue};
-
-use crate::models::user::UserSession;
+use crate::models::{error::Result, user::UserSession};
 
 use super::http_client::HttpClient;
 
+#[derive(Clone)]
 pub struct AuthApi {
-    tabnews_client: Rc<RefCell<HttpClient>>,
+    tabnews_client: Arc<HttpClient>,
 }
 
 impl AuthApi {
-    pub fn new(client: Rc<RefCell<HttpClient>>) -> Self {
+    pub fn new(client: Arc<HttpClient>) -> Self {
         AuthApi {
             tabnews_client: client,
         }
@@ -22,34 +21,36 @@ impl AuthApi {
     /// # Examples
     ///
     /// ```no_run
-    /// # use tabnews::TabnewsClient;
+    /// # use tabnews::{Error, TabnewsClient};
     /// # use tabnews::models::user::UserSession;
     /// # #[tokio::main]
-    /// # async fn main() {
+    /// # async fn main() -> Result<(), Error> {
     /// let client = TabnewsClient::default();
     /// let user_session: UserSession = client.auth_api
     ///     .get_user_session(
     ///         "<email>".to_owned(),
     ///         "<password>".to_owned(),
-    ///     ).await;
+    ///     ).await?;
     ///
     /// assert!(!user_session.token.is_empty());
+    /// # Ok(())
     /// # }
     ///
     /// ```
-    pub async fn get_user_session(&self, email: String, password: String) -> UserSession {
+    pub async fn get_user_session(&self, email: String, password: String) -> Result<UserSession> {
+        tracing::debug!("creating session");
+
         let mut data: HashMap<&str, String> = HashMap::new();
 
         data.insert("email", email);
         data.insert("password", password);
 
-        let _client = self.tabnews_client.borrow();
-
-        let response = _client.post("/sessions".to_owned(), data).await.unwrap();
+        let response = self
+            .tabnews_client
+            .post("/sessions".to_owned(), data)
+            .await?;
 
-        let json_response = response.json().await.unwrap();
-
-        json_response
+        response.json()
     }
 
     /// Create a session with the provided `email` and `password`,
@@ -58,36 +59,34 @@ impl AuthApi {
     /// # Examples
     ///
     /// ```no_run
-    /// # use tabnews::TabnewsClient;
+    /// # use tabnews::{Error, TabnewsClient};
     /// # use tabnews::models::user::UserSession;
     /// # #[tokio::main]
-    /// # async fn main() {
+    /// # async fn main() -> Result<(), Error> {
     /// let client = TabnewsClient::default();
     /// let user_session: UserSession = client.auth_api
     ///     .login(
     ///         "<email>".to_owned(),
     ///         "<password>".to_owned(),
-    ///     ).await;
+    ///     ).await?;
     ///
     ///
     /// assert!(client.is_logged());
+    /// # Ok(())
     /// # }
     ///
     /// ```
-    pub async fn login(&self, email: String, password: String) -> UserSession {
-        let session = self.get_user_session(email, password).await;
-
-        {
-            let mut _client = self.tabnews_client.borrow_mut();
+    pub async fn login(&self, email: String, password: String) -> Result<UserSession> {
+        let session = self.get_user_session(email, password).await?;
 
-            let cookie_value = format!("session_id={}", session.token);
+        self.tabnews_client.set_session_token(&session.token)?;
 
-            _client.add_header(
-                HeaderName::from_str("Cookie").unwrap(),
-                HeaderValue::try_from(cookie_value).unwrap(),
-            );
-        }
+        tracing::info!(
+            session_id = %session.id,
+            expires_at = %session.expires_at,
+            "logged in"
+        );
 
-        session
+        Ok(session)
     }
 }
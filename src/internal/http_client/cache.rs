@@ -0,0 +1,427 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use reqwest::{
+    header::{
+        HeaderMap, HeaderName, HeaderValue, CACHE_CONTROL, COOKIE, ETAG, IF_MODIFIED_SINCE,
+        IF_NONE_MATCH, LAST_MODIFIED,
+    },
+    StatusCode,
+};
+use serde::{Deserialize, Serialize};
+
+use super::transport::{HttpRequest, HttpResponse};
+use crate::models::error::Result;
+
+/// Response stored by a [`CacheStore`]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CacheEntry {
+    /// Path of the request, relative to the host, without the query
+    pub path: String,
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    #[serde(skip)]
+    pub body: Vec<u8>,
+    /// Unix timestamp, in seconds, of when the entry was stored or revalidated
+    pub stored_at: u64,
+    /// How long, in seconds, the entry can be used without revalidation
+    pub max_age: Option<u64>,
+    /// Whether the entry must be revalidated before every use
+    pub no_cache: bool,
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+}
+
+impl CacheEntry {
+    fn is_fresh(&self) -> bool {
+        match (self.no_cache, self.max_age) {
+            (false, Some(max_age)) => now() < self.stored_at.saturating_add(max_age),
+            _ => false,
+        }
+    }
+
+    fn to_response(&self) -> HttpResponse {
+        let mut headers = HeaderMap::new();
+
+        for (key, value) in &self.headers {
+            if let (Ok(key), Ok(value)) = (
+                HeaderName::try_from(key.as_str()),
+                HeaderValue::try_from(value.as_str()),
+            ) {
+                headers.append(key, value);
+            }
+        }
+
+        HttpResponse {
+            status: StatusCode::from_u16(self.status).unwrap_or(StatusCode::OK),
+            headers,
+            body: self.body.clone(),
+        }
+    }
+
+    /// Updates freshness and validators from the headers of `response`
+    fn refresh(&mut self, response: &HttpResponse) {
+        let directives = CacheDirectives::parse(&response.headers);
+
+        self.stored_at = now();
+        self.max_age = directives.max_age;
+        self.no_cache = directives.no_cache;
+
+        if let Some(etag) = header(&response.headers, &ETAG) {
+            self.etag = Some(etag);
+        }
+
+        if let Some(last_modified) = header(&response.headers, &LAST_MODIFIED) {
+            self.last_modified = Some(last_modified);
+        }
+    }
+}
+
+/// Backend where [`HttpCache`] keeps its entries
+pub trait CacheStore: Send + Sync {
+    fn get(&self, key: &str) -> Option<CacheEntry>;
+
+    fn put(&self, key: &str, entry: CacheEntry);
+
+    /// Removes every entry for which `predicate` returns `true`
+    fn remove_where(&self, predicate: &dyn Fn(&CacheEntry) -> bool);
+}
+
+/// [`CacheStore`] that keeps the entries in memory
+#[derive(Default)]
+pub struct MemoryCache {
+    entries: Mutex<HashMap<String, CacheEntry>>,
+}
+
+impl CacheStore for MemoryCache {
+    fn get(&self, key: &str) -> Option<CacheEntry> {
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(key)
+            .cloned()
+    }
+
+    fn put(&self, key: &str, entry: CacheEntry) {
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(key.to_owned(), entry);
+    }
+
+    fn remove_where(&self, predicate: &dyn Fn(&CacheEntry) -> bool) {
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .retain(|_, entry| !predicate(entry));
+    }
+}
+
+/// [`CacheStore`] that keeps the entries as files in a directory.
+///
+/// Each entry is made of a `.json` file with its metadata
+/// and a `.body` file with the raw body.
+pub struct DiskCache {
+    directory: PathBuf,
+}
+
+/// Metadata file of a [`DiskCache`] entry.
+///
+/// Files are named after a hash of the key, so the key itself is kept
+/// to tell collisions apart.
+#[derive(Serialize, Deserialize)]
+struct DiskEntry {
+    key: String,
+    #[serde(flatten)]
+    entry: CacheEntry,
+}
+
+impl DiskCache {
+    /// Uses `directory` to store the entries, creating it if needed
+    pub fn new(directory: impl Into<PathBuf>) -> Result<Self> {
+        let directory = directory.into();
+
+        fs::create_dir_all(&directory)?;
+
+        Ok(DiskCache { directory })
+    }
+
+    fn file(&self, key: &str, extension: &str) -> PathBuf {
+        self.directory
+            .join(format!("{:016x}.{}", fnv1a(key.as_bytes()), extension))
+    }
+}
+
+impl CacheStore for DiskCache {
+    fn get(&self, key: &str) -> Option<CacheEntry> {
+        let metadata = fs::read(self.file(key, "json")).ok()?;
+        let stored: DiskEntry = serde_json::from_slice(&metadata).ok()?;
+
+        // Another key with the same hash
+        if stored.key != key {
+            return None;
+        }
+
+        let mut entry = stored.entry;
+        entry.body = fs::read(self.file(key, "body")).ok()?;
+
+        Some(entry)
+    }
+
+    fn put(&self, key: &str, entry: CacheEntry) {
+        let stored = DiskEntry {
+            key: key.to_owned(),
+            entry,
+        };
+
+        let metadata = match serde_json::to_vec(&stored) {
+            Ok(metadata) => metadata,
+            Err(_) => return,
+        };
+
+        // A failed write only means a cache miss later
+        fs::write(self.file(key, "body"), &stored.entry.body).ok();
+        fs::write(self.file(key, "json"), metadata).ok();
+    }
+
+    fn remove_where(&self, predicate: &dyn Fn(&CacheEntry) -> bool) {
+        let files = match fs::read_dir(&self.directory) {
+            Ok(files) => files,
+            Err(_) => return,
+        };
+
+        for file in files.flatten() {
+            let path = file.path();
+
+            if path.extension().is_none_or(|extension| extension != "json") {
+                continue;
+            }
+
+            let entry = fs::read(&path)
+                .ok()
+                .and_then(|metadata| serde_json::from_slice::<DiskEntry>(&metadata).ok());
+
+            if entry.is_some_and(|stored| predicate(&stored.entry)) {
+                fs::remove_file(path.with_extension("body")).ok();
+                fs::remove_file(&path).ok();
+            }
+        }
+    }
+}
+
+/// Cache of `GET` responses used by [`HttpClient`](super::HttpClient).
+///
+/// Entries are keyed by url, query and session, so logged in users never see
+/// each other's responses. `Cache-Control: no-store` responses are never
+/// stored, fresh entries (`max-age`) are served without touching the network
+/// and stale ones are revalidated with `If-None-Match`/`If-Modified-Since`.
+///
+/// Successful `POST` and `PATCH` requests to `/contents` invalidate the
+/// entries of the content they changed and of the listings containing it.
+///
+/// # Examples
+///
+/// ```
+/// # use tabnews::internal::http_client::cache::HttpCache;
+/// # use tabnews::TabnewsClient;
+/// let client = TabnewsClient::default();
+///
+/// client.http_client.set_cache(HttpCache::memory());
+/// ```
+#[derive(Clone)]
+pub struct HttpCache {
+    store: Arc<dyn CacheStore>,
+}
+
+impl HttpCache {
+    pub fn new(store: Arc<dyn CacheStore>) -> Self {
+        HttpCache { store }
+    }
+
+    /// Cache backed by a [`MemoryCache`]
+    pub fn memory() -> Self {
+        HttpCache::new(Arc::new(MemoryCache::default()))
+    }
+
+    /// Cache backed by a [`DiskCache`] stored in `directory`
+    pub fn disk(directory: impl Into<PathBuf>) -> Result<Self> {
+        Ok(HttpCache::new(Arc::new(DiskCache::new(directory)?)))
+    }
+
+    pub(crate) fn key(request: &HttpRequest) -> String {
+        let identity = request
+            .headers
+            .get(COOKIE)
+            .map_or(0, |cookie| fnv1a(cookie.as_bytes()));
+
+        format!("{:016x} {} {}", identity, request.method, request.url)
+    }
+
+    /// Returns the cached response for `key` when it can be used as is,
+    /// otherwise adds the validators of the stale entry to `request`
+    pub(crate) fn lookup(&self, key: &str, request: &mut HttpRequest) -> Option<HttpResponse> {
+        let entry = self.store.get(key)?;
+
+        if entry.is_fresh() {
+            return Some(entry.to_response());
+        }
+
+        if let Some(etag) = entry
+            .etag
+            .as_deref()
+            .and_then(|etag| HeaderValue::from_str(etag).ok())
+        {
+            request.headers.insert(IF_NONE_MATCH, etag);
+        }
+
+        if let Some(last_modified) = entry
+            .last_modified
+            .as_deref()
+            .and_then(|date| HeaderValue::from_str(date).ok())
+        {
+            request.headers.insert(IF_MODIFIED_SINCE, last_modified);
+        }
+
+        None
+    }
+
+    /// Stores `response` when allowed, or turns a `304 Not Modified`
+    /// into the cached response
+    pub(crate) fn store(&self, key: &str, path: &str, response: HttpResponse) -> HttpResponse {
+        if response.status == StatusCode::NOT_MODIFIED {
+            if let Some(mut entry) = self.store.get(key) {
+                entry.refresh(&response);
+                self.store.put(key, entry.clone());
+
+                return entry.to_response();
+            }
+
+            return response;
+        }
+
+        if !response.status.is_success() {
+            return response;
+        }
+
+        let directives = CacheDirectives::parse(&response.headers);
+        let etag = header(&response.headers, &ETAG);
+        let last_modified = header(&response.headers, &LAST_MODIFIED);
+
+        let useful = directives.max_age.is_some() || etag.is_some() || last_modified.is_some();
+
+        if directives.no_store || !useful {
+            return response;
+        }
+
+        let headers = response
+            .headers
+            .iter()
+            .filter_map(|(key, value)| Some((key.to_string(), value.to_str().ok()?.to_owned())))
+            .collect();
+
+        self.store.put(
+            key,
+            CacheEntry {
+                path: normalize(path),
+                status: response.status.as_u16(),
+                headers,
+                body: response.body.clone(),
+                stored_at: now(),
+                max_age: directives.max_age,
+                no_cache: directives.no_cache,
+                etag,
+                last_modified,
+            },
+        );
+
+        response
+    }
+
+    /// Removes the entries affected by a write to `path`
+    pub(crate) fn invalidate(&self, path: &str) {
+        let path = normalize(path);
+        let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+
+        match segments.as_slice() {
+            // Publishing may change any listing, including comments
+            ["contents"] => self
+                .store
+                .remove_where(&|entry| entry.path.starts_with("/contents")),
+            ["contents", username, slug, ..] => {
+                let content = format!("/contents/{}/{}", username, slug);
+                let listing = format!("/contents/{}", username);
+
+                self.store.remove_where(&|entry| {
+                    entry.path == "/contents"
+                        || entry.path == "/contents/rss"
+                        || entry.path.eq_ignore_ascii_case(&listing)
+                        || entry.path.starts_with(&content)
+                })
+            }
+            _ => {}
+        }
+    }
+}
+
+#[derive(Default)]
+struct CacheDirectives {
+    no_store: bool,
+    no_cache: bool,
+    max_age: Option<u64>,
+}
+
+impl CacheDirectives {
+    fn parse(headers: &HeaderMap) -> Self {
+        let mut directives = CacheDirectives::default();
+
+        for value in headers.get_all(CACHE_CONTROL) {
+            let value = match value.to_str() {
+                Ok(value) => value,
+                Err(_) => continue,
+            };
+
+            for directive in value.split(',').map(|d| d.trim().to_ascii_lowercase()) {
+                match directive.split_once('=') {
+                    Some(("max-age", seconds)) => {
+                        directives.max_age = seconds.trim_matches('"').parse().ok()
+                    }
+                    None if directive == "no-store" => directives.no_store = true,
+                    None if directive == "no-cache" => directives.no_cache = true,
+                    _ => {}
+                }
+            }
+        }
+
+        directives
+    }
+}
+
+fn header(headers: &HeaderMap, key: &HeaderName) -> Option<String> {
+    headers
+        .get(key)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_owned)
+}
+
+fn normalize(path: &str) -> String {
+    let path = path.split('?').next().unwrap_or_default();
+
+    format!("/{}", path.trim_matches('/'))
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+/// 64-bit FNV-1a, stable across runs so disk entries can be found again
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
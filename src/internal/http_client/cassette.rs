@@ -0,0 +1,530 @@
+//! Record-and-replay of HTTP interactions.
+//!
+//! [`RecordingTransport`] sends the requests through another [`Transport`]
+//! and saves each request/response pair to a cassette file, with the secrets
+//! scrubbed. [`ReplayTransport`] serves the responses of a cassette without
+//! touching the network and fails with [`Error::Replay`] when a request has
+//! no recorded match.
+//!
+//! Cassettes ending in `.yaml` or `.yml` are written as YAML, any other
+//! path as JSON.
+//!
+//! Only available with the `cassette` feature.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # use std::sync::Arc;
+//! # use tabnews::internal::http_client::cassette::{RecordingTransport, ReplayTransport};
+//! # use tabnews::internal::http_client::ReqwestTransport;
+//! # use tabnews::{Error, TabnewsClient};
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Error> {
+//! // Once, against the real API
+//! let recorder = RecordingTransport::new(
+//!     Arc::new(ReqwestTransport::default()),
+//!     "tests/cassettes/homepage.yaml",
+//! );
+//! let client = TabnewsClient::builder()
+//!     .transport(Arc::new(recorder))
+//!     .build()?;
+//! client.posts_api.get_homepage_posts(None).await?;
+//!
+//! // In CI, without network
+//! let replay = ReplayTransport::open("tests/cassettes/homepage.yaml")?;
+//! let client = TabnewsClient::builder()
+//!     .transport(Arc::new(replay))
+//!     .build()?;
+//! client.posts_api.get_homepage_posts(None).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, PoisonError};
+
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue, AUTHORIZATION, COOKIE, SET_COOKIE},
+    Method, StatusCode,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::redact::REDACTED;
+use super::transport::{BoxFuture, HttpRequest, HttpResponse, Transport};
+use crate::models::error::{Error, Result};
+
+/// Body of a recorded request or response
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum RecordedBody {
+    /// UTF-8 body, such as json or xml
+    Text(String),
+    /// Any other body, such as a thumbnail
+    Bytes(Vec<u8>),
+}
+
+impl RecordedBody {
+    fn new(bytes: &[u8]) -> Self {
+        match std::str::from_utf8(bytes) {
+            Ok(text) => RecordedBody::Text(text.to_owned()),
+            Err(_) => RecordedBody::Bytes(bytes.to_vec()),
+        }
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        match self {
+            RecordedBody::Text(text) => text.as_bytes(),
+            RecordedBody::Bytes(bytes) => bytes,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    pub method: String,
+    /// Absolute url, query string included
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<RecordedBody>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: RecordedBody,
+}
+
+/// A request and the response it received
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Interaction {
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+}
+
+/// Interactions saved in a cassette file, in the order they happened
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Cassette {
+    pub interactions: Vec<Interaction>,
+}
+
+impl Cassette {
+    /// Reads a JSON or YAML cassette, depending on the extension of `path`
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let content = fs::read(path)?;
+
+        let cassette = match is_yaml(path) {
+            true => serde_yaml::from_slice(&content).map_err(|err| err.to_string()),
+            false => serde_json::from_slice(&content).map_err(|err| err.to_string()),
+        };
+
+        cassette.map_err(|err| {
+            Error::InvalidInput(format!("invalid cassette {}: {}", path.display(), err))
+        })
+    }
+
+    /// Writes the cassette as JSON or YAML, depending on the extension of `path`,
+    /// creating the missing directories
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+
+        let content = match is_yaml(path) {
+            true => serde_yaml::to_string(self).map_err(|err| err.to_string()),
+            false => serde_json::to_string_pretty(self).map_err(|err| err.to_string()),
+        }
+        .map_err(|err| Error::InvalidInput(format!("can't serialize cassette: {}", err)))?;
+
+        if let Some(directory) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(directory)?;
+        }
+
+        fs::write(path, content)?;
+
+        Ok(())
+    }
+}
+
+/// Removes secrets from the interactions before they are saved.
+///
+/// By default the `Cookie`, `Set-Cookie` and `Authorization` headers and the
+/// json fields `password`, `token` and `session_id` are replaced with
+/// `[redacted]`, at any depth.
+#[derive(Clone, Debug)]
+pub struct Scrubber {
+    headers: Vec<HeaderName>,
+    json_keys: Vec<String>,
+}
+
+impl Default for Scrubber {
+    fn default() -> Self {
+        Scrubber {
+            headers: vec![COOKIE, SET_COOKIE, AUTHORIZATION],
+            json_keys: vec![
+                "password".to_owned(),
+                "token".to_owned(),
+                "session_id".to_owned(),
+            ],
+        }
+    }
+}
+
+impl Scrubber {
+    /// Also scrubs the header `name`
+    pub fn header(mut self, name: HeaderName) -> Self {
+        self.headers.push(name);
+
+        self
+    }
+
+    /// Also scrubs the json fields named `key`, such as `email`
+    pub fn json_key(mut self, key: &str) -> Self {
+        self.json_keys.push(key.to_owned());
+
+        self
+    }
+
+    fn scrub_headers(&self, headers: &HeaderMap) -> Vec<(String, String)> {
+        headers
+            .iter()
+            .map(|(key, value)| {
+                let value = match self.headers.contains(key) {
+                    true => REDACTED.to_owned(),
+                    false => String::from_utf8_lossy(value.as_bytes()).into_owned(),
+                };
+
+                (key.to_string(), value)
+            })
+            .collect()
+    }
+
+    fn scrub_body(&self, body: &[u8]) -> RecordedBody {
+        match serde_json::from_slice::<Value>(body) {
+            Ok(mut json) => {
+                self.scrub_json(&mut json);
+
+                RecordedBody::Text(json.to_string())
+            }
+            Err(_) => RecordedBody::new(body),
+        }
+    }
+
+    fn scrub_json(&self, value: &mut Value) {
+        match value {
+            Value::Object(map) => {
+                for (key, value) in map.iter_mut() {
+                    match self.json_keys.contains(key) {
+                        true => *value = Value::String(REDACTED.to_owned()),
+                        false => self.scrub_json(value),
+                    }
+                }
+            }
+            Value::Array(values) => values.iter_mut().for_each(|value| self.scrub_json(value)),
+            _ => {}
+        }
+    }
+
+    fn request(&self, request: &HttpRequest) -> RecordedRequest {
+        RecordedRequest {
+            method: request.method.to_string(),
+            url: request.url.clone(),
+            headers: self.scrub_headers(&request.headers),
+            body: request.body.as_deref().map(|body| self.scrub_body(body)),
+        }
+    }
+
+    fn response(&self, response: &HttpResponse) -> RecordedResponse {
+        RecordedResponse {
+            status: response.status.as_u16(),
+            headers: self.scrub_headers(&response.headers),
+            body: self.scrub_body(&response.body),
+        }
+    }
+}
+
+/// [`Transport`] that saves every interaction to a cassette file
+pub struct RecordingTransport {
+    inner: Arc<dyn Transport>,
+    path: PathBuf,
+    scrubber: Scrubber,
+    cassette: Mutex<Cassette>,
+}
+
+impl RecordingTransport {
+    /// Sends the requests through `inner` and records them to `path`,
+    /// replacing the cassette if it exists
+    pub fn new(inner: Arc<dyn Transport>, path: impl Into<PathBuf>) -> Self {
+        RecordingTransport {
+            inner,
+            path: path.into(),
+            scrubber: Scrubber::default(),
+            cassette: Mutex::new(Cassette::default()),
+        }
+    }
+
+    /// Replaces the default [`Scrubber`]
+    pub fn scrubber(mut self, scrubber: Scrubber) -> Self {
+        self.scrubber = scrubber;
+
+        self
+    }
+
+    /// Returns the interactions recorded so far
+    pub fn cassette(&self) -> Cassette {
+        self.cassette
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+}
+
+impl Transport for RecordingTransport {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>> {
+        Box::pin(async move {
+            let recorded_request = self.scrubber.request(&request);
+
+            let response = self.inner.send(request).await?;
+
+            let interaction = Interaction {
+                request: recorded_request,
+                response: self.scrubber.response(&response),
+            };
+
+            // The file is rewritten after each interaction, so an interrupted
+            // session still leaves a usable cassette behind. It is written
+            // while holding the lock, so concurrent requests can't replace
+            // it with an older snapshot.
+            let mut cassette = self.cassette.lock().unwrap_or_else(PoisonError::into_inner);
+            cassette.interactions.push(interaction);
+            cassette.save(&self.path)?;
+            drop(cassette);
+
+            Ok(response)
+        })
+    }
+}
+
+/// Which parts of a request must be equal to the recorded one for it to match
+#[derive(Clone, Debug)]
+pub struct MatchRules {
+    method_and_path: bool,
+    query: bool,
+    body: bool,
+}
+
+impl Default for MatchRules {
+    /// Matches the method, the path and the query, ignoring the body
+    fn default() -> Self {
+        MatchRules {
+            method_and_path: true,
+            query: true,
+            body: false,
+        }
+    }
+}
+
+impl MatchRules {
+    /// Whether the method and the path must match
+    pub fn method_and_path(mut self, enabled: bool) -> Self {
+        self.method_and_path = enabled;
+
+        self
+    }
+
+    /// Whether the query string must match, ignoring the order of the parameters
+    pub fn query(mut self, enabled: bool) -> Self {
+        self.query = enabled;
+
+        self
+    }
+
+    /// Whether the body must match, comparing json bodies by value
+    pub fn body(mut self, enabled: bool) -> Self {
+        self.body = enabled;
+
+        self
+    }
+
+    fn matches(&self, recorded: &RecordedRequest, request: &RecordedRequest) -> bool {
+        let recorded_url = HttpRequest::new(Method::GET, recorded.url.clone());
+        let url = HttpRequest::new(Method::GET, request.url.clone());
+
+        if self.method_and_path
+            && (!recorded.method.eq_ignore_ascii_case(&request.method)
+                || recorded_url.path() != url.path())
+        {
+            return false;
+        }
+
+        if self.query && sorted_query(recorded_url.query()) != sorted_query(url.query()) {
+            return false;
+        }
+
+        if self.body && !same_body(recorded.body.as_ref(), request.body.as_ref()) {
+            return false;
+        }
+
+        true
+    }
+}
+
+/// [`Transport`] that serves the responses saved in a cassette.
+///
+/// Each request is answered by the first recorded interaction that matches
+/// it and wasn't played yet, so repeated requests get the responses in the
+/// order they were recorded.
+pub struct ReplayTransport {
+    name: String,
+    rules: MatchRules,
+    scrubber: Scrubber,
+    allow_repeats: bool,
+    interactions: Vec<Interaction>,
+    played: Mutex<Vec<bool>>,
+}
+
+impl ReplayTransport {
+    /// Replays the cassette saved at `path`
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+
+        Ok(ReplayTransport::new(
+            Cassette::load(path)?,
+            path.display().to_string(),
+        ))
+    }
+
+    /// Replays `cassette`, calling it `name` in the errors
+    pub fn new(cassette: Cassette, name: impl Into<String>) -> Self {
+        let played = vec![false; cassette.interactions.len()];
+
+        ReplayTransport {
+            name: name.into(),
+            rules: MatchRules::default(),
+            scrubber: Scrubber::default(),
+            allow_repeats: false,
+            interactions: cassette.interactions,
+            played: Mutex::new(played),
+        }
+    }
+
+    /// Replaces the default [`MatchRules`]
+    pub fn match_rules(mut self, rules: MatchRules) -> Self {
+        self.rules = rules;
+
+        self
+    }
+
+    /// Sets the [`Scrubber`] used when recording, so bodies compare equal
+    pub fn scrubber(mut self, scrubber: Scrubber) -> Self {
+        self.scrubber = scrubber;
+
+        self
+    }
+
+    /// Allows an interaction to be played again once every match was played
+    pub fn allow_repeats(mut self, allow: bool) -> Self {
+        self.allow_repeats = allow;
+
+        self
+    }
+
+    /// Number of recorded interactions that were not played
+    pub fn unplayed(&self) -> usize {
+        self.played
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .filter(|played| !**played)
+            .count()
+    }
+
+    fn find(&self, request: &HttpRequest) -> Result<HttpResponse> {
+        let recorded_request = self.scrubber.request(request);
+        let mut played = self.played.lock().unwrap_or_else(PoisonError::into_inner);
+
+        let mut matches = self
+            .interactions
+            .iter()
+            .enumerate()
+            .filter(|(_, interaction)| self.rules.matches(&interaction.request, &recorded_request))
+            .map(|(index, _)| index);
+
+        let index = match matches.clone().find(|index| !played[*index]) {
+            Some(index) => Some(index),
+            None if self.allow_repeats => matches.next(),
+            None => None,
+        };
+
+        let index = index.ok_or_else(|| {
+            tracing::error!(
+                cassette = %self.name,
+                method = %request.method,
+                url = %request.url,
+                "request not found in cassette"
+            );
+
+            Error::Replay(format!(
+                "{} {} in cassette {}",
+                request.method, request.url, self.name
+            ))
+        })?;
+
+        played[index] = true;
+
+        response(&self.interactions[index].response)
+    }
+}
+
+impl Transport for ReplayTransport {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>> {
+        Box::pin(async move { self.find(&request) })
+    }
+}
+
+fn response(recorded: &RecordedResponse) -> Result<HttpResponse> {
+    let status = StatusCode::from_u16(recorded.status)
+        .map_err(|_| Error::InvalidInput(format!("invalid recorded status {}", recorded.status)))?;
+
+    let mut headers = HeaderMap::new();
+
+    for (key, value) in &recorded.headers {
+        headers.append(
+            HeaderName::try_from(key.as_str())?,
+            HeaderValue::try_from(value.as_str())?,
+        );
+    }
+
+    Ok(HttpResponse {
+        status,
+        headers,
+        body: recorded.body.as_bytes().to_vec(),
+    })
+}
+
+fn sorted_query(query: Option<&str>) -> Vec<(String, String)> {
+    let mut pairs: Vec<(String, String)> = query
+        .and_then(|query| serde_urlencoded::from_str(query).ok())
+        .unwrap_or_default();
+
+    pairs.sort();
+
+    pairs
+}
+
+fn same_body(recorded: Option<&RecordedBody>, body: Option<&RecordedBody>) -> bool {
+    let json = |body: Option<&RecordedBody>| {
+        body.and_then(|body| serde_json::from_slice::<Value>(body.as_bytes()).ok())
+    };
+
+    match (json(recorded), json(body)) {
+        (Some(recorded), Some(body)) => recorded == body,
+        _ => recorded == body,
+    }
+}
+
+fn is_yaml(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension == "yaml" || extension == "yml")
+}
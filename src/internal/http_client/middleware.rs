@@ -0,0 +1,97 @@
+use std::sync::Arc;
+
+use super::transport::{BoxFuture, HttpRequest, HttpResponse};
+use super::HttpClient;
+use crate::models::error::Result;
+
+/// Hook around every request sent by [`HttpClient`].
+///
+/// Middlewares run in the order they were added, each one receiving the
+/// request and the [`Next`] step of the chain. A middleware can change the
+/// request before calling [`Next::run`], change the response it returns,
+/// or skip [`Next::run`] and answer with a response of its own.
+///
+/// The chain runs before the cache, the retries and the rate limiter,
+/// so a middleware sees each call once, whatever happens below it.
+///
+/// # Examples
+///
+/// ```
+/// # use reqwest::header::HeaderValue;
+/// # use tabnews::internal::http_client::middleware::{Middleware, Next};
+/// # use tabnews::internal::http_client::transport::BoxFuture;
+/// # use tabnews::internal::http_client::{HttpRequest, HttpResponse};
+/// # use tabnews::{Result, TabnewsClient};
+/// struct CorrelationId(&'static str);
+///
+/// impl Middleware for CorrelationId {
+///     fn handle<'a>(
+///         &'a self,
+///         mut request: HttpRequest,
+///         next: Next<'a>,
+///     ) -> BoxFuture<'a, Result<HttpResponse>> {
+///         request
+///             .headers
+///             .insert("x-correlation-id", HeaderValue::from_static(self.0));
+///
+///         next.run(request)
+///     }
+/// }
+///
+/// let client = TabnewsClient::default();
+///
+/// client.http_client.add_middleware(CorrelationId("my-app"));
+/// ```
+pub trait Middleware: Send + Sync {
+    fn handle<'a>(
+        &'a self,
+        request: HttpRequest,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, Result<HttpResponse>>;
+}
+
+/// Lets a middleware be shared, for example to read the metrics it records
+impl<M: Middleware + ?Sized> Middleware for Arc<M> {
+    fn handle<'a>(
+        &'a self,
+        request: HttpRequest,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, Result<HttpResponse>> {
+        (**self).handle(request, next)
+    }
+}
+
+/// Rest of the middleware chain, ending with the actual request
+pub struct Next<'a> {
+    middlewares: &'a [Arc<dyn Middleware>],
+    client: &'a HttpClient,
+    path: &'a str,
+}
+
+impl<'a> Next<'a> {
+    pub(crate) fn new(
+        middlewares: &'a [Arc<dyn Middleware>],
+        client: &'a HttpClient,
+        path: &'a str,
+    ) -> Self {
+        Next {
+            middlewares,
+            client,
+            path,
+        }
+    }
+
+    /// Hands `request` to the next middleware, or sends it when this is the last one
+    pub fn run(self, request: HttpRequest) -> BoxFuture<'a, Result<HttpResponse>> {
+        match self.middlewares.split_first() {
+            Some((middleware, middlewares)) => middleware.handle(
+                request,
+                Next {
+                    middlewares,
+                    ..self
+                },
+            ),
+            None => Box::pin(self.client.send_through_cache(self.path, request)),
+        }
+    }
+}
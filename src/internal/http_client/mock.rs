@@ -0,0 +1,108 @@
+use std::collections::VecDeque;
+use std::sync::{Mutex, PoisonError};
+
+use reqwest::StatusCode;
+use serde::Serialize;
+
+use super::transport::{BoxFuture, HttpRequest, HttpResponse, Transport};
+use crate::models::error::{Error, Result};
+
+/// In-memory [`Transport`] that answers with scripted responses
+///
+/// Responses are served in the order they were pushed, and every request
+/// received is recorded so it can be inspected afterwards.
+///
+/// # Examples
+///
+/// ```
+/// # use std::sync::Arc;
+/// # use reqwest::StatusCode;
+/// # use tabnews::internal::http_client::{HttpClient, mock::MockTransport};
+/// # use tabnews::internal::posts::PostsApi;
+/// # use tabnews::Error;
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Error> {
+/// let transport = Arc::new(MockTransport::default());
+/// transport.push_json(StatusCode::OK, &serde_json::json!([]))?;
+///
+/// let client = HttpClient::with_transport("http://tabnews.test".to_owned(), transport.clone());
+/// let posts_api = PostsApi::new(Arc::new(client));
+///
+/// let posts = posts_api.get_homepage_posts(None).await?;
+///
+/// assert!(posts.is_empty());
+/// assert_eq!(transport.requests()[0].path(), "/contents");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default)]
+pub struct MockTransport {
+    responses: Mutex<VecDeque<Result<HttpResponse>>>,
+    requests: Mutex<Vec<HttpRequest>>,
+}
+
+impl MockTransport {
+    /// Queues `response` to be returned by the next unanswered request
+    pub fn push_response(&self, response: HttpResponse) {
+        self.responses
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push_back(Ok(response));
+    }
+
+    /// Queues a response with `body` serialized as json
+    pub fn push_json<T: Serialize>(&self, status: StatusCode, body: &T) -> Result<()> {
+        self.push_response(HttpResponse::from_json(status, body)?);
+
+        Ok(())
+    }
+
+    /// Queues an error, as if the request could not be sent
+    pub fn push_error(&self, error: Error) {
+        self.responses
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push_back(Err(error));
+    }
+
+    /// Returns the requests received so far, in order
+    pub fn requests(&self) -> Vec<HttpRequest> {
+        self.requests
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Returns how many scripted responses were not consumed yet
+    pub fn pending(&self) -> usize {
+        self.responses
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len()
+    }
+}
+
+impl Transport for MockTransport {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>> {
+        let description = format!("{} {}", request.method, request.url);
+
+        self.requests
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(request);
+
+        let response = self
+            .responses
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .pop_front();
+
+        Box::pin(async move {
+            response.unwrap_or_else(|| {
+                Err(Error::Transport(
+                    format!("no scripted response left for {}", description).into(),
+                ))
+            })
+        })
+    }
+}
@@ -0,0 +1,461 @@
+pub mod cache;
+#[cfg(feature = "cassette")]
+pub mod cassette;
+pub mod middleware;
+pub mod mock;
+pub mod rate_limit;
+pub(crate) mod redact;
+pub mod retry;
+pub mod transport;
+
+use std::collections::HashMap;
+use std::sync::{Arc, PoisonError, RwLock};
+use std::time::Instant;
+
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue, IntoHeaderName, CONTENT_TYPE, COOKIE},
+    Method,
+};
+use serde::Serialize;
+use tracing::{field, Instrument, Span};
+
+use crate::models::error::{Error, Result};
+
+use cache::HttpCache;
+use middleware::{Middleware, Next};
+use rate_limit::RateLimiter;
+use retry::{RetryEvent, RetryPolicy};
+pub use transport::{HttpRequest, HttpResponse, ReqwestTransport, StreamingResponse, Transport};
+
+/// Low level client shared by every API group.
+///
+/// The host and the headers live behind locks, so a single [`HttpClient`]
+/// can be shared through an `Arc` and used by many tasks at once.
+/// The locks are never held across an `.await`.
+///
+/// Requests are sent through a [`Transport`], [`ReqwestTransport`] by default,
+/// and failed requests are retried according to a [`RetryPolicy`].
+/// Every attempt waits for the [`RateLimiter`], when one is set, and `GET`
+/// responses go through the [`HttpCache`], when one is set. Before all of
+/// that, requests go through the [`Middleware`]s added to the client.
+///
+/// Each request runs inside a `tabnews.request` [`tracing`] span recording
+/// its method, path, status, latency, number of retries and TabNews
+/// `request_id`. Credentials are never recorded.
+pub struct HttpClient {
+    host: RwLock<String>,
+    transport: Arc<dyn Transport>,
+    headers: RwLock<HeaderMap>,
+    retry_policy: RwLock<RetryPolicy>,
+    rate_limiter: RwLock<Option<Arc<RateLimiter>>>,
+    cache: RwLock<Option<HttpCache>>,
+    middlewares: RwLock<Vec<Arc<dyn Middleware>>>,
+}
+
+impl Default for HttpClient {
+    fn default() -> Self {
+        const BASE_URL: &str = "https://www.tabnews.com.br/api/v1";
+
+        HttpClient::new(BASE_URL.to_owned())
+    }
+}
+
+impl HttpClient {
+    /// Creates a new [`HttpClient`].
+    /// `host` can be passed as argument to change the default host
+    pub fn new(host: String) -> Self {
+        HttpClient::with_transport(host, Arc::new(ReqwestTransport::default()))
+    }
+
+    /// Creates a new [`HttpClient`] that sends its requests through `transport`
+    pub fn with_transport(host: String, transport: Arc<dyn Transport>) -> Self {
+        let mut headers = HeaderMap::new();
+
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+        Self {
+            host: RwLock::new(host),
+            transport,
+            headers: RwLock::new(headers),
+            retry_policy: RwLock::new(RetryPolicy::default()),
+            rate_limiter: RwLock::new(None),
+            cache: RwLock::new(None),
+            middlewares: RwLock::new(Vec::new()),
+        }
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!(
+            "{}/{}",
+            self.host().trim_end_matches('/'),
+            path.trim_start_matches('/')
+        )
+    }
+
+    fn headers(&self) -> HeaderMap {
+        self.headers
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    async fn send(&self, path: &str, request: HttpRequest) -> Result<HttpResponse> {
+        let span = tracing::info_span!(
+            "tabnews.request",
+            method = %request.method,
+            path = %path,
+            status = field::Empty,
+            latency_ms = field::Empty,
+            retries = 0,
+            request_id = field::Empty,
+            cache = field::Empty,
+        );
+        let started = Instant::now();
+
+        let request = request.headers(self.headers());
+        let middlewares = self.middlewares();
+
+        let outcome = Next::new(&middlewares, self, path)
+            .run(request)
+            .instrument(span.clone())
+            .await;
+
+        span.record("latency_ms", started.elapsed().as_millis() as u64);
+
+        let _entered = span.enter();
+
+        match &outcome {
+            Ok(response) => {
+                span.record("status", response.status.as_u16());
+
+                if let Some(request_id) = response.request_id() {
+                    span.record("request_id", field::display(&request_id));
+                }
+
+                match response.status.is_success() {
+                    true => tracing::debug!("request completed"),
+                    false => tracing::warn!("request failed"),
+                }
+            }
+            Err(err) => tracing::warn!(error = %err, "request failed"),
+        }
+
+        outcome?.error_for_status()
+    }
+
+    async fn send_through_cache(
+        &self,
+        path: &str,
+        mut request: HttpRequest,
+    ) -> Result<HttpResponse> {
+        let cache = self.cache();
+
+        let cache_key = match (&cache, &request.method) {
+            (Some(cache), &Method::GET) => {
+                let key = HttpCache::key(&request);
+
+                if let Some(response) = cache.lookup(&key, &mut request) {
+                    Span::current().record("cache", "hit");
+
+                    return Ok(response);
+                }
+
+                Some(key)
+            }
+            _ => None,
+        };
+
+        let response = self.send_with_retries(path, request).await?;
+
+        let response = match (&cache, cache_key) {
+            (Some(cache), Some(key)) => cache.store(&key, path, response),
+            (Some(cache), None) if response.status.is_success() => {
+                cache.invalidate(path);
+
+                response
+            }
+            _ => response,
+        };
+
+        Ok(response)
+    }
+
+    async fn send_with_retries(&self, path: &str, request: HttpRequest) -> Result<HttpResponse> {
+        let policy = self.retry_policy();
+        let retryable = policy.allows(&request.method);
+        let rate_limiter = self.rate_limiter();
+
+        let mut attempt = 1;
+
+        loop {
+            if let Some(rate_limiter) = &rate_limiter {
+                rate_limiter.acquire(path).await;
+            }
+
+            let outcome = self.transport.send(request.clone()).await;
+
+            let reason = match retryable {
+                true => policy.should_retry(attempt, &outcome),
+                false => None,
+            };
+
+            let reason = match reason {
+                Some(reason) => reason,
+                None => return outcome,
+            };
+
+            let delay = policy.delay(attempt, &outcome);
+
+            Span::current().record("retries", attempt);
+            tracing::warn!(
+                attempt,
+                delay_ms = delay.as_millis() as u64,
+                reason = ?reason,
+                "retrying request"
+            );
+
+            policy.notify(&RetryEvent {
+                method: request.method.clone(),
+                url: request.url.clone(),
+                attempt,
+                delay,
+                reason,
+            });
+
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    pub async fn get_with_params<T: Serialize>(
+        &self,
+        path: String,
+        params: Option<&T>,
+    ) -> Result<HttpResponse> {
+        let mut url = self.url(&path);
+
+        if let Some(params) = params {
+            let query = serde_urlencoded::to_string(params)
+                .map_err(|err| Error::InvalidInput(err.to_string()))?;
+
+            if !query.is_empty() {
+                url = format!("{}?{}", url, query);
+            }
+        }
+
+        self.send(&path, HttpRequest::new(Method::GET, url)).await
+    }
+
+    pub async fn get(&self, path: String) -> Result<HttpResponse> {
+        let url = self.url(&path);
+
+        self.send(&path, HttpRequest::new(Method::GET, url)).await
+    }
+
+    /// Sends a `GET` request and returns as soon as the headers arrive,
+    /// leaving the body to be read in chunks.
+    ///
+    /// The body can't be replayed, so the middlewares, the cache
+    /// and the retries are skipped; the rate limiter still applies.
+    pub async fn get_streaming(&self, path: String) -> Result<StreamingResponse> {
+        let span = tracing::info_span!(
+            "tabnews.request",
+            method = %Method::GET,
+            path = %path,
+            status = field::Empty,
+            latency_ms = field::Empty,
+            streaming = true,
+        );
+        let started = Instant::now();
+
+        let request = HttpRequest::new(Method::GET, self.url(&path)).headers(self.headers());
+
+        let outcome = async {
+            if let Some(rate_limiter) = self.rate_limiter() {
+                rate_limiter.acquire(&path).await;
+            }
+
+            self.transport.send_streaming(request).await
+        }
+        .instrument(span.clone())
+        .await;
+
+        span.record("latency_ms", started.elapsed().as_millis() as u64);
+
+        let _entered = span.enter();
+
+        match &outcome {
+            Ok(response) => {
+                span.record("status", response.status.as_u16());
+                tracing::debug!("response headers received");
+            }
+            Err(err) => tracing::warn!(error = %err, "request failed"),
+        }
+
+        drop(_entered);
+
+        outcome?.error_for_status().await
+    }
+
+    pub async fn post<T>(&self, path: String, body: T) -> Result<HttpResponse>
+    where
+        T: Serialize,
+    {
+        let url = self.url(&path);
+
+        self.send(&path, HttpRequest::new(Method::POST, url).json(&body)?)
+            .await
+    }
+
+    pub async fn patch<T>(&self, path: String, body: T) -> Result<HttpResponse>
+    where
+        T: Serialize,
+    {
+        let url = self.url(&path);
+
+        self.send(&path, HttpRequest::new(Method::PATCH, url).json(&body)?)
+            .await
+    }
+
+    pub fn add_header<K>(&self, key: K, value: HeaderValue)
+    where
+        K: IntoHeaderName,
+    {
+        self.headers
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(key, value);
+    }
+
+    pub fn add_multiple_headers(&self, headers: HashMap<String, String>) -> Result<()> {
+        for (k, v) in headers.iter() {
+            let key = HeaderName::try_from(k)?;
+
+            if key == COOKIE {
+                tracing::info!("session changed through the Cookie header");
+            }
+
+            self.add_header(key, HeaderValue::try_from(v)?);
+        }
+
+        Ok(())
+    }
+
+    /// Sends `session_id=<token>` in the `Cookie` header of the next requests
+    pub fn set_session_token(&self, token: &str) -> Result<()> {
+        self.add_header(
+            COOKIE,
+            HeaderValue::try_from(format!("session_id={}", token))?,
+        );
+
+        tracing::info!("session token set");
+
+        Ok(())
+    }
+
+    /// Removes the `Cookie` header, so the next requests are anonymous
+    pub fn clear_session(&self) {
+        let removed = self
+            .headers
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(COOKIE);
+
+        if removed.is_some() {
+            tracing::info!("session cleared");
+        }
+    }
+
+    pub fn get_header(&self, header_key: &str) -> Result<String> {
+        let headers = self.headers.read().unwrap_or_else(PoisonError::into_inner);
+
+        match headers.get(header_key) {
+            Some(header_value) => Ok(header_value.to_str()?.to_owned()),
+            None => Err(Error::InvalidHeader(format!(
+                "header {} doesn't exists",
+                header_key
+            ))),
+        }
+    }
+
+    pub fn host(&self) -> String {
+        self.host
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    pub fn retry_policy(&self) -> RetryPolicy {
+        self.retry_policy
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Replaces the [`RetryPolicy`] used by the next requests
+    pub fn set_retry_policy(&self, policy: RetryPolicy) {
+        *self
+            .retry_policy
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = policy;
+    }
+
+    fn rate_limiter(&self) -> Option<Arc<RateLimiter>> {
+        self.rate_limiter
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Sets the [`RateLimiter`] shared by every request of this client
+    pub fn set_rate_limiter(&self, rate_limiter: RateLimiter) {
+        *self
+            .rate_limiter
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = Some(Arc::new(rate_limiter));
+    }
+
+    fn cache(&self) -> Option<HttpCache> {
+        self.cache
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Sets the [`HttpCache`] used by the `GET` requests of this client
+    pub fn set_cache(&self, cache: HttpCache) {
+        *self.cache.write().unwrap_or_else(PoisonError::into_inner) = Some(cache);
+    }
+
+    fn middlewares(&self) -> Vec<Arc<dyn Middleware>> {
+        self.middlewares
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Adds `middleware` at the end of the middleware chain
+    pub fn add_middleware<M>(&self, middleware: M)
+    where
+        M: Middleware + 'static,
+    {
+        self.middlewares
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(Arc::new(middleware));
+    }
+
+    /// Removes every middleware
+    pub fn clear_middlewares(&self) {
+        self.middlewares
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+
+    pub fn set_host(&self, host: String) {
+        tracing::debug!(host = %host, "host changed");
+
+        *self.host.write().unwrap_or_else(PoisonError::into_inner) = host;
+    }
+}
@@ -0,0 +1,141 @@
+use std::fmt;
+use std::time::Duration;
+
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+
+/// Token bucket refilled continuously at `rate` tokens per second
+struct TokenBucket {
+    capacity: f64,
+    rate: f64,
+    state: Mutex<BucketState>,
+}
+
+struct BucketState {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn new(requests: u32, per: Duration) -> Self {
+        let capacity = f64::from(requests.max(1));
+
+        TokenBucket {
+            capacity,
+            rate: capacity / per.as_secs_f64().max(f64::EPSILON),
+            state: Mutex::new(BucketState {
+                tokens: capacity,
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    /// Waits until a token is available and takes it.
+    ///
+    /// The lock is held while waiting: tokio's mutex is fair, so concurrent
+    /// callers are served in the order they arrived.
+    async fn acquire(&self) {
+        let mut state = self.state.lock().await;
+
+        self.refill(&mut state);
+
+        if state.tokens < 1.0 {
+            let missing = 1.0 - state.tokens;
+            tokio::time::sleep(Duration::from_secs_f64(missing / self.rate)).await;
+
+            self.refill(&mut state);
+        }
+
+        state.tokens = (state.tokens - 1.0).max(0.0);
+    }
+
+    fn refill(&self, state: &mut BucketState) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(state.last_refill).as_secs_f64();
+
+        state.tokens = (state.tokens + elapsed * self.rate).min(self.capacity);
+        state.last_refill = now;
+    }
+}
+
+/// Client-side rate limiter shared by every API group of a client.
+///
+/// A request waits for a token of the global bucket, when there is one,
+/// and for a token of the bucket of the longest route prefix matching its
+/// path. Requests are never refused, they wait in line instead.
+///
+/// # Examples
+///
+/// ```
+/// # use std::time::Duration;
+/// # use tabnews::internal::http_client::rate_limit::RateLimiter;
+/// # use tabnews::TabnewsClient;
+/// let client = TabnewsClient::default();
+///
+/// client.http_client.set_rate_limiter(
+///     RateLimiter::new(10, Duration::from_secs(1))
+///         .route("/analytics", 1, Duration::from_secs(5)),
+/// );
+/// ```
+#[derive(Default)]
+pub struct RateLimiter {
+    global: Option<TokenBucket>,
+    routes: Vec<(String, TokenBucket)>,
+}
+
+impl fmt::Debug for RateLimiter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RateLimiter")
+            .field("global", &self.global.is_some())
+            .field(
+                "routes",
+                &self.routes.iter().map(|(p, _)| p).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl RateLimiter {
+    /// Allows `requests` requests every `per`, with bursts up to `requests`
+    pub fn new(requests: u32, per: Duration) -> Self {
+        RateLimiter {
+            global: Some(TokenBucket::new(requests, per)),
+            routes: Vec::new(),
+        }
+    }
+
+    /// Limiter without a global budget, to be used only with [`RateLimiter::route`]
+    pub fn unlimited() -> Self {
+        RateLimiter::default()
+    }
+
+    /// Allows `requests` requests every `per` to paths starting with `prefix`,
+    /// such as `/contents` or `/analytics`
+    pub fn route(mut self, prefix: &str, requests: u32, per: Duration) -> Self {
+        let prefix = format!("/{}", prefix.trim_start_matches('/'));
+
+        self.routes.push((prefix, TokenBucket::new(requests, per)));
+        self.routes
+            .sort_by_key(|(prefix, _)| std::cmp::Reverse(prefix.len()));
+
+        self
+    }
+
+    /// Waits until a request to `path` is allowed
+    pub async fn acquire(&self, path: &str) {
+        let path = format!("/{}", path.trim_start_matches('/'));
+
+        if let Some(global) = &self.global {
+            global.acquire().await;
+        }
+
+        let route = self.routes.iter().find(|(prefix, _)| {
+            path.strip_prefix(prefix.as_str())
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+        });
+
+        if let Some((_, bucket)) = route {
+            bucket.acquire().await;
+        }
+    }
+}
@@ -0,0 +1,63 @@
+//! Helpers that keep secrets out of logs and `Debug` output
+
+use std::fmt;
+
+use reqwest::header::{HeaderMap, AUTHORIZATION, COOKIE, PROXY_AUTHORIZATION, SET_COOKIE};
+use serde_json::Value;
+
+pub(crate) const REDACTED: &str = "[redacted]";
+
+/// Json keys whose values are never logged
+const SECRET_KEYS: [&str; 3] = ["password", "token", "session_id"];
+
+/// `Debug` view of a [`HeaderMap`] with the credentials hidden
+pub(crate) struct Headers<'a>(pub &'a HeaderMap);
+
+impl fmt::Debug for Headers<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let secret = [&COOKIE, &SET_COOKIE, &AUTHORIZATION, &PROXY_AUTHORIZATION];
+
+        f.debug_map()
+            .entries(self.0.iter().map(|(key, value)| {
+                let value = match secret.contains(&key) {
+                    true => REDACTED,
+                    false => value.to_str().unwrap_or("[binary]"),
+                };
+
+                (key.as_str(), value)
+            }))
+            .finish()
+    }
+}
+
+/// `Debug` view of a body: json with the secrets hidden, otherwise its size
+pub(crate) struct Body<'a>(pub &'a [u8]);
+
+impl fmt::Debug for Body<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match serde_json::from_slice::<Value>(self.0) {
+            Ok(mut json) => {
+                redact_json(&mut json);
+
+                write!(f, "{}", json)
+            }
+            Err(_) => write!(f, "<{} bytes>", self.0.len()),
+        }
+    }
+}
+
+fn redact_json(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if SECRET_KEYS.contains(&key.as_str()) {
+                    *value = Value::String(REDACTED.to_owned());
+                } else {
+                    redact_json(value);
+                }
+            }
+        }
+        Value::Array(values) => values.iter_mut().for_each(redact_json),
+        _ => {}
+    }
+}
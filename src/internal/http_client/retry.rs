@@ -0,0 +1,239 @@
+use std::collections::hash_map::RandomState;
+use std::fmt;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use reqwest::{header::RETRY_AFTER, Method, StatusCode};
+
+use super::transport::HttpResponse;
+use crate::models::error::Error;
+
+/// Callback notified before each retry
+pub type RetryObserver = Arc<dyn Fn(&RetryEvent) + Send + Sync>;
+
+/// Why a request is being retried
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RetryReason {
+    /// The server answered with a retryable status
+    Status(StatusCode),
+    /// The request timed out
+    Timeout,
+    /// The request could not be sent
+    Transport(String),
+}
+
+/// Information about a retry, handed to the observers of a [`RetryPolicy`]
+#[derive(Clone, Debug)]
+pub struct RetryEvent {
+    pub method: Method,
+    pub url: String,
+    /// Number of the attempt that failed, starting at 1
+    pub attempt: u32,
+    /// How long the client waits before the next attempt
+    pub delay: Duration,
+    pub reason: RetryReason,
+}
+
+/// Configures how [`HttpClient`](super::HttpClient) retries failed requests.
+///
+/// By default only `GET` requests are retried, up to 3 attempts, when the
+/// server answers with `429`, `500`, `502`, `503` or `504`, or when the
+/// request could not be sent. The delay doubles on each attempt, starting at
+/// `base_delay`, unless the server sends a `Retry-After` header. Either way
+/// the delay never exceeds `max_delay`.
+///
+/// # Examples
+///
+/// ```
+/// # use std::time::Duration;
+/// # use tabnews::internal::http_client::retry::RetryPolicy;
+/// # use tabnews::TabnewsClient;
+/// let client = TabnewsClient::default();
+///
+/// client.http_client.set_retry_policy(
+///     RetryPolicy::default()
+///         .max_attempts(5)
+///         .base_delay(Duration::from_millis(500))
+///         .retry_non_idempotent(true)
+///         .on_retry(|event| eprintln!("retrying {} {}", event.method, event.url)),
+/// );
+/// ```
+#[derive(Clone)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    jitter: f64,
+    retryable_statuses: Vec<StatusCode>,
+    retry_non_idempotent: bool,
+    observers: Vec<RetryObserver>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(30),
+            jitter: 0.2,
+            retryable_statuses: vec![
+                StatusCode::TOO_MANY_REQUESTS,
+                StatusCode::INTERNAL_SERVER_ERROR,
+                StatusCode::BAD_GATEWAY,
+                StatusCode::SERVICE_UNAVAILABLE,
+                StatusCode::GATEWAY_TIMEOUT,
+            ],
+            retry_non_idempotent: false,
+            observers: Vec::new(),
+        }
+    }
+}
+
+impl fmt::Debug for RetryPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_attempts", &self.max_attempts)
+            .field("base_delay", &self.base_delay)
+            .field("max_delay", &self.max_delay)
+            .field("jitter", &self.jitter)
+            .field("retryable_statuses", &self.retryable_statuses)
+            .field("retry_non_idempotent", &self.retry_non_idempotent)
+            .field("observers", &self.observers.len())
+            .finish()
+    }
+}
+
+impl RetryPolicy {
+    /// Policy that sends every request only once
+    pub fn none() -> Self {
+        RetryPolicy::default().max_attempts(1)
+    }
+
+    /// Sets how many times a request is sent at most, the first one included
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+
+        self
+    }
+
+    /// Sets the delay before the first retry
+    pub fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+
+        self
+    }
+
+    /// Sets the longest delay between attempts, also applied to the
+    /// `Retry-After` sent by the server
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+
+        self
+    }
+
+    /// Sets the fraction of the delay, between `0.0` and `1.0`,
+    /// that is randomly subtracted from it
+    pub fn jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+
+        self
+    }
+
+    /// Sets the statuses that cause a retry
+    pub fn retryable_statuses(mut self, statuses: Vec<StatusCode>) -> Self {
+        self.retryable_statuses = statuses;
+
+        self
+    }
+
+    /// Allows retrying `POST` and `PATCH` requests
+    pub fn retry_non_idempotent(mut self, retry: bool) -> Self {
+        self.retry_non_idempotent = retry;
+
+        self
+    }
+
+    /// Adds a callback notified before each retry
+    pub fn on_retry<F>(mut self, observer: F) -> Self
+    where
+        F: Fn(&RetryEvent) + Send + Sync + 'static,
+    {
+        self.observers.push(Arc::new(observer));
+
+        self
+    }
+
+    /// Whether requests with `method` can be retried at all
+    pub fn allows(&self, method: &Method) -> bool {
+        self.max_attempts > 1
+            && (self.retry_non_idempotent || matches!(*method, Method::GET | Method::HEAD))
+    }
+
+    /// Returns why the outcome of `attempt` should be retried, if it should
+    pub(crate) fn should_retry(
+        &self,
+        attempt: u32,
+        outcome: &Result<HttpResponse, Error>,
+    ) -> Option<RetryReason> {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+
+        match outcome {
+            Ok(response) if self.retryable_statuses.contains(&response.status) => {
+                Some(RetryReason::Status(response.status))
+            }
+            Ok(_) => None,
+            Err(Error::Timeout) => Some(RetryReason::Timeout),
+            Err(Error::Transport(err)) => Some(RetryReason::Transport(err.to_string())),
+            Err(_) => None,
+        }
+    }
+
+    /// Delay before retrying `attempt`, honoring `Retry-After` when present
+    pub(crate) fn delay(&self, attempt: u32, outcome: &Result<HttpResponse, Error>) -> Duration {
+        if let Ok(response) = outcome {
+            if let Some(delay) = retry_after(response) {
+                return delay.min(self.max_delay);
+            }
+        }
+
+        let exponent = attempt.saturating_sub(1).min(31);
+        let delay = self
+            .base_delay
+            .saturating_mul(1 << exponent)
+            .min(self.max_delay);
+
+        delay.mul_f64(1.0 - self.jitter * random_fraction())
+    }
+
+    pub(crate) fn notify(&self, event: &RetryEvent) {
+        for observer in &self.observers {
+            observer(event);
+        }
+    }
+}
+
+/// Parses the `Retry-After` header, either in seconds or as an HTTP date
+fn retry_after(response: &HttpResponse) -> Option<Duration> {
+    let value = response.headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = httpdate::parse_http_date(value).ok()?;
+
+    Some(
+        date.duration_since(SystemTime::now())
+            .unwrap_or(Duration::ZERO),
+    )
+}
+
+/// Random number in `[0, 1)`, good enough to spread retries
+fn random_fraction() -> f64 {
+    let value = RandomState::new().build_hasher().finish();
+
+    (value >> 11) as f64 / (1u64 << 53) as f64
+}
@@ -0,0 +1,328 @@
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+
+use futures::{future, stream, stream::BoxStream, StreamExt, TryStreamExt};
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue},
+    Client, Method, StatusCode,
+};
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::redact;
+use crate::models::error::{Error, Result, TabnewsError};
+
+/// Boxed future returned by [`Transport::send`]
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// Request handed to a [`Transport`].
+///
+/// Its `Debug` output hides the `Cookie` header and passwords.
+#[derive(Clone)]
+pub struct HttpRequest {
+    pub method: Method,
+    /// Absolute url, query string included
+    pub url: String,
+    pub headers: HeaderMap,
+    pub body: Option<Vec<u8>>,
+}
+
+impl HttpRequest {
+    pub fn new(method: Method, url: String) -> Self {
+        HttpRequest {
+            method,
+            url,
+            headers: HeaderMap::new(),
+            body: None,
+        }
+    }
+
+    /// Sets the headers of the request, replacing the previous ones
+    pub fn headers(mut self, headers: HeaderMap) -> Self {
+        self.headers = headers;
+
+        self
+    }
+
+    /// Sets a single header of the request
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.headers.insert(key, value);
+
+        self
+    }
+
+    /// Serializes `body` as the json body of the request
+    pub fn json<T: Serialize>(mut self, body: &T) -> Result<Self> {
+        let body = serde_json::to_vec(body).map_err(|err| Error::InvalidInput(err.to_string()))?;
+
+        self.body = Some(body);
+
+        Ok(self)
+    }
+
+    /// Returns the path of the url, without the scheme, host and query
+    pub fn path(&self) -> &str {
+        let without_scheme = self
+            .url
+            .split_once("://")
+            .map_or(self.url.as_str(), |(_, rest)| rest);
+
+        let path = without_scheme
+            .find('/')
+            .map_or("/", |index| &without_scheme[index..]);
+
+        path.split_once('?').map_or(path, |(path, _)| path)
+    }
+
+    /// Returns the query string of the url, if any
+    pub fn query(&self) -> Option<&str> {
+        self.url.split_once('?').map(|(_, query)| query)
+    }
+}
+
+impl fmt::Debug for HttpRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HttpRequest")
+            .field("method", &self.method)
+            .field("url", &self.url)
+            .field("headers", &redact::Headers(&self.headers))
+            .field("body", &self.body.as_deref().map(redact::Body))
+            .finish()
+    }
+}
+
+/// Response returned by a [`Transport`], with the body already read.
+///
+/// Its `Debug` output hides the `Set-Cookie` header and session tokens.
+#[derive(Clone)]
+pub struct HttpResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Vec<u8>,
+}
+
+impl fmt::Debug for HttpResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HttpResponse")
+            .field("status", &self.status)
+            .field("headers", &redact::Headers(&self.headers))
+            .field("body", &redact::Body(&self.body))
+            .finish()
+    }
+}
+
+impl HttpResponse {
+    pub fn new(status: StatusCode, body: Vec<u8>) -> Self {
+        HttpResponse {
+            status,
+            headers: HeaderMap::new(),
+            body,
+        }
+    }
+
+    /// Creates a response whose body is `body` serialized as json
+    pub fn from_json<T: Serialize>(status: StatusCode, body: &T) -> Result<Self> {
+        let body = serde_json::to_vec(body).map_err(|err| Error::InvalidInput(err.to_string()))?;
+
+        let mut response = HttpResponse::new(status, body);
+        response.headers.insert(
+            reqwest::header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        );
+
+        Ok(response)
+    }
+
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        &self.body
+    }
+
+    /// Returns the TabNews `request_id` of the response, read from the
+    /// `x-request-id` header or, for errors, from the error body
+    pub fn request_id(&self) -> Option<String> {
+        if let Some(request_id) = self
+            .headers
+            .get("x-request-id")
+            .and_then(|value| value.to_str().ok())
+        {
+            return Some(request_id.to_owned());
+        }
+
+        if self.status.is_success() {
+            return None;
+        }
+
+        serde_json::from_slice::<TabnewsError>(&self.body)
+            .ok()
+            .map(|error| error.request_id)
+    }
+
+    /// Returns the body as text, replacing invalid UTF-8 sequences
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+
+    /// Deserializes the json body, keeping the raw body on failure
+    pub fn json<T: DeserializeOwned>(&self) -> Result<T> {
+        serde_json::from_slice(&self.body).map_err(|source| Error::Decode {
+            source,
+            body: self.text(),
+        })
+    }
+
+    /// Returns the response if its status is a success,
+    /// otherwise turns it into [`Error::Http`]
+    pub fn error_for_status(self) -> Result<Self> {
+        if self.status.is_success() {
+            return Ok(self);
+        }
+
+        Err(Error::Http {
+            status: self.status.as_u16(),
+            error: serde_json::from_slice::<TabnewsError>(&self.body)
+                .ok()
+                .map(Box::new),
+        })
+    }
+}
+
+/// Response whose body is read in chunks, as they arrive
+pub struct StreamingResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: BoxStream<'static, Result<Vec<u8>>>,
+}
+
+impl fmt::Debug for StreamingResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StreamingResponse")
+            .field("status", &self.status)
+            .field("headers", &redact::Headers(&self.headers))
+            .finish_non_exhaustive()
+    }
+}
+
+impl StreamingResponse {
+    /// Reads the whole body
+    pub async fn into_response(self) -> Result<HttpResponse> {
+        let body: Vec<Vec<u8>> = self.body.try_collect().await?;
+
+        Ok(HttpResponse {
+            status: self.status,
+            headers: self.headers,
+            body: body.concat(),
+        })
+    }
+
+    /// Reads the body of non-success responses to return the [`Error::Http`]
+    pub async fn error_for_status(self) -> Result<Self> {
+        if self.status.is_success() {
+            return Ok(self);
+        }
+
+        match self.into_response().await?.error_for_status() {
+            Ok(response) => Ok(StreamingResponse::from(response)),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+impl From<HttpResponse> for StreamingResponse {
+    fn from(response: HttpResponse) -> Self {
+        StreamingResponse {
+            status: response.status,
+            headers: response.headers,
+            body: stream::once(future::ready(Ok(response.body))).boxed(),
+        }
+    }
+}
+
+/// Backend that actually sends the requests built by
+/// [`HttpClient`](super::HttpClient).
+///
+/// [`ReqwestTransport`] is used by default, and
+/// [`MockTransport`](super::mock::MockTransport) serves scripted responses
+/// without touching the network.
+pub trait Transport: Send + Sync {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>>;
+
+    /// Sends `request` without waiting for the whole body.
+    ///
+    /// By default the body is read by [`Transport::send`] and
+    /// handed back as a single chunk.
+    fn send_streaming(&self, request: HttpRequest) -> BoxFuture<'_, Result<StreamingResponse>> {
+        Box::pin(async move { self.send(request).await.map(StreamingResponse::from) })
+    }
+}
+
+/// [`Transport`] backed by a [`reqwest::Client`]
+#[derive(Clone, Default)]
+pub struct ReqwestTransport {
+    client: Client,
+}
+
+impl ReqwestTransport {
+    pub fn new(client: Client) -> Self {
+        ReqwestTransport { client }
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse>> {
+        Box::pin(async move {
+            let mut builder = self
+                .client
+                .request(request.method, request.url.as_str())
+                .headers(request.headers);
+
+            if let Some(body) = request.body {
+                builder = builder.body(body);
+            }
+
+            let response = builder.send().await?;
+
+            let status = response.status();
+            let headers = response.headers().to_owned();
+            let body = response.bytes().await?.to_vec();
+
+            Ok(HttpResponse {
+                status,
+                headers,
+                body,
+            })
+        })
+    }
+
+    fn send_streaming(&self, request: HttpRequest) -> BoxFuture<'_, Result<StreamingResponse>> {
+        Box::pin(async move {
+            let mut builder = self
+                .client
+                .request(request.method, request.url.as_str())
+                .headers(request.headers);
+
+            if let Some(body) = request.body {
+                builder = builder.body(body);
+            }
+
+            let response = builder.send().await?;
+
+            Ok(StreamingResponse {
+                status: response.status(),
+                headers: response.headers().to_owned(),
+                body: response
+                    .bytes_stream()
+                    .map_ok(|chunk| chunk.to_vec())
+                    .map_err(Error::from)
+                    .boxed(),
+            })
+        })
+    }
+}
@@ -1,6 +1,8 @@
 pub mod analytics;
 pub mod auth;
+pub(crate) mod dates;
 pub mod http_client;
+pub mod pagination;
 pub mod posts;
 pub mod user;
 pub mod users;
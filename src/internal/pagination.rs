@@ -0,0 +1,464 @@
+use std::collections::VecDeque;
+use std::future::Future;
+use std::ops::{Deref, DerefMut};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use futures::stream::{self, BoxStream, Stream, StreamExt};
+use reqwest::header::{HeaderMap, LINK};
+use serde::de::DeserializeOwned;
+use tokio::task::JoinHandle;
+
+use super::http_client::transport::BoxFuture;
+use super::http_client::HttpResponse;
+use crate::models::error::{Error, Result};
+
+/// Default number of items requested per page, the same as the TabNews API
+pub const DEFAULT_PER_PAGE: u64 = 30;
+
+/// Largest `per_page` accepted by the TabNews API
+pub const MAX_PER_PAGE: u64 = 100;
+
+/// Header with the total number of items of a listing
+const TOTAL_ROWS: &str = "x-pagination-total-rows";
+
+/// One page of a listing, with the pagination metadata sent by TabNews.
+///
+/// The previous, next, first and last pages are read from the `Link` header
+/// and the total from the `X-Pagination-Total-Rows` header. When the server
+/// doesn't send a `Link` header, a full page is assumed to have a next one.
+///
+/// A page dereferences to its items, so it can be used as a `Vec<T>`.
+///
+/// # Examples
+///
+/// ```
+/// # use tabnews::{Error, TabnewsClient};
+/// # use tabnews::internal::pagination::Page;
+/// # use tabnews::models::content::Content;
+/// # use tabnews::testing::FakeServer;
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Error> {
+/// # let server = FakeServer::start();
+/// let client = TabnewsClient::default();
+/// # client.http_client.set_host(server.url());
+///
+/// let page: Page<Content> = client.posts_api.get_homepage_posts(None).await?;
+///
+/// println!(
+///     "page {} of {}, {} posts in total",
+///     page.page,
+///     page.last_page.unwrap_or(page.page),
+///     page.total_rows.unwrap_or_default(),
+/// );
+///
+/// for post in page.iter() {
+///     assert!(post.slug.is_some());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// Number of this page, starting at 1
+    pub page: u64,
+    pub per_page: u64,
+    /// Number of items in the whole listing
+    pub total_rows: Option<u64>,
+    pub first_page: Option<u64>,
+    pub prev_page: Option<u64>,
+    pub next_page: Option<u64>,
+    pub last_page: Option<u64>,
+    /// TabNews `request_id` of the response
+    pub request_id: Option<String>,
+}
+
+impl<T> Page<T> {
+    /// Number of pages of the listing, when the total is known
+    pub fn total_pages(&self) -> Option<u64> {
+        self.total_rows
+            .map(|total| total.div_ceil(self.per_page.max(1)))
+    }
+
+    pub fn has_next(&self) -> bool {
+        self.next_page.is_some()
+    }
+
+    pub fn has_prev(&self) -> bool {
+        self.prev_page.is_some()
+    }
+
+    pub fn into_items(self) -> Vec<T> {
+        self.items
+    }
+
+    /// Applies `f` to the items, keeping the metadata
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Page<U> {
+        Page {
+            items: self.items.into_iter().map(f).collect(),
+            page: self.page,
+            per_page: self.per_page,
+            total_rows: self.total_rows,
+            first_page: self.first_page,
+            prev_page: self.prev_page,
+            next_page: self.next_page,
+            last_page: self.last_page,
+            request_id: self.request_id,
+        }
+    }
+}
+
+impl<T: DeserializeOwned> Page<T> {
+    /// Decodes the items of `response`, which answered the request for
+    /// `page` with `per_page` items, and reads the pagination headers
+    pub fn from_response(response: &HttpResponse, page: u64, per_page: u64) -> Result<Self> {
+        let items: Vec<T> = response.json()?;
+        let links = Links::parse(&response.headers);
+
+        let (prev_page, next_page) = match links.present {
+            true => (links.prev, links.next),
+            // Without links, a full page is the only hint of a next one
+            false => (
+                page.checked_sub(1).filter(|prev| *prev >= 1),
+                ((items.len() as u64) >= per_page && !items.is_empty()).then_some(page + 1),
+            ),
+        };
+
+        Ok(Page {
+            items,
+            page,
+            per_page,
+            total_rows: response
+                .headers
+                .get(TOTAL_ROWS)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.trim().parse().ok()),
+            first_page: links.first,
+            prev_page,
+            next_page,
+            last_page: links.last,
+            request_id: response.request_id(),
+        })
+    }
+}
+
+impl<T> Deref for Page<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.items
+    }
+}
+
+impl<T> DerefMut for Page<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.items
+    }
+}
+
+impl<T> IntoIterator for Page<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Page<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+impl<T> From<Page<T>> for Vec<T> {
+    fn from(page: Page<T>) -> Self {
+        page.items
+    }
+}
+
+/// Page numbers of the `Link` header, such as
+/// `<https://www.tabnews.com.br/api/v1/contents?page=2&per_page=30>; rel="next"`
+#[derive(Default)]
+struct Links {
+    present: bool,
+    first: Option<u64>,
+    prev: Option<u64>,
+    next: Option<u64>,
+    last: Option<u64>,
+}
+
+impl Links {
+    fn parse(headers: &HeaderMap) -> Self {
+        let mut links = Links::default();
+
+        for value in headers.get_all(LINK) {
+            let value = match value.to_str() {
+                Ok(value) => value,
+                Err(_) => continue,
+            };
+
+            links.present = true;
+
+            for link in value.split(',') {
+                let mut parts = link.split(';');
+
+                let url = parts
+                    .next()
+                    .map(|url| url.trim().trim_start_matches('<').trim_end_matches('>'))
+                    .unwrap_or_default();
+
+                let page = url
+                    .split_once('?')
+                    .and_then(|(_, query)| {
+                        serde_urlencoded::from_str::<Vec<(String, String)>>(query).ok()
+                    })
+                    .and_then(|query| {
+                        query
+                            .into_iter()
+                            .find(|(key, _)| key == "page")
+                            .and_then(|(_, page)| page.parse().ok())
+                    });
+
+                let relations = parts
+                    .filter_map(|part| part.trim().strip_prefix("rel="))
+                    .flat_map(|rel| rel.trim_matches('"').split_whitespace());
+
+                for relation in relations {
+                    match relation {
+                        "first" => links.first = page,
+                        "prev" | "previous" => links.prev = page,
+                        "next" => links.next = page,
+                        "last" => links.last = page,
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        links
+    }
+}
+
+type PageFetcher<T> = Arc<dyn Fn(u64, u64) -> BoxFuture<'static, Result<Page<T>>> + Send + Sync>;
+
+/// Stream over every item of a paginated listing.
+///
+/// Pages are only requested when the items of the previous one were consumed,
+/// so `take` and [`Paginated::limit`] never fetch more than needed. The stream
+/// ends after the last [`Page`], the first one without a next page, or after
+/// the first error.
+///
+/// # Examples
+///
+/// ```
+/// # use futures::TryStreamExt;
+/// # use tabnews::{Error, TabnewsClient};
+/// # use tabnews::models::content::Content;
+/// # use tabnews::testing::FakeServer;
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Error> {
+/// # let server = FakeServer::start();
+/// let client = TabnewsClient::default();
+/// # client.http_client.set_host(server.url());
+///
+/// let posts: Vec<Content> = client
+///     .posts_api
+///     .stream_homepage_posts(None)
+///     .per_page(50)
+///     .prefetch(true)
+///     .limit(120)
+///     .try_collect()
+///     .await?;
+///
+/// assert!(posts.len() <= 120);
+/// # Ok(())
+/// # }
+/// ```
+#[must_use = "streams do nothing unless polled"]
+pub struct Paginated<T> {
+    fetch: PageFetcher<T>,
+    start_page: u64,
+    per_page: u64,
+    prefetch: bool,
+    limit: Option<usize>,
+    stream: Option<BoxStream<'static, Result<T>>>,
+}
+
+impl<T: Send + 'static> Paginated<T> {
+    pub(crate) fn new<F, Fut>(fetch: F) -> Self
+    where
+        F: Fn(u64, u64) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<Page<T>>> + Send + 'static,
+    {
+        Paginated {
+            fetch: Arc::new(move |page, per_page| Box::pin(fetch(page, per_page))),
+            start_page: 1,
+            per_page: DEFAULT_PER_PAGE,
+            prefetch: false,
+            limit: None,
+            stream: None,
+        }
+    }
+
+    /// Sets the first page requested, starting at 1
+    pub fn start_page(mut self, page: u64) -> Self {
+        self.start_page = page;
+
+        self
+    }
+
+    /// Sets how many items are requested per page, from 1 to [`MAX_PER_PAGE`]
+    pub fn per_page(mut self, per_page: u64) -> Self {
+        self.per_page = per_page;
+
+        self
+    }
+
+    /// Requests the next page in the background while the current one is consumed
+    pub fn prefetch(mut self, prefetch: bool) -> Self {
+        self.prefetch = prefetch;
+
+        self
+    }
+
+    /// Stops after `limit` items
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+
+        self
+    }
+
+    fn build(&self) -> BoxStream<'static, Result<T>> {
+        if self.start_page < 1 {
+            return invalid("page must be greater than 0");
+        }
+
+        if !(1..=MAX_PER_PAGE).contains(&self.per_page) {
+            return invalid(&format!("per_page must be between 1 and {}", MAX_PER_PAGE));
+        }
+
+        let state = PageState {
+            fetch: Arc::clone(&self.fetch),
+            page: self.start_page,
+            per_page: self.per_page,
+            prefetch: self.prefetch,
+            buffer: VecDeque::new(),
+            pending: None,
+            done: false,
+        };
+
+        let stream = stream::unfold(state, PageState::next).boxed();
+
+        match self.limit {
+            Some(limit) => stream.take(limit).boxed(),
+            None => stream,
+        }
+    }
+}
+
+impl<T: Send + 'static> Stream for Paginated<T> {
+    type Item = Result<T>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        if this.stream.is_none() {
+            this.stream = Some(this.build());
+        }
+
+        match &mut this.stream {
+            Some(stream) => stream.poll_next_unpin(cx),
+            None => Poll::Ready(None),
+        }
+    }
+}
+
+fn invalid<T: Send + 'static>(reason: &str) -> BoxStream<'static, Result<T>> {
+    stream::once(futures::future::ready(Err(Error::InvalidInput(
+        reason.to_owned(),
+    ))))
+    .boxed()
+}
+
+/// Background request of the next page, cancelled when dropped
+struct Prefetch<T>(JoinHandle<Result<Page<T>>>);
+
+impl<T> Drop for Prefetch<T> {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+struct PageState<T> {
+    fetch: PageFetcher<T>,
+    page: u64,
+    per_page: u64,
+    prefetch: bool,
+    buffer: VecDeque<T>,
+    pending: Option<Prefetch<T>>,
+    done: bool,
+}
+
+impl<T: Send + 'static> PageState<T> {
+    async fn next(mut self) -> Option<(Result<T>, Self)> {
+        loop {
+            if let Some(item) = self.buffer.pop_front() {
+                return Some((Ok(item), self));
+            }
+
+            if self.done {
+                return None;
+            }
+
+            let page = match self.pending.take() {
+                Some(mut prefetch) => (&mut prefetch.0)
+                    .await
+                    .unwrap_or_else(|err| Err(Error::Transport(Box::new(err)))),
+                None => (self.fetch)(self.page, self.per_page).await,
+            };
+
+            let page = match page {
+                Ok(page) => page,
+                Err(err) => {
+                    self.done = true;
+
+                    return Some((Err(err), self));
+                }
+            };
+
+            tracing::debug!(
+                page = page.page,
+                items = page.items.len(),
+                total_rows = page.total_rows,
+                "page fetched"
+            );
+
+            // An empty page ends the listing, whatever the links say, and so
+            // does a link back to a page already fetched
+            match page
+                .next_page
+                .filter(|next_page| !page.items.is_empty() && *next_page > self.page)
+            {
+                Some(next_page) => {
+                    self.page = next_page;
+
+                    if self.prefetch {
+                        self.pending = Some(Prefetch(tokio::spawn((self.fetch)(
+                            self.page,
+                            self.per_page,
+                        ))));
+                    }
+                }
+                None => self.done = true,
+            }
+
+            self.buffer.extend(page.items);
+        }
+    }
+}
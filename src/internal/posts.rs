@@ -1,48 +1,80 @@
-use reqwest::Response;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 
-use std::cell::RefCell;
-use std::collections::HashMap;
-use std::rc::Rc;
+use futures::{stream, StreamExt};
+use serde::Serialize;
 
 use super::http_client::HttpClient;
+use super::pagination::{Page, Paginated, DEFAULT_PER_PAGE};
+use super::user::UserApi;
 
+use crate::models::ancestors::{Ancestors, KnownContents};
+use crate::models::comment_tree::CommentTree;
 use crate::models::content::Content;
 use crate::models::content::ContentParams;
+use crate::models::content::ContentPatch;
+use crate::models::content::ContentStatus;
+use crate::models::content::ListingParams;
 use crate::models::content::Tabcoins;
 use crate::models::content::TabcoinsTransaction;
-use crate::models::error::TabnewsError;
+use crate::models::error::{Error, Result};
+use crate::models::rss::RssFeed;
+use crate::models::thumbnail::{Thumbnail, ThumbnailStream};
+
+/// Query of a content listing, the params plus the status filter
+#[derive(Serialize)]
+struct ContentQuery<'a> {
+    #[serde(flatten)]
+    params: &'a ListingParams,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<ContentStatus>,
+}
 
+#[derive(Clone)]
 pub struct PostsApi {
-    tabnews_client: Rc<RefCell<HttpClient>>,
+    tabnews_client: Arc<HttpClient>,
 }
 
 impl Default for PostsApi {
     fn default() -> Self {
-        let tabnews_client = Rc::new(RefCell::new(HttpClient::default()));
+        let tabnews_client = Arc::new(HttpClient::default());
 
         PostsApi::new(tabnews_client)
     }
 }
 
 impl PostsApi {
-    pub fn new(client: Rc<RefCell<HttpClient>>) -> Self {
+    pub fn new(client: Arc<HttpClient>) -> Self {
         PostsApi {
             tabnews_client: client,
         }
     }
 
-    fn build_params(&self, params: Option<ContentParams>) -> Option<ContentParams> {
-        let params = if params.is_some() {
-            let p = params.unwrap();
-            ContentParams { ..p }
-        } else {
-            ContentParams::default()
+    async fn get_contents_page(
+        &self,
+        uri: String,
+        params: ListingParams,
+        status: Option<ContentStatus>,
+    ) -> Result<Page<Content>> {
+        params.params.validate()?;
+
+        let query = ContentQuery {
+            params: &params,
+            status,
         };
 
-        Some(params)
+        let response = self
+            .tabnews_client
+            .get_with_params(uri, Some(&query))
+            .await?;
+
+        let page = params.params.page.unwrap_or(1);
+        let per_page = params.params.per_page.unwrap_or(DEFAULT_PER_PAGE);
+
+        Page::from_response(&response, page, per_page)
     }
 
-    /// Returns the posts of the homepage
+    /// Returns a page of the posts of the homepage
     ///
     /// # Examples
     ///
@@ -50,13 +82,14 @@ impl PostsApi {
     ///
     /// ```
     /// # use tabnews::TabnewsClient;
+    /// # use tabnews::internal::pagination::Page;
     /// # use tabnews::models::content::Content;
-    /// # use tabnews::models::error::TabnewsError;
+    /// # use tabnews::Error;
     /// # #[tokio::main]
-    /// # async fn main() -> Result<(), TabnewsError> {
+    /// # async fn main() -> Result<(), Error> {
     /// let client = TabnewsClient::default();
     ///
-    /// let homepage_posts: Vec<Content> = client.posts_api
+    /// let homepage_posts: Page<Content> = client.posts_api
     ///     .get_homepage_posts(None).await?;
     ///
     /// assert!(homepage_posts.len() >= 0);
@@ -67,19 +100,20 @@ impl PostsApi {
     ///
     /// ```
     /// # use tabnews::TabnewsClient;
-    /// # use tabnews::models::content::{Content, ContentParams};
-    /// # use tabnews::models::error::TabnewsError;
+    /// # use tabnews::internal::pagination::Page;
+    /// # use tabnews::models::content::{Content, ContentParams, Strategy};
+    /// # use tabnews::Error;
     /// # #[tokio::main]
-    /// # async fn main() -> Result<(), TabnewsError> {
+    /// # async fn main() -> Result<(), Error> {
     /// let client = TabnewsClient::default();
     ///
-    /// let parameters = ContentParams {
-    ///     page: Some(1),
-    ///     per_page: Some(10),
-    ///     strategy: Some("old".to_owned())
-    /// };
+    /// let parameters = ContentParams::builder()
+    ///     .page(1)
+    ///     .per_page(10)
+    ///     .strategy(Strategy::Old)
+    ///     .build()?;
     ///
-    /// let homepage_posts: Vec<Content> = client.posts_api
+    /// let homepage_posts: Page<Content> = client.posts_api
     ///     .get_homepage_posts(Some(parameters)).await?;
     ///
     /// assert!(homepage_posts.len() >= 0);
@@ -88,36 +122,27 @@ impl PostsApi {
     /// ```
     pub async fn get_homepage_posts(
         &self,
-        params: Option<ContentParams>,
-    ) -> Result<Vec<Content>, TabnewsError> {
-        let _params = self.build_params(params);
-
-        let _client = self.tabnews_client.borrow();
-
-        let response = _client
-            .get_with_params("/contents".to_owned(), Some(&_params))
+        params: impl Into<ListingParams>,
+    ) -> Result<Page<Content>> {
+        self.get_contents_page("/contents".to_owned(), params.into(), None)
             .await
-            .unwrap();
-
-        let json_response: Vec<Content> = response.json().await.unwrap();
-
-        Ok(json_response)
     }
 
-    /// Returns the posts of a specific user
+    /// Returns a page of the contents of a specific user
     ///
     /// # Examples
     ///
     /// Example without parameters:
     /// ```
     /// # use tabnews::TabnewsClient;
+    /// # use tabnews::internal::pagination::Page;
     /// # use tabnews::models::content::Content;
-    /// # use tabnews::models::error::TabnewsError;
+    /// # use tabnews::Error;
     /// # #[tokio::main]
-    /// # async fn main() -> Result<(), TabnewsError> {
+    /// # async fn main() -> Result<(), Error> {
     /// let client = TabnewsClient::default();
     ///
-    /// let fadiinho_posts: Vec<Content> = client.posts_api
+    /// let fadiinho_posts: Page<Content> = client.posts_api
     ///     .get_posts_by_user("fadiinho", None).await?;
     ///
     /// assert!(fadiinho_posts.len() >= 0);
@@ -128,19 +153,20 @@ impl PostsApi {
     ///
     /// ```
     /// # use tabnews::TabnewsClient;
-    /// # use tabnews::models::content::{Content, ContentParams};
-    /// # use tabnews::models::error::TabnewsError;
+    /// # use tabnews::internal::pagination::Page;
+    /// # use tabnews::models::content::{Content, ContentParams, Strategy};
+    /// # use tabnews::Error;
     /// # #[tokio::main]
-    /// # async fn main() -> Result<(), TabnewsError> {
+    /// # async fn main() -> Result<(), Error> {
     /// let client = TabnewsClient::default();
     ///
-    /// let parameters = ContentParams {
-    ///     page: Some(1),
-    ///     per_page: Some(10),
-    ///     strategy: Some("old".to_owned())
-    /// };
+    /// let parameters = ContentParams::builder()
+    ///     .page(1)
+    ///     .per_page(10)
+    ///     .strategy(Strategy::Old)
+    ///     .build()?;
     ///
-    /// let fadiinho_posts: Vec<Content> = client.posts_api
+    /// let fadiinho_posts: Page<Content> = client.posts_api
     ///     .get_posts_by_user("fadiinho", Some(parameters)).await?;
     ///
     /// assert!(fadiinho_posts.len() >= 0);
@@ -150,19 +176,91 @@ impl PostsApi {
     pub async fn get_posts_by_user(
         &self,
         username: &str,
-        params: Option<ContentParams>,
-    ) -> Result<Vec<Content>, TabnewsError> {
-        let _params = self.build_params(params);
-
+        params: impl Into<ListingParams>,
+    ) -> Result<Page<Content>> {
         let uri = format!("/contents/{}", username);
 
-        let _client = self.tabnews_client.borrow();
+        self.get_contents_page(uri, params.into(), None).await
+    }
+
+    /// Streams every post of the homepage, requesting the pages lazily.
+    ///
+    /// `page` and `per_page` of `params` set the first page and the page
+    /// size, which can also be changed on the returned [`Paginated`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use futures::TryStreamExt;
+    /// # use tabnews::TabnewsClient;
+    /// # use tabnews::models::content::{Content, ContentParams, Strategy};
+    /// # use tabnews::Error;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// let client = TabnewsClient::default();
+    ///
+    /// let parameters = ContentParams::from(Strategy::New);
+    ///
+    /// let latest_posts: Vec<Content> = client.posts_api
+    ///     .stream_homepage_posts(Some(parameters))
+    ///     .limit(50)
+    ///     .try_collect()
+    ///     .await?;
+    ///
+    /// assert!(latest_posts.len() <= 50);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stream_homepage_posts(&self, params: impl Into<ListingParams>) -> Paginated<Content> {
+        let posts_api = self.clone();
 
-        let response = _client.get_with_params(uri, Some(&_params)).await.unwrap();
+        paginate_contents(params.into(), move |params| {
+            let posts_api = posts_api.clone();
 
-        let json_response: Vec<Content> = response.json().await.unwrap();
+            async move { posts_api.get_homepage_posts(params).await }
+        })
+    }
 
-        Ok(json_response)
+    /// Streams every content of a specific user, requesting the pages lazily.
+    ///
+    /// `page` and `per_page` of `params` set the first page and the page
+    /// size, which can also be changed on the returned [`Paginated`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use futures::StreamExt;
+    /// # use tabnews::TabnewsClient;
+    /// # use tabnews::Error;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// let client = TabnewsClient::default();
+    ///
+    /// let mut posts = client.posts_api
+    ///     .stream_posts_by_user("fadiinho", None)
+    ///     .per_page(100)
+    ///     .prefetch(true);
+    ///
+    /// while let Some(post) = posts.next().await {
+    ///     assert!(post?.slug.is_some());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stream_posts_by_user(
+        &self,
+        username: &str,
+        params: impl Into<ListingParams>,
+    ) -> Paginated<Content> {
+        let posts_api = self.clone();
+        let username = username.to_owned();
+
+        paginate_contents(params.into(), move |params| {
+            let posts_api = posts_api.clone();
+            let username = username.clone();
+
+            async move { posts_api.get_posts_by_user(&username, params).await }
+        })
     }
 
     /// Get the details of a specific post
@@ -172,9 +270,9 @@ impl PostsApi {
     /// ```
     /// # use tabnews::TabnewsClient;
     /// # use tabnews::models::content::Content;
-    /// # use tabnews::models::error::TabnewsError;
+    /// # use tabnews::Error;
     /// # #[tokio::main]
-    /// # async fn main() -> Result<(), TabnewsError> {
+    /// # async fn main() -> Result<(), Error> {
     /// let client = TabnewsClient::default();
     ///
     /// let post: Content = client.posts_api.get_post_details(
@@ -185,20 +283,17 @@ impl PostsApi {
     /// Ok(())
     /// # }
     /// ```
-    pub async fn get_post_details(
-        &self,
-        username: &str,
-        slug: &str,
-    ) -> Result<Content, TabnewsError> {
+    pub async fn get_post_details(&self, username: &str, slug: &str) -> Result<Content> {
         let _params = ContentParams::default();
 
         let uri = format!("/contents/{}/{}", username, slug);
 
-        let _client = self.tabnews_client.borrow();
-
-        let response = _client.get_with_params(uri, Some(&_params)).await.unwrap();
+        let response = self
+            .tabnews_client
+            .get_with_params(uri, Some(&_params))
+            .await?;
 
-        let json_response = response.json().await.unwrap();
+        let json_response = response.json()?;
 
         Ok(json_response)
     }
@@ -210,9 +305,9 @@ impl PostsApi {
     /// ```
     /// # use tabnews::TabnewsClient;
     /// # use tabnews::models::content::{Content, ContentParams};
-    /// # use tabnews::models::error::TabnewsError;
+    /// # use tabnews::Error;
     /// # #[tokio::main]
-    /// # async fn main() -> Result<(), TabnewsError> {
+    /// # async fn main() -> Result<(), Error> {
     /// let client = TabnewsClient::default();
     ///
     /// let post: Vec<Content> = client.posts_api.get_post_comments(
@@ -223,57 +318,156 @@ impl PostsApi {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_post_comments(
-        &self,
-        username: &str,
-        slug: &str,
-    ) -> Result<Vec<Content>, TabnewsError> {
+    pub async fn get_post_comments(&self, username: &str, slug: &str) -> Result<Vec<Content>> {
         let _params = ContentParams::default();
         let uri = format!("/contents/{}/{}/children", username, slug);
 
-        let _client = self.tabnews_client.borrow();
-
-        let response = _client.get_with_params(uri, Some(&_params)).await.unwrap();
+        let response = self
+            .tabnews_client
+            .get_with_params(uri, Some(&_params))
+            .await?;
 
-        let json_response = response.json().await.unwrap();
+        let json_response = response.json()?;
 
         Ok(json_response)
     }
 
+    /// Get the comments of a specific post arranged in a [`CommentTree`]
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use tabnews::TabnewsClient;
+    /// # use tabnews::models::comment_tree::CommentOrder;
+    /// # use tabnews::Error;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// let client = TabnewsClient::default();
+    ///
+    /// let mut tree = client.posts_api.get_comment_tree(
+    ///     "GabrielSozinho",
+    ///     "documentacao-da-api-do-tabnews"
+    /// ).await?;
+    /// tree.sort_by(CommentOrder::Tabcoins);
+    ///
+    /// for (depth, comment) in tree.flatten() {
+    ///     println!("{}{}", "  ".repeat(depth), comment.body.as_deref().unwrap_or_default());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_comment_tree(&self, username: &str, slug: &str) -> Result<CommentTree> {
+        let comments = self.get_post_comments(username, slug).await?;
+
+        Ok(CommentTree::new(comments))
+    }
+
     /// Get the thumbnail of a specific post
-    /// It returns a reqwest `Response`, that can be used to save the file.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use reqwest::Response;
     /// # use tabnews::TabnewsClient;
-    /// # use tabnews::models::content::{Content, ContentParams};
-    /// # use tabnews::models::error::TabnewsError;
+    /// # use tabnews::models::thumbnail::Thumbnail;
+    /// # use tabnews::Error;
     /// # #[tokio::main]
-    /// # async fn main() -> Result<(), TabnewsError> {
+    /// # async fn main() -> Result<(), Error> {
     /// let client = TabnewsClient::default();
     ///
-    /// let response: Response = client.posts_api.get_post_thumbnail(
+    /// let thumbnail: Thumbnail = client.posts_api.get_post_thumbnail(
     ///     "GabrielSozinho",
     ///     "documentacao-da-api-do-tabnews"
     /// ).await?;
+    ///
+    /// assert_eq!(thumbnail.content_type(), Some("image/png"));
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_post_thumbnail(
+    pub async fn get_post_thumbnail(&self, username: &str, slug: &str) -> Result<Thumbnail> {
+        let _params = ContentParams::default();
+        let uri = format!("/contents/{}/{}/thumbnail", username, slug);
+
+        let response = self
+            .tabnews_client
+            .get_with_params(uri, Some(&_params))
+            .await?;
+
+        Ok(Thumbnail::from(response))
+    }
+
+    /// Get the thumbnail of a specific post without keeping it in memory,
+    /// its bytes are read as they arrive
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use tabnews::TabnewsClient;
+    /// # use tabnews::Error;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// let client = TabnewsClient::default();
+    ///
+    /// let thumbnail = client.posts_api.stream_post_thumbnail(
+    ///     "GabrielSozinho",
+    ///     "documentacao-da-api-do-tabnews"
+    /// ).await?;
+    ///
+    /// let written = thumbnail.save_to("thumbnail.png").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn stream_post_thumbnail(
         &self,
         username: &str,
         slug: &str,
-    ) -> Result<Response, TabnewsError> {
-        let _params = ContentParams::default();
+    ) -> Result<ThumbnailStream> {
         let uri = format!("/contents/{}/{}/thumbnail", username, slug);
 
-        let _client = self.tabnews_client.borrow();
+        let response = self.tabnews_client.get_streaming(uri).await?;
 
-        let response = _client.get_with_params(uri, Some(&_params)).await.unwrap();
+        Ok(ThumbnailStream::from(response))
+    }
 
-        Ok(response)
+    /// Get the thumbnails of `contents`, in the same order, making at most
+    /// `concurrency` requests at a time
+    ///
+    /// A content without `owner_username` or `slug` gets an [`Error::InvalidInput`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use tabnews::TabnewsClient;
+    /// # use tabnews::Error;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// let client = TabnewsClient::default();
+    ///
+    /// let posts = client.posts_api.get_homepage_posts(None).await?;
+    /// let thumbnails = client.posts_api.get_thumbnails(&posts, 4).await;
+    ///
+    /// for (post, thumbnail) in posts.iter().zip(thumbnails) {
+    ///     thumbnail?.save_to(format!("{}.png", post.slug.as_deref().unwrap_or_default()))?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_thumbnails(
+        &self,
+        contents: &[Content],
+        concurrency: usize,
+    ) -> Vec<Result<Thumbnail>> {
+        stream::iter(contents)
+            .map(|content| async move {
+                match (&content.owner_username, &content.slug) {
+                    (Some(username), Some(slug)) => self.get_post_thumbnail(username, slug).await,
+                    _ => Err(Error::InvalidInput(
+                        "a thumbnail can't be requested without owner_username and slug".to_owned(),
+                    )),
+                }
+            })
+            .buffered(concurrency.max(1))
+            .collect()
+            .await
     }
 
     /// Get the parent of a specific post/comment
@@ -283,9 +477,9 @@ impl PostsApi {
     /// ```no_run
     /// # use tabnews::TabnewsClient;
     /// # use tabnews::models::content::Content;
-    /// # use tabnews::models::error::TabnewsError;
+    /// # use tabnews::Error;
     /// # #[tokio::main]
-    /// # async fn main() -> Result<(), TabnewsError> {
+    /// # async fn main() -> Result<(), Error> {
     /// let client = TabnewsClient::default();
     ///
     /// let post: Content = client.posts_api.get_post_parent(
@@ -297,18 +491,12 @@ impl PostsApi {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_post_parent(
-        &self,
-        username: &str,
-        slug: &str,
-    ) -> Result<Content, TabnewsError> {
+    pub async fn get_post_parent(&self, username: &str, slug: &str) -> Result<Content> {
         let uri = format!("/contents/{}/{}/parent", username, slug);
 
-        let _client = self.tabnews_client.borrow();
-
-        let response = _client.get(uri).await.unwrap();
+        let response = self.tabnews_client.get(uri).await?;
 
-        let json_response = response.json().await.unwrap();
+        let json_response = response.json()?;
 
         Ok(json_response)
     }
@@ -320,9 +508,9 @@ impl PostsApi {
     /// ```no_run
     /// # use tabnews::TabnewsClient;
     /// # use tabnews::models::content::Content;
-    /// # use tabnews::models::error::TabnewsError;
+    /// # use tabnews::Error;
     /// # #[tokio::main]
-    /// # async fn main() -> Result<(), TabnewsError> {
+    /// # async fn main() -> Result<(), Error> {
     /// let client = TabnewsClient::default();
     ///
     /// let post: Content = client.posts_api.get_post_root(
@@ -334,27 +522,148 @@ impl PostsApi {
     /// Ok(())
     /// # }
     /// ```
-    pub async fn get_post_root(&self, username: &str, slug: &str) -> Result<Content, TabnewsError> {
+    pub async fn get_post_root(&self, username: &str, slug: &str) -> Result<Content> {
         let uri = format!("/contents/{}/{}/root", username, slug);
 
-        let _client = self.tabnews_client.borrow();
+        let response = self.tabnews_client.get(uri).await?;
 
-        let response = _client.get(uri).await.unwrap();
-
-        let json_response = response.json().await.unwrap();
+        let json_response = response.json()?;
 
         Ok(json_response)
     }
+
+    /// Get the chain of contents from a specific post/comment up to its root post
+    ///
+    /// # Errors
+    ///
+    /// It will return [`Error::Cycle`] if the parents lead back to a content
+    /// already in the chain
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use tabnews::TabnewsClient;
+    /// # use tabnews::models::ancestors::Ancestors;
+    /// # use tabnews::Error;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// let client = TabnewsClient::default();
+    ///
+    /// let ancestors: Ancestors = client.posts_api.get_ancestors(
+    ///     "GabrielSozinho",
+    ///     "ae53bb7c-264f-4ad1-aeb0-340dde9b1647"
+    /// ).await?;
+    ///
+    /// // The reply, its parent and its grandparent
+    /// for content in ancestors.context(2) {
+    ///     println!("{}", content.body.as_deref().unwrap_or_default());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_ancestors(&self, username: &str, slug: &str) -> Result<Ancestors> {
+        self.get_ancestors_with(username, slug, &mut KnownContents::default())
+            .await
+    }
+
+    /// Same as [`PostsApi::get_ancestors`], but only requests the contents
+    /// missing from `known`, adding them to it as they are fetched
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use tabnews::TabnewsClient;
+    /// # use tabnews::models::ancestors::KnownContents;
+    /// # use tabnews::Error;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// let client = TabnewsClient::default();
+    ///
+    /// let tree = client.posts_api.get_comment_tree(
+    ///     "GabrielSozinho",
+    ///     "documentacao-da-api-do-tabnews"
+    /// ).await?;
+    /// let mut known = KnownContents::from(tree);
+    ///
+    /// // Only the root post is requested, the comments are already known
+    /// let ancestors = client.posts_api.get_ancestors_with(
+    ///     "GabrielSozinho",
+    ///     "ae53bb7c-264f-4ad1-aeb0-340dde9b1647",
+    ///     &mut known,
+    /// ).await?;
+    ///
+    /// assert_eq!(ancestors.depth(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_ancestors_with(
+        &self,
+        username: &str,
+        slug: &str,
+        known: &mut KnownContents,
+    ) -> Result<Ancestors> {
+        let content = match known.find(username, slug) {
+            Some(content) => content.clone(),
+            None => {
+                let content = self.get_post_details(username, slug).await?;
+                known.insert(content.clone());
+
+                content
+            }
+        };
+
+        let mut visited: HashSet<String> = content.id.iter().cloned().collect();
+        let mut ancestors: Vec<Content> = Vec::new();
+
+        loop {
+            let current = ancestors.last().unwrap_or(&content);
+
+            let parent_id = match &current.parent_id {
+                Some(parent_id) => parent_id.clone(),
+                None => break,
+            };
+
+            if !visited.insert(parent_id.clone()) {
+                return Err(Error::Cycle(parent_id));
+            }
+
+            let parent = match known.get(&parent_id) {
+                Some(parent) => parent.clone(),
+                None => {
+                    let (owner, slug) = current
+                        .owner_username
+                        .as_deref()
+                        .zip(current.slug.as_deref())
+                        .ok_or_else(|| {
+                            Error::InvalidInput(format!(
+                                "parent {} can't be requested without the owner_username and slug of its child",
+                                parent_id
+                            ))
+                        })?;
+
+                    let parent = self.get_post_parent(owner, slug).await?;
+                    known.insert(parent.clone());
+
+                    parent
+                }
+            };
+
+            ancestors.push(parent);
+        }
+
+        Ok(Ancestors::new(content, ancestors))
+    }
+
     /// Get the tabcoins of a post
     ///
     /// # Examples
     ///
     /// ```
     /// # use tabnews::TabnewsClient;
-    /// # use tabnews::models::error::TabnewsError;
+    /// # use tabnews::Error;
     ///
     /// # #[tokio::main]
-    /// # async fn main() -> Result<(), TabnewsError> {
+    /// # async fn main() -> Result<(), Error> {
     /// let client = TabnewsClient::default();
     ///
     /// let tabcoins: i64 = client.posts_api.get_post_tabcoins(
@@ -369,11 +678,10 @@ impl PostsApi {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_post_tabcoins(&self, username: &str, slug: &str) -> Result<i64, TabnewsError> {
-        let response = self.get_post_details(username, slug).await.unwrap();
+    pub async fn get_post_tabcoins(&self, username: &str, slug: &str) -> Result<i64> {
+        let response = self.get_post_details(username, slug).await?;
 
-        dbg!(&response);
-        Ok(response.tabcoins.unwrap())
+        Ok(response.tabcoins.unwrap_or_default())
     }
 
     async fn _tabcoins_operation(
@@ -381,11 +689,9 @@ impl PostsApi {
         username: &str,
         slug: &str,
         transaction_type: TabcoinsTransaction,
-    ) -> Result<Tabcoins, TabnewsError> {
+    ) -> Result<Tabcoins> {
         let uri = format!("/contents/{}/{}/tabcoins", username, slug);
 
-        let _client = self.tabnews_client.borrow();
-
         let mut body: HashMap<&str, &str> = HashMap::new();
 
         body.insert(
@@ -396,9 +702,9 @@ impl PostsApi {
             },
         );
 
-        let response = _client.post(uri, body).await.unwrap();
+        let response = self.tabnews_client.post(uri, body).await?;
 
-        let json_response = response.json().await.unwrap();
+        let json_response = response.json()?;
 
         Ok(json_response)
     }
@@ -407,16 +713,16 @@ impl PostsApi {
     /// Downvote a post.
     /// It will cost tabcoins of your account.
     ///
-    /// # Panics
-    /// It will panic if the cookie header isn't set.
+    /// # Errors
+    /// The server will refuse the request if the cookie header isn't set.
     ///
     /// # Examples
     /// ```no_run
     /// # use tabnews::models::content::Tabcoins;
     /// # use tabnews::TabnewsClient;
-    /// # use tabnews::models::error::TabnewsError;
+    /// # use tabnews::Error;
     /// # #[tokio::main]
-    /// # async fn main () -> Result<(), TabnewsError> {
+    /// # async fn main () -> Result<(), Error> {
     /// let client = TabnewsClient::default();
     ///
     /// let tabcoins: Tabcoins = client.posts_api.downvote(
@@ -426,7 +732,7 @@ impl PostsApi {
     /// #     Ok(())
     /// # }
     /// ```
-    pub async fn downvote(&self, username: &str, slug: &str) -> Result<Tabcoins, TabnewsError> {
+    pub async fn downvote(&self, username: &str, slug: &str) -> Result<Tabcoins> {
         self._tabcoins_operation(username, slug, TabcoinsTransaction::Debit)
             .await
     }
@@ -434,16 +740,16 @@ impl PostsApi {
     /// Upvote a post.
     /// It will cost tabcoins of your account.
     ///
-    /// # Panics
-    /// It will panic if the cookie header isn't set.
+    /// # Errors
+    /// The server will refuse the request if the cookie header isn't set.
     ///
     /// # Examples
     /// ```no_run
     /// # use tabnews::models::content::Tabcoins;
     /// # use tabnews::TabnewsClient;
-    /// # use tabnews::models::error::TabnewsError;
+    /// # use tabnews::Error;
     /// # #[tokio::main]
-    /// # async fn main () -> Result<(), TabnewsError> {
+    /// # async fn main () -> Result<(), Error> {
     /// let client = TabnewsClient::default();
     ///
     /// let tabcoins: Tabcoins = client.posts_api.upvote(
@@ -453,7 +759,7 @@ impl PostsApi {
     /// #     Ok(())
     /// # }
     /// ```
-    pub async fn upvote(&self, username: &str, slug: &str) -> Result<Tabcoins, TabnewsError> {
+    pub async fn upvote(&self, username: &str, slug: &str) -> Result<Tabcoins> {
         self._tabcoins_operation(username, slug, TabcoinsTransaction::Credit)
             .await
     }
@@ -463,9 +769,9 @@ impl PostsApi {
     /// # Examples
     /// ```
     /// # use tabnews::TabnewsClient;
-    /// # use tabnews::models::error::TabnewsError;
+    /// # use tabnews::Error;
     /// # #[tokio::main]
-    /// # async fn main () -> Result<(), TabnewsError> {
+    /// # async fn main () -> Result<(), Error> {
     /// let client = TabnewsClient::default();
     ///
     /// let rss: String = client.posts_api.get_rss().await?;
@@ -474,21 +780,45 @@ impl PostsApi {
     /// #     Ok(())
     /// # }
     /// ```
-    pub async fn get_rss(&self) -> Result<String, TabnewsError> {
-        let _client = self.tabnews_client.borrow();
+    pub async fn get_rss(&self) -> Result<String> {
+        let response = self.tabnews_client.get("/contents/rss".to_owned()).await?;
 
-        let response = _client.get("/contents/rss".to_owned()).await.unwrap();
+        Ok(response.text())
+    }
 
-        Ok(response.text().await.unwrap())
+    /// Get TabNews RSS as a typed [`RssFeed`]
+    ///
+    /// Each item can be passed back to [`PostsApi::get_post_details`].
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use tabnews::TabnewsClient;
+    /// # use tabnews::Error;
+    /// # #[tokio::main]
+    /// # async fn main () -> Result<(), Error> {
+    /// let client = TabnewsClient::default();
+    ///
+    /// let feed = client.posts_api.get_rss_feed().await?;
+    ///
+    /// if let Some((username, slug)) = feed.items[0].username_and_slug() {
+    ///     let post = client.posts_api.get_post_details(username, slug).await?;
+    ///     println!("{:?}", post.title);
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub async fn get_rss_feed(&self) -> Result<RssFeed> {
+        self.get_rss().await?.parse()
     }
 
     /// Publish a content
-    async fn _publish(&self, content: Content) -> Result<Content, TabnewsError> {
-        let _client = self.tabnews_client.borrow();
-
-        let response = _client.post("/contents".to_owned(), content).await.unwrap();
+    async fn _publish(&self, content: Content) -> Result<Content> {
+        let response = self
+            .tabnews_client
+            .post("/contents".to_owned(), content)
+            .await?;
 
-        let json_response = response.json().await.unwrap();
+        let json_response = response.json()?;
 
         Ok(json_response)
     }
@@ -498,9 +828,9 @@ impl PostsApi {
     /// # Examples
     /// ```no_run
     /// # use tabnews::models::content::Content;
-    /// # use tabnews::TabnewsClient;
+    /// # use tabnews::{Error, TabnewsClient};
     /// # #[tokio::main]
-    /// # async fn main() {
+    /// # async fn main() -> Result<(), Error> {
     /// let client = TabnewsClient::default();
     /// let mut post = Content::default();
     ///
@@ -509,29 +839,243 @@ impl PostsApi {
     /// post.set_source_url("https://optional-source-url.com");
     /// post.set_slug("optional-slug");
     ///
-    /// let response = client.posts_api.publish_post(post).await;
+    /// let response = client.posts_api.publish_post(post).await?;
     ///
     /// assert!(response.id.is_some());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn publish_post(&self, content: Content) -> Result<Content> {
+        self._publish(content).await
+    }
+
+    /// Changes the post or comment `slug` of `username` and returns it updated.
+    ///
+    /// Requires being logged in as its owner.
+    ///
+    /// # Errors
+    ///
+    /// It will return [`Error::InvalidInput`] if no field of `patch` was set
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use tabnews::models::content::ContentPatch;
+    /// # use tabnews::{Error, TabnewsClient};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// let client = TabnewsClient::default();
+    ///
+    /// let patch = ContentPatch::default()
+    ///     .title("Cool Title, Now Fixed")
+    ///     .body("Cool text/markdown, with fewer typos");
+    ///
+    /// let response = client
+    ///     .posts_api
+    ///     .update_content("fadiinho", "cool-title", patch)
+    ///     .await?;
+    ///
+    /// assert_eq!(response.title.as_deref(), Some("Cool Title, Now Fixed"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_content(
+        &self,
+        username: &str,
+        slug: &str,
+        patch: ContentPatch,
+    ) -> Result<Content> {
+        if patch.is_empty() {
+            return Err(Error::InvalidInput(
+                "At least one field of the patch must be set!".to_owned(),
+            ));
+        }
+
+        let uri = format!("/contents/{}/{}", username, slug);
+
+        let response = self.tabnews_client.patch(uri, patch).await?;
+
+        let json_response = response.json()?;
+
+        Ok(json_response)
+    }
+
+    /// Deletes the post or comment `slug` of `username` and returns it.
+    ///
+    /// Requires being logged in as its owner. Deleted contents can't be
+    /// restored or changed anymore.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use tabnews::models::content::ContentStatus;
+    /// # use tabnews::{Error, TabnewsClient};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// let client = TabnewsClient::default();
+    ///
+    /// let deleted = client.posts_api.delete_content("fadiinho", "cool-title").await?;
+    ///
+    /// assert_eq!(deleted.status, Some(ContentStatus::Deleted));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_content(&self, username: &str, slug: &str) -> Result<Content> {
+        let patch = ContentPatch::default().status(ContentStatus::Deleted);
+
+        self.update_content(username, slug, patch).await
+    }
+
+    /// Turns the post or comment `slug` of `username` back into a draft,
+    /// only visible to its owner, and returns it.
+    ///
+    /// Requires being logged in as its owner.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use tabnews::models::content::ContentStatus;
+    /// # use tabnews::{Error, TabnewsClient};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// let client = TabnewsClient::default();
+    ///
+    /// let draft = client.posts_api.unpublish_content("fadiinho", "cool-title").await?;
+    ///
+    /// assert_eq!(draft.status, Some(ContentStatus::Draft));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn unpublish_content(&self, username: &str, slug: &str) -> Result<Content> {
+        let patch = ContentPatch::default().status(ContentStatus::Draft);
+
+        self.update_content(username, slug, patch).await
+    }
+
+    /// Creates `content` as a draft, only visible to its owner, and returns it
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use tabnews::models::content::{Content, ContentStatus};
+    /// # use tabnews::{Error, TabnewsClient};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// let client = TabnewsClient::default();
+    /// let mut post = Content::default();
+    ///
+    /// post.set_title("Work in Progress");
+    /// post.set_body("Cool text/markdown, still being written");
+    ///
+    /// let draft = client.posts_api.create_draft(post).await?;
+    ///
+    /// assert_eq!(draft.status, Some(ContentStatus::Draft));
+    /// # Ok(())
     /// # }
     /// ```
-    pub async fn publish_post(&self, content: Content) -> Content {
-        let response = self._publish(content).await.unwrap();
+    pub async fn create_draft(&self, mut content: Content) -> Result<Content> {
+        content.set_status(ContentStatus::Draft);
 
-        response
+        self._publish(content).await
+    }
+
+    /// Returns a page of the drafts of the current user
+    ///
+    /// # Errors
+    ///
+    /// It will return [`Error::NotLoggedIn`] if the client isn't logged in
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use tabnews::models::content::ContentStatus;
+    /// # use tabnews::{Error, TabnewsClient};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// let client = TabnewsClient::default();
+    ///
+    /// let drafts = client.posts_api.list_drafts(None).await?;
+    ///
+    /// assert!(drafts.iter().all(|d| d.status == Some(ContentStatus::Draft)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_drafts(&self, params: impl Into<ListingParams>) -> Result<Page<Content>> {
+        let user = UserApi::new(Arc::clone(&self.tabnews_client))
+            .get_current_user()
+            .await?;
+
+        let uri = format!("/contents/{}", user.username);
+
+        self.get_contents_page(uri, params.into(), Some(ContentStatus::Draft))
+            .await
+    }
+
+    /// Returns the draft `slug` of `username` as it will be published.
+    ///
+    /// Requires being logged in as its owner, as drafts aren't visible to
+    /// anyone else.
+    ///
+    /// # Errors
+    ///
+    /// It will return [`Error::InvalidInput`] if the content isn't a draft
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use tabnews::{Error, TabnewsClient};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// let client = TabnewsClient::default();
+    ///
+    /// let draft = client.posts_api.preview_draft("fadiinho", "work-in-progress").await?;
+    ///
+    /// println!("{}", draft.body.unwrap_or_default());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn preview_draft(&self, username: &str, slug: &str) -> Result<Content> {
+        let content = self.get_post_details(username, slug).await?;
+
+        if content.status != Some(ContentStatus::Draft) {
+            return Err(Error::InvalidInput(format!(
+                "{}/{} is not a draft",
+                username, slug
+            )));
+        }
+
+        Ok(content)
+    }
+
+    /// Publishes the draft `slug` of `username`, keeping its slug, and returns it
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use tabnews::models::content::ContentStatus;
+    /// # use tabnews::{Error, TabnewsClient};
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// let client = TabnewsClient::default();
+    ///
+    /// let post = client.posts_api.publish_draft("fadiinho", "work-in-progress").await?;
+    ///
+    /// assert_eq!(post.status, Some(ContentStatus::Published));
+    /// assert_eq!(post.slug.as_deref(), Some("work-in-progress"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn publish_draft(&self, username: &str, slug: &str) -> Result<Content> {
+        let patch = ContentPatch::default().status(ContentStatus::Published);
+
+        self.update_content(username, slug, patch).await
     }
 
     /// Publish a comment and returns it
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// It will panic if `parent_id` was not set
+    /// It will return [`Error::InvalidInput`] if `parent_id` was not set
     ///
     /// # Examples
     /// ```no_run
     /// # use tabnews::models::content::Content;
-    /// # use tabnews::TabnewsClient;
+    /// # use tabnews::{Error, TabnewsClient};
     /// # #[tokio::main]
-    /// # async fn main()  {
+    /// # async fn main() -> Result<(), Error> {
     /// let client = TabnewsClient::default();
     /// let mut comment = Content::default();
     ///
@@ -540,18 +1084,43 @@ impl PostsApi {
     /// comment.set_slug("optional-slug");
     /// comment.set_parent_id("<parent-id>");
     ///
-    /// let response = client.posts_api.publish_comment(comment).await.unwrap();
+    /// let response = client.posts_api.publish_comment(comment).await?;
     ///
     /// assert!(response.id.is_some());
+    /// # Ok(())
     /// # }
     /// ```
-    pub async fn publish_comment(&self, content: Content) -> Result<Content, &str> {
+    pub async fn publish_comment(&self, content: Content) -> Result<Content> {
         if content.parent_id.is_none() {
-            return Err("To post a comment `parent_id` must be set!");
+            return Err(Error::InvalidInput(
+                "To post a comment `parent_id` must be set!".to_owned(),
+            ));
         }
 
-        let response = self._publish(content).await.unwrap();
+        self._publish(content).await
+    }
+}
 
-        Ok(response)
+/// Pages through a content listing, `fetch` getting the params of each page
+fn paginate_contents<F, Fut>(params: ListingParams, fetch: F) -> Paginated<Content>
+where
+    F: Fn(ListingParams) -> Fut + Send + Sync + 'static,
+    Fut: std::future::Future<Output = Result<Page<Content>>> + Send + 'static,
+{
+    let start_page = params.params.page.unwrap_or(1);
+    let per_page = params.params.per_page;
+
+    let paginated = Paginated::new(move |page, per_page| {
+        let mut params = params.clone();
+        params.params.page = Some(page);
+        params.params.per_page = Some(per_page);
+
+        fetch(params)
+    })
+    .start_page(start_page);
+
+    match per_page {
+        Some(per_page) => paginated.per_page(per_page),
+        None => paginated,
     }
 }
@@ -1,24 +1,25 @@
+use crate::models::error::{Error, Result};
 use crate::models::user::User;
 
-use std::cell::RefCell;
-use std::rc::Rc;
+use std::sync::Arc;
 
 use super::http_client::HttpClient;
 
+#[derive(Clone)]
 pub struct UserApi {
-    tabnews_client: Rc<RefCell<HttpClient>>,
+    tabnews_client: Arc<HttpClient>,
 }
 
 impl Default for UserApi {
     fn default() -> Self {
-        let tabnews_client = Rc::new(RefCell::new(HttpClient::default()));
+        let tabnews_client = Arc::new(HttpClient::default());
 
         UserApi::new(tabnews_client)
     }
 }
 
 impl UserApi {
-    pub fn new(client: Rc<RefCell<HttpClient>>) -> Self {
+    pub fn new(client: Arc<HttpClient>) -> Self {
         UserApi {
             tabnews_client: client,
         }
@@ -26,37 +27,33 @@ impl UserApi {
 
     /// Get current user information
     ///
-    /// # Panics
+    /// # Errors
     ///
-    /// If the `Cookie` header is not set (not logged in), this code will panic.
+    /// If the `Cookie` header is not set (not logged in), it returns [`Error::NotLoggedIn`].
     ///
     /// # Examples
     ///
     /// ```no_run
     /// # use std::collections::HashMap;
-    /// # use tabnews::TabnewsClient;
+    /// # use tabnews::{Error, TabnewsClient};
     /// # use tabnews::models::user::User;
-    /// # use tabnews::models::error::TabnewsError;
     /// # #[tokio::main]
-    /// # async fn main() -> Result<(), TabnewsError> {
+    /// # async fn main() -> Result<(), Error> {
     /// let client = TabnewsClient::default();
     ///
-    /// let user: User = client.user_api.get_current_user().await.unwrap();
+    /// let user: User = client.user_api.get_current_user().await?;
     ///
     /// assert!(!user.id.is_empty());
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_current_user(&self) -> Result<User, &str> {
-        let _client = self.tabnews_client.borrow();
-        if _client.get_header("Cookie").is_err() {
-            return Err("`Cookie` header doesn't exists. In order to use `get_current_user()`, `Cookie` with `session_id=<token>` value is required");
+    pub async fn get_current_user(&self) -> Result<User> {
+        if self.tabnews_client.get_header("Cookie").is_err() {
+            return Err(Error::NotLoggedIn);
         }
 
-        let response = _client.get("/user".to_owned()).await.unwrap();
+        let response = self.tabnews_client.get("/user".to_owned()).await?;
 
-        let json_response: User = response.json().await.unwrap();
-
-        Ok(json_response)
+        response.json()
     }
 }
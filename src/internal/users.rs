@@ -2,27 +2,28 @@ use crate::models::user::EditProfilePayload;
 
 use std::collections::HashMap;
 
-use std::cell::RefCell;
-use std::rc::Rc;
+use std::sync::Arc;
 
-use crate::models::{error::TabnewsError, user::User};
+use crate::models::{error::Result, user::User};
 
-use super::http_client::HttpClient;
+use super::http_client::{HttpClient, HttpResponse};
+use super::pagination::{Page, Paginated, DEFAULT_PER_PAGE};
 
+#[derive(Clone)]
 pub struct UsersApi {
-    tabnews_client: Rc<RefCell<HttpClient>>,
+    tabnews_client: Arc<HttpClient>,
 }
 
 impl Default for UsersApi {
     fn default() -> Self {
-        let tabnews_client = Rc::new(RefCell::new(HttpClient::default()));
+        let tabnews_client = Arc::new(HttpClient::default());
 
         UsersApi::new(tabnews_client)
     }
 }
 
 impl UsersApi {
-    pub fn new(client: Rc<RefCell<HttpClient>>) -> Self {
+    pub fn new(client: Arc<HttpClient>) -> Self {
         UsersApi {
             tabnews_client: client,
         }
@@ -30,24 +31,15 @@ impl UsersApi {
 
     // Not tested yet!
     #[allow(dead_code)]
-    async fn create_user(
-        &self,
-        username: String,
-        email: String,
-        password: String,
-    ) -> Result<User, TabnewsError> {
+    async fn create_user(&self, username: String, email: String, password: String) -> Result<User> {
         let mut body: HashMap<String, String> = HashMap::new();
         body.insert("username".to_owned(), username);
         body.insert("email".to_owned(), email);
         body.insert("password".to_owned(), password);
 
-        let _client = self.tabnews_client.borrow();
-
-        let response = _client.post("/users".to_owned(), body).await.unwrap();
-
-        let json_response: User = response.json().await.unwrap();
+        let response = self.tabnews_client.post("/users".to_owned(), body).await?;
 
-        Ok(json_response)
+        response.json()
     }
 
     // Not tested yet!
@@ -59,43 +51,77 @@ impl UsersApi {
         // the user won't need to set the `current_username`.
         current_username: String,
         payload: EditProfilePayload,
-    ) -> reqwest::Response {
+    ) -> Result<HttpResponse> {
         let url = format!("users/{}", current_username);
 
-        let _client = self.tabnews_client.borrow();
+        self.tabnews_client.patch(url, payload).await
+    }
 
-        let response = _client.patch(url, payload).await.unwrap();
+    /// List the first page of users
+    /// It can only be used by users that have permissions "read:user:list"
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # use tabnews::TabnewsClient;
+    /// # use tabnews::internal::pagination::Page;
+    /// # use tabnews::models::user::User;
+    /// # use tabnews::Error;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Error> {
+    /// let client = TabnewsClient::default();
+    /// let users: Page<User>  = client.users_api.list_all_users().await?;
+    ///
+    /// assert!(users.len() >= 0);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_all_users(&self) -> Result<Page<User>> {
+        self.list_users_page(1, DEFAULT_PER_PAGE).await
+    }
 
-        // let json_response = response.json().await.unwrap();
+    /// List the users of a specific page
+    /// It can only be used by users that have permissions "read:user:list"
+    pub async fn list_users_page(&self, page: u64, per_page: u64) -> Result<Page<User>> {
+        let params = [("page", page), ("per_page", per_page)];
+
+        let response = self
+            .tabnews_client
+            .get_with_params("/users".to_owned(), Some(&params))
+            .await?;
 
-        response
+        Page::from_response(&response, page, per_page)
     }
 
-    /// List all users
+    /// Streams every user, requesting the pages lazily.
     /// It can only be used by users that have permissions "read:user:list"
     ///
     /// # Examples
     /// ```no_run
+    /// # use futures::TryStreamExt;
     /// # use tabnews::TabnewsClient;
     /// # use tabnews::models::user::User;
-    /// # use tabnews::models::error::TabnewsError;
+    /// # use tabnews::Error;
     /// # #[tokio::main]
-    /// # async fn main() -> Result<(), TabnewsError> {
+    /// # async fn main() -> Result<(), Error> {
     /// let client = TabnewsClient::default();
-    /// let users: Vec<User>  = client.users_api.list_all_users().await?;
+    /// let users: Vec<User> = client.users_api
+    ///     .stream_all_users()
+    ///     .per_page(100)
+    ///     .try_collect()
+    ///     .await?;
     ///
     /// assert!(users.len() >= 0);
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn list_all_users(&self) -> Result<Vec<User>, TabnewsError> {
-        let _client = self.tabnews_client.borrow();
-
-        let response = _client.get("/users".to_owned()).await.unwrap();
+    pub fn stream_all_users(&self) -> Paginated<User> {
+        let users_api = self.clone();
 
-        let json_response = response.json().await.unwrap();
+        Paginated::new(move |page, per_page| {
+            let users_api = users_api.clone();
 
-        Ok(json_response)
+            async move { users_api.list_users_page(page, per_page).await }
+        })
     }
 
     /// Get user info
@@ -104,9 +130,9 @@ impl UsersApi {
     /// ```
     /// # use tabnews::TabnewsClient;
     /// # use tabnews::models::user::User;
-    /// # use tabnews::models::error::TabnewsError;
+    /// # use tabnews::Error;
     /// # #[tokio::main]
-    /// # async fn main() -> Result<(), TabnewsError> {
+    /// # async fn main() -> Result<(), Error> {
     /// let client = TabnewsClient::default();
     /// let user: User  = client.users_api.get_user("fadiinho").await?;
     ///
@@ -114,14 +140,10 @@ impl UsersApi {
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_user(&self, username: &str) -> Result<User, TabnewsError> {
+    pub async fn get_user(&self, username: &str) -> Result<User> {
         let uri = format!("/users/{}", username);
-        let _client = self.tabnews_client.borrow();
-
-        let response = _client.get(uri).await.unwrap();
-
-        let json_response = response.json().await.unwrap();
+        let response = self.tabnews_client.get(uri).await?;
 
-        Ok(json_response)
+        response.json()
     }
 }
@@ -1,13 +1,20 @@
 extern crate reqwest;
 extern crate serde;
 
+#[cfg(feature = "blocking")]
+pub mod blocking;
+pub mod builder;
+pub mod feed;
+#[cfg(feature = "html")]
+pub mod html;
 pub mod internal;
 pub mod models;
+#[cfg(feature = "testing")]
+pub mod testing;
 pub mod utils;
 
-use std::cell::RefCell;
 use std::collections::HashMap;
-use std::rc::Rc;
+use std::sync::Arc;
 
 use internal::analytics::AnalyticsApi;
 use internal::auth::AuthApi;
@@ -17,70 +24,81 @@ use internal::user::UserApi;
 use internal::users::UsersApi;
 use utils::get_preview_url;
 
+pub use builder::TabnewsClientBuilder;
+pub use models::error::{Error, Result};
+
+/// Entry point of the crate.
+///
+/// Every API group shares the same [`HttpClient`], so logging in through
+/// `auth_api` is seen by all of them. The client is `Send + Sync` and
+/// cheap to clone, so it can be shared between tokio tasks.
+#[derive(Clone)]
 pub struct TabnewsClient {
     pub posts_api: PostsApi,
     pub analytics_api: AnalyticsApi,
     pub user_api: UserApi,
     pub users_api: UsersApi,
     pub auth_api: AuthApi,
-    pub http_client: Rc<RefCell<HttpClient>>,
+    pub http_client: Arc<HttpClient>,
 }
 
 impl Default for TabnewsClient {
     fn default() -> Self {
-        let client = Rc::new(RefCell::new(HttpClient::default()));
-
-        TabnewsClient {
-            http_client: Rc::clone(&client),
-            posts_api: PostsApi::new(Rc::clone(&client)),
-            analytics_api: AnalyticsApi::new(Rc::clone(&client)),
-            user_api: UserApi::new(Rc::clone(&client)),
-            users_api: UsersApi::new(Rc::clone(&client)),
-            auth_api: AuthApi::new(Rc::clone(&client)),
-        }
+        TabnewsClient::from_http_client(Arc::new(HttpClient::default()))
     }
 }
 
 impl TabnewsClient {
-    pub fn new(headers: HashMap<String, String>) -> Self {
-        let client = Rc::new(RefCell::new(HttpClient::default()));
+    /// Starts a [`TabnewsClientBuilder`] to configure the host, timeouts,
+    /// proxy, headers and session of a new client
+    pub fn builder() -> TabnewsClientBuilder {
+        TabnewsClientBuilder::default()
+    }
 
-        {
-            let mut _client = client.borrow_mut();
+    pub fn new(headers: HashMap<String, String>) -> Result<Self> {
+        let client = Arc::new(HttpClient::default());
 
-            _client.add_multiple_headers(headers);
-        }
+        client.add_multiple_headers(headers)?;
 
+        Ok(TabnewsClient::from_http_client(client))
+    }
+
+    /// Creates a [`TabnewsClient`] whose API groups share `client`
+    pub fn from_http_client(client: Arc<HttpClient>) -> Self {
         TabnewsClient {
-            http_client: Rc::clone(&client),
-            posts_api: PostsApi::new(Rc::clone(&client)),
-            analytics_api: AnalyticsApi::new(Rc::clone(&client)),
-            user_api: UserApi::new(Rc::clone(&client)),
-            users_api: UsersApi::new(Rc::clone(&client)),
-            auth_api: AuthApi::new(Rc::clone(&client)),
+            http_client: Arc::clone(&client),
+            posts_api: PostsApi::new(Arc::clone(&client)),
+            analytics_api: AnalyticsApi::new(Arc::clone(&client)),
+            user_api: UserApi::new(Arc::clone(&client)),
+            users_api: UsersApi::new(Arc::clone(&client)),
+            auth_api: AuthApi::new(Arc::clone(&client)),
         }
     }
 
-    pub async fn use_preview_url(self) -> Self {
-        let preview_url = get_preview_url().await;
+    pub async fn use_preview_url(self) -> Result<Self> {
+        let preview_url = get_preview_url().await?;
 
-        {
-            let mut client = self.http_client.borrow_mut();
+        self.http_client.set_host(format!("{}/api/v1", preview_url));
 
-            client.set_host(format!("{}/api/v1", preview_url));
-        }
-
-        self
+        Ok(self)
     }
 
     pub fn is_logged(&self) -> bool {
-        let _client = self.http_client.borrow();
+        self.http_client.get_header("Cookie").is_ok()
+    }
 
-        _client.get_header("Cookie").is_ok()
+    /// Emits a `tracing` debug event with the host and session state of the client
+    pub fn trace_configuration(&self) {
+        tracing::debug!(
+            host = %self.http_client.host(),
+            logged = self.is_logged(),
+            retry_policy = ?self.http_client.retry_policy(),
+            "tabnews client configuration"
+        );
     }
 
+    #[deprecated(note = "use `trace_configuration`, which goes through `tracing`")]
     pub fn debug_dump_host(&self) {
-        let _client = self.http_client.borrow();
-        dbg!(&_client.host);
+        self.trace_configuration();
     }
 }
@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+
+use super::comment_tree::CommentTree;
+use super::content::Content;
+
+/// A content and the chain of contents it answers, up to the root post,
+/// returned by [`PostsApi::get_ancestors`](crate::internal::posts::PostsApi::get_ancestors)
+///
+/// # Examples
+///
+/// ```
+/// # use tabnews::models::ancestors::Ancestors;
+/// # use tabnews::models::content::Content;
+/// # fn main() -> Result<(), serde_json::Error> {
+/// let reply: Content = serde_json::from_str(r#"{ "id": "c", "parent_id": "b" }"#)?;
+/// let comment: Content = serde_json::from_str(r#"{ "id": "b", "parent_id": "a" }"#)?;
+/// let post: Content = serde_json::from_str(r#"{ "id": "a" }"#)?;
+///
+/// let ancestors = Ancestors::new(reply, vec![comment, post]);
+///
+/// assert_eq!(ancestors.root().id.as_deref(), Some("a"));
+///
+/// let context: Vec<_> = ancestors
+///     .context(1)
+///     .iter()
+///     .map(|content| content.id.as_deref().unwrap())
+///     .collect();
+/// assert_eq!(context, ["b", "c"]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Ancestors {
+    content: Content,
+    ancestors: Vec<Content>,
+}
+
+impl Ancestors {
+    /// `ancestors` goes from the parent of `content` up to the root post
+    pub fn new(content: Content, ancestors: Vec<Content>) -> Self {
+        Ancestors { content, ancestors }
+    }
+
+    /// The content the chain starts from
+    pub fn content(&self) -> &Content {
+        &self.content
+    }
+
+    pub fn parent(&self) -> Option<&Content> {
+        self.ancestors.first()
+    }
+
+    pub fn grandparent(&self) -> Option<&Content> {
+        self.ancestors.get(1)
+    }
+
+    /// The post at the top of the chain, the content itself when it is a post
+    pub fn root(&self) -> &Content {
+        self.ancestors.last().unwrap_or(&self.content)
+    }
+
+    /// How many contents are above the content, 0 for posts
+    pub fn depth(&self) -> usize {
+        self.ancestors.len()
+    }
+
+    /// The ancestors from the parent up to the root post
+    pub fn iter(&self) -> impl Iterator<Item = &Content> {
+        self.ancestors.iter()
+    }
+
+    /// The content preceded by up to `levels` ancestors, the oldest first,
+    /// such as a comment with its parent and grandparent for `levels = 2`
+    pub fn context(&self, levels: usize) -> Vec<&Content> {
+        let mut context: Vec<&Content> = self.ancestors.iter().take(levels).rev().collect();
+        context.push(&self.content);
+
+        context
+    }
+
+    /// The whole thread, from the root post down to the content
+    pub fn thread(&self) -> Vec<&Content> {
+        self.context(self.ancestors.len())
+    }
+
+    /// Takes the content and its ancestors, from the parent up to the root post
+    pub fn into_parts(self) -> (Content, Vec<Content>) {
+        (self.content, self.ancestors)
+    }
+}
+
+/// Contents already fetched, looked up by id before making a request
+/// in [`PostsApi::get_ancestors_with`](crate::internal::posts::PostsApi::get_ancestors_with)
+///
+/// Nested [`Content::children`] are stored as contents of their own.
+#[derive(Default, Debug)]
+pub struct KnownContents {
+    contents: HashMap<String, Content>,
+}
+
+impl KnownContents {
+    /// Stores `content` and its nested replies, ignoring contents without an id
+    pub fn insert(&mut self, content: Content) {
+        let mut pending = vec![content];
+
+        while let Some(mut content) = pending.pop() {
+            if let Some(children) = content.children.take() {
+                pending.extend(*children);
+            }
+
+            if let Some(id) = content.id.clone() {
+                self.contents.insert(id, content);
+            }
+        }
+    }
+
+    pub fn get(&self, id: &str) -> Option<&Content> {
+        self.contents.get(id)
+    }
+
+    /// Finds the content `slug` of `username`
+    pub fn find(&self, username: &str, slug: &str) -> Option<&Content> {
+        self.contents.values().find(|content| {
+            content.slug.as_deref() == Some(slug)
+                && content
+                    .owner_username
+                    .as_deref()
+                    .is_some_and(|owner| owner.eq_ignore_ascii_case(username))
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.contents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.contents.is_empty()
+    }
+}
+
+impl Extend<Content> for KnownContents {
+    fn extend<I: IntoIterator<Item = Content>>(&mut self, contents: I) {
+        for content in contents {
+            self.insert(content);
+        }
+    }
+}
+
+impl From<Vec<Content>> for KnownContents {
+    fn from(contents: Vec<Content>) -> Self {
+        let mut known = KnownContents::default();
+        known.extend(contents);
+
+        known
+    }
+}
+
+impl From<CommentTree> for KnownContents {
+    fn from(tree: CommentTree) -> Self {
+        KnownContents::from(tree.into_contents())
+    }
+}
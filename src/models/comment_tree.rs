@@ -0,0 +1,306 @@
+use std::cmp::Reverse;
+use std::collections::VecDeque;
+
+use super::content::Content;
+
+/// Order of the comments that answer the same content
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CommentOrder {
+    /// Most tabcoins first, the newest first on ties
+    Tabcoins,
+    /// Most recently published first
+    Newest,
+    /// Oldest published first
+    Oldest,
+}
+
+#[derive(Debug)]
+struct Node {
+    content: Content,
+    parent: Option<usize>,
+    children: Vec<usize>,
+    depth: usize,
+}
+
+/// Comments of a post, as returned by
+/// [`PostsApi::get_post_comments`](crate::internal::posts::PostsApi::get_post_comments),
+/// arranged to be walked and queried.
+///
+/// The replies nested in [`Content::children`] are moved into the tree,
+/// so the contents it holds have no `children` of their own.
+///
+/// # Examples
+///
+/// ```
+/// # use tabnews::models::comment_tree::CommentTree;
+/// # use tabnews::models::content::Content;
+/// # fn main() -> Result<(), serde_json::Error> {
+/// let comments: Vec<Content> = serde_json::from_str(r#"[
+///     { "id": "a", "children": [{ "id": "b", "children": [] }] },
+///     { "id": "c", "children": [] }
+/// ]"#)?;
+///
+/// let tree = CommentTree::new(comments);
+///
+/// let ids: Vec<_> = tree
+///     .flatten()
+///     .map(|(depth, content)| (depth, content.id.as_deref().unwrap()))
+///     .collect();
+///
+/// assert_eq!(ids, [(0, "a"), (1, "b"), (0, "c")]);
+/// assert_eq!(tree.parent_of("b").and_then(|c| c.id.as_deref()), Some("a"));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct CommentTree {
+    nodes: Vec<Node>,
+    roots: Vec<usize>,
+}
+
+impl CommentTree {
+    /// Builds the tree from the top level comments and their nested replies
+    pub fn new(comments: Vec<Content>) -> Self {
+        let mut tree = CommentTree {
+            nodes: Vec::new(),
+            roots: Vec::new(),
+        };
+
+        let mut pending: Vec<(Content, Option<usize>)> =
+            comments.into_iter().rev().map(|c| (c, None)).collect();
+
+        while let Some((mut content, parent)) = pending.pop() {
+            let index = tree.nodes.len();
+            let children = content.children.take().map(|c| *c).unwrap_or_default();
+
+            let depth = match parent {
+                Some(parent) => {
+                    tree.nodes[parent].children.push(index);
+                    tree.nodes[parent].depth + 1
+                }
+                None => {
+                    tree.roots.push(index);
+                    0
+                }
+            };
+
+            tree.nodes.push(Node {
+                content,
+                parent,
+                children: Vec::new(),
+                depth,
+            });
+
+            pending.extend(children.into_iter().rev().map(|c| (c, Some(index))));
+        }
+
+        tree
+    }
+
+    /// Number of comments, replies included
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Comments that answer the post directly
+    pub fn roots(&self) -> impl Iterator<Item = CommentNode<'_>> {
+        self.roots.iter().map(move |&index| self.node(index))
+    }
+
+    /// Finds the comment with `id`
+    pub fn get(&self, id: &str) -> Option<CommentNode<'_>> {
+        self.nodes
+            .iter()
+            .position(|node| node.content.id.as_deref() == Some(id))
+            .map(|index| self.node(index))
+    }
+
+    /// Returns the comment answered by the comment with `id`,
+    /// `None` for top level comments
+    pub fn parent_of(&self, id: &str) -> Option<&Content> {
+        self.get(id)?.parent().map(|parent| parent.content())
+    }
+
+    /// Returns how deep the comment with `id` is, 0 for top level comments
+    pub fn depth_of(&self, id: &str) -> Option<usize> {
+        self.get(id).map(|node| node.depth())
+    }
+
+    /// Walks the comments depth-first, each one followed by its replies
+    pub fn depth_first(&self) -> DepthFirst<'_> {
+        DepthFirst {
+            tree: self,
+            stack: self.roots.iter().rev().copied().collect(),
+        }
+    }
+
+    /// Walks the comments breadth-first, level by level
+    pub fn breadth_first(&self) -> BreadthFirst<'_> {
+        BreadthFirst {
+            tree: self,
+            queue: self.roots.iter().copied().collect(),
+        }
+    }
+
+    /// Every comment in depth-first order along with its depth,
+    /// handy to print the thread indented
+    pub fn flatten(&self) -> impl Iterator<Item = (usize, &Content)> {
+        self.depth_first()
+            .map(|node| (node.depth(), node.content()))
+    }
+
+    /// Sorts the top level comments and the replies to each comment by `order`
+    pub fn sort_by(&mut self, order: CommentOrder) {
+        let keys: Vec<(i64, Option<String>)> = self
+            .nodes
+            .iter()
+            .map(|node| {
+                let content = &node.content;
+                let date = content
+                    .published_at
+                    .clone()
+                    .or_else(|| content.created_at.clone());
+
+                (content.tabcoins.unwrap_or_default(), date)
+            })
+            .collect();
+
+        let sort = |siblings: &mut Vec<usize>| match order {
+            CommentOrder::Tabcoins => siblings.sort_by_key(|&index| Reverse(&keys[index])),
+            CommentOrder::Newest => siblings.sort_by_key(|&index| Reverse(&keys[index].1)),
+            CommentOrder::Oldest => siblings.sort_by_key(|&index| &keys[index].1),
+        };
+
+        sort(&mut self.roots);
+        self.nodes
+            .iter_mut()
+            .for_each(|node| sort(&mut node.children));
+    }
+
+    /// Takes the comments out of the tree, in depth-first order
+    pub fn into_contents(self) -> Vec<Content> {
+        let order: Vec<usize> = self.depth_first().map(|node| node.index).collect();
+        let mut contents: Vec<Option<Content>> = self
+            .nodes
+            .into_iter()
+            .map(|node| Some(node.content))
+            .collect();
+
+        order
+            .into_iter()
+            .filter_map(|index| contents[index].take())
+            .collect()
+    }
+
+    fn node(&self, index: usize) -> CommentNode<'_> {
+        CommentNode { tree: self, index }
+    }
+}
+
+impl From<Vec<Content>> for CommentTree {
+    fn from(comments: Vec<Content>) -> Self {
+        CommentTree::new(comments)
+    }
+}
+
+/// A comment of a [`CommentTree`], with access to its surroundings
+#[derive(Clone, Copy)]
+pub struct CommentNode<'a> {
+    tree: &'a CommentTree,
+    index: usize,
+}
+
+impl<'a> CommentNode<'a> {
+    pub fn content(&self) -> &'a Content {
+        &self.tree.nodes[self.index].content
+    }
+
+    /// How deep the comment is, 0 for top level comments
+    pub fn depth(&self) -> usize {
+        self.tree.nodes[self.index].depth
+    }
+
+    /// The comment answered by this one, `None` for top level comments
+    pub fn parent(&self) -> Option<CommentNode<'a>> {
+        self.tree.nodes[self.index]
+            .parent
+            .map(|index| self.tree.node(index))
+    }
+
+    /// The direct replies to the comment
+    pub fn children(&self) -> impl Iterator<Item = CommentNode<'a>> {
+        let tree = self.tree;
+
+        tree.nodes[self.index]
+            .children
+            .iter()
+            .map(move |&index| tree.node(index))
+    }
+
+    /// Number of replies under the comment, at any depth.
+    ///
+    /// Matches the `children_deep_count` sent by the server
+    /// when the whole thread was fetched.
+    pub fn subtree_count(&self) -> u64 {
+        let mut count = 0;
+        let mut stack = vec![self.index];
+
+        while let Some(index) = stack.pop() {
+            let children = &self.tree.nodes[index].children;
+
+            count += children.len() as u64;
+            stack.extend(children);
+        }
+
+        count
+    }
+
+    /// Whether [`CommentNode::subtree_count`] agrees with the
+    /// `children_deep_count` of the content, when it was sent
+    pub fn is_complete(&self) -> bool {
+        self.content()
+            .children_deep_count
+            .is_none_or(|count| count == self.subtree_count())
+    }
+}
+
+/// Depth-first iterator of a [`CommentTree`], created by [`CommentTree::depth_first`]
+pub struct DepthFirst<'a> {
+    tree: &'a CommentTree,
+    stack: Vec<usize>,
+}
+
+impl<'a> Iterator for DepthFirst<'a> {
+    type Item = CommentNode<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.stack.pop()?;
+
+        self.stack
+            .extend(self.tree.nodes[index].children.iter().rev());
+
+        Some(self.tree.node(index))
+    }
+}
+
+/// Breadth-first iterator of a [`CommentTree`], created by [`CommentTree::breadth_first`]
+pub struct BreadthFirst<'a> {
+    tree: &'a CommentTree,
+    queue: VecDeque<usize>,
+}
+
+impl<'a> Iterator for BreadthFirst<'a> {
+    type Item = CommentNode<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.queue.pop_front()?;
+
+        self.queue.extend(&self.tree.nodes[index].children);
+
+        Some(self.tree.node(index))
+    }
+}
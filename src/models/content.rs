@@ -1,5 +1,11 @@
+use std::fmt;
+use std::str::FromStr;
+
 use serde::{Deserialize, Serialize};
 
+use crate::internal::pagination::MAX_PER_PAGE;
+use crate::models::error::{Error, Result};
+
 pub enum TabcoinsTransaction {
     Credit,
     Debit,
@@ -10,6 +16,55 @@ pub struct Tabcoins {
     pub tabcoins: i64,
 }
 
+/// Publication status of a post/comment
+#[derive(Serialize, Deserialize, Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum ContentStatus {
+    /// Only visible to its owner
+    Draft,
+    /// Visible to everyone
+    #[default]
+    Published,
+    /// Removed by its owner, can't be changed anymore
+    Deleted,
+    /// A status added to the API after this version, such as `firewall`
+    #[serde(other)]
+    Unknown,
+}
+
+impl ContentStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ContentStatus::Draft => "draft",
+            ContentStatus::Published => "published",
+            ContentStatus::Deleted => "deleted",
+            ContentStatus::Unknown => "unknown",
+        }
+    }
+}
+
+impl fmt::Display for ContentStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ContentStatus {
+    type Err = Error;
+
+    fn from_str(status: &str) -> Result<Self> {
+        match status {
+            "draft" => Ok(ContentStatus::Draft),
+            "published" => Ok(ContentStatus::Published),
+            "deleted" => Ok(ContentStatus::Deleted),
+            _ => Err(Error::InvalidInput(format!(
+                "unknown status {:?}, expected \"draft\", \"published\" or \"deleted\"",
+                status
+            ))),
+        }
+    }
+}
+
 /// Struct that represents a post/comment
 ///
 /// # Examples
@@ -25,7 +80,7 @@ pub struct Tabcoins {
 ///     .set_slug("example-of-a-cool-post");
 /// # }
 /// ```
-
+///
 /// Example on how to create a comment
 /// ```rust
 /// # use tabnews::models::content::Content;
@@ -38,7 +93,7 @@ pub struct Tabcoins {
 ///     .set_parent_id("0000-4002-8922-0000");
 /// # }
 /// ```
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct Content {
     pub id: Option<String>,
     pub parent_id: Option<String>,
@@ -46,7 +101,7 @@ pub struct Content {
     pub slug: Option<String>,
     pub body: Option<String>,
     pub title: Option<String>,
-    pub status: Option<String>,
+    pub status: Option<ContentStatus>,
     pub source_url: Option<String>,
     pub created_at: Option<String>,
     pub updated_at: Option<String>,
@@ -67,7 +122,7 @@ impl Default for Content {
             slug: None,
             body: None,
             title: None,
-            status: Some("published".to_owned()),
+            status: Some(ContentStatus::Published),
             source_url: None,
             created_at: None,
             updated_at: None,
@@ -150,6 +205,23 @@ impl Content {
         self
     }
 
+    /// Sets the status, [`ContentStatus::Published`] by default
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use tabnews::models::content::{Content, ContentStatus};
+    /// # fn main() {
+    /// let draft = Content::default()
+    ///     .set_status(ContentStatus::Draft);
+    /// # }
+    /// ```
+    pub fn set_status(&mut self, status: ContentStatus) -> &mut Self {
+        self.status = Some(status);
+
+        self
+    }
+
     /// Sets the parent id
     ///
     /// Required to post comments
@@ -170,19 +242,313 @@ impl Content {
     }
 }
 
-#[derive(Serialize)]
+/// Order of the contents of a listing
+#[derive(Serialize, Deserialize, Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum Strategy {
+    /// Most recently published first
+    New,
+    /// Oldest published first
+    Old,
+    /// Ranked by tabcoins and age, the default of the TabNews API
+    #[default]
+    Relevant,
+}
+
+impl Strategy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Strategy::New => "new",
+            Strategy::Old => "old",
+            Strategy::Relevant => "relevant",
+        }
+    }
+}
+
+impl fmt::Display for Strategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Strategy {
+    type Err = Error;
+
+    fn from_str(strategy: &str) -> Result<Self> {
+        match strategy {
+            "new" => Ok(Strategy::New),
+            "old" => Ok(Strategy::Old),
+            "relevant" => Ok(Strategy::Relevant),
+            _ => Err(Error::InvalidInput(format!(
+                "unknown strategy {:?}, expected \"new\", \"old\" or \"relevant\"",
+                strategy
+            ))),
+        }
+    }
+}
+
+impl TryFrom<&str> for Strategy {
+    type Error = Error;
+
+    fn try_from(strategy: &str) -> Result<Self> {
+        strategy.parse()
+    }
+}
+
+/// Changes to an existing post or comment, sent by
+/// [`PostsApi::update_content`](crate::internal::posts::PostsApi::update_content)
+///
+/// Only the fields that were set are sent, the others are kept as they are.
+///
+/// # Examples
+///
+/// ```
+/// # use tabnews::models::content::ContentPatch;
+/// let patch = ContentPatch::default()
+///     .title("A Better Title")
+///     .clear_source_url();
+///
+/// assert_eq!(
+///     serde_json::to_string(&patch).unwrap(),
+///     r#"{"title":"A Better Title","source_url":null}"#
+/// );
+/// ```
+#[derive(Serialize, Default, Clone, Debug, PartialEq, Eq)]
+pub struct ContentPatch {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// `Some(None)` removes the source url
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_url: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<ContentStatus>,
+}
+
+impl ContentPatch {
+    /// Changes the title
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+
+        self
+    }
+
+    /// Changes the body
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+
+        self
+    }
+
+    /// Changes the source url
+    pub fn source_url(mut self, source_url: impl Into<String>) -> Self {
+        self.source_url = Some(Some(source_url.into()));
+
+        self
+    }
+
+    /// Removes the source url
+    pub fn clear_source_url(mut self) -> Self {
+        self.source_url = Some(None);
+
+        self
+    }
+
+    /// Changes the slug, and so the url of the content
+    pub fn slug(mut self, slug: impl Into<String>) -> Self {
+        self.slug = Some(slug.into());
+
+        self
+    }
+
+    /// Changes the status
+    pub fn status(mut self, status: ContentStatus) -> Self {
+        self.status = Some(status);
+
+        self
+    }
+
+    /// Whether no field was set
+    pub fn is_empty(&self) -> bool {
+        self == &ContentPatch::default()
+    }
+}
+
+/// Query params of the content listings
+///
+/// Unset params are left for the server to default. `strategy` is the name
+/// of a [`Strategy`]. Use [`ContentParams::builder`] to have the params
+/// validated before any request, listings validate them anyway.
+///
+/// # Examples
+///
+/// ```
+/// # use tabnews::models::content::{ContentParams, Strategy};
+/// # fn main() -> Result<(), tabnews::Error> {
+/// let params = ContentParams::builder()
+///     .page(2)
+///     .per_page(50)
+///     .strategy(Strategy::New)
+///     .build()?;
+///
+/// assert_eq!(params.strategy.as_deref(), Some("new"));
+/// assert!(ContentParams::builder().per_page(500).build().is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Serialize, Default, Clone, Debug, PartialEq, Eq)]
 pub struct ContentParams {
     pub page: Option<u64>,
     pub per_page: Option<u64>,
     pub strategy: Option<String>,
 }
 
-impl Default for ContentParams {
-    fn default() -> Self {
+impl ContentParams {
+    pub fn builder() -> ContentParamsBuilder {
+        ContentParamsBuilder::default()
+    }
+
+    /// Checks that `page` is at least 1, `per_page` is between 1 and
+    /// [`MAX_PER_PAGE`] and `strategy` is a [`Strategy`]
+    pub fn validate(&self) -> Result<()> {
+        if self.page == Some(0) {
+            return Err(Error::InvalidInput("page must be at least 1".to_owned()));
+        }
+
+        if self
+            .per_page
+            .is_some_and(|per_page| !(1..=MAX_PER_PAGE).contains(&per_page))
+        {
+            return Err(Error::InvalidInput(format!(
+                "per_page must be between 1 and {}",
+                MAX_PER_PAGE
+            )));
+        }
+
+        if let Some(strategy) = &self.strategy {
+            strategy.parse::<Strategy>()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl From<Strategy> for ContentParams {
+    fn from(strategy: Strategy) -> Self {
         ContentParams {
-            page: None,
-            per_page: None,
-            strategy: None,
+            strategy: Some(strategy.to_string()),
+            ..Default::default()
+        }
+    }
+}
+
+impl TryFrom<ContentParamsBuilder> for ContentParams {
+    type Error = Error;
+
+    fn try_from(builder: ContentParamsBuilder) -> Result<Self> {
+        builder.build()
+    }
+}
+
+/// Builder of [`ContentParams`], created by [`ContentParams::builder`]
+#[derive(Default, Clone, Debug)]
+pub struct ContentParamsBuilder {
+    params: ContentParams,
+}
+
+impl ContentParamsBuilder {
+    /// Sets the page to list, starting at 1
+    pub fn page(mut self, page: u64) -> Self {
+        self.params.page = Some(page);
+
+        self
+    }
+
+    /// Sets how many contents are listed per page, from 1 to [`MAX_PER_PAGE`]
+    pub fn per_page(mut self, per_page: u64) -> Self {
+        self.params.per_page = Some(per_page);
+
+        self
+    }
+
+    /// Sets the order of the contents
+    pub fn strategy(mut self, strategy: Strategy) -> Self {
+        self.params.strategy = Some(strategy.to_string());
+
+        self
+    }
+
+    /// Validates the params, see [`ContentParams::validate`]
+    pub fn build(self) -> Result<ContentParams> {
+        self.params.validate()?;
+
+        Ok(self.params)
+    }
+}
+
+/// Params of the content listings, the [`ContentParams`] plus the filters
+/// of which contents are listed
+///
+/// The listings take anything that converts into it, so an
+/// `Option<ContentParams>` can still be passed to them.
+///
+/// # Examples
+///
+/// ```
+/// # use tabnews::models::content::{ListingParams, Strategy};
+/// let params = ListingParams::from(Strategy::New)
+///     .with_root(false)
+///     .with_children(true);
+///
+/// assert_eq!(params.params.strategy.as_deref(), Some("new"));
+/// assert_eq!(params.with_children, Some(true));
+/// ```
+#[derive(Serialize, Default, Clone, Debug, PartialEq, Eq)]
+pub struct ListingParams {
+    #[serde(flatten)]
+    pub params: ContentParams,
+    /// Whether root contents, the posts, are listed
+    pub with_root: Option<bool>,
+    /// Whether child contents, the comments, are listed
+    pub with_children: Option<bool>,
+}
+
+impl ListingParams {
+    /// Sets whether posts are listed
+    pub fn with_root(mut self, with_root: bool) -> Self {
+        self.with_root = Some(with_root);
+
+        self
+    }
+
+    /// Sets whether comments are listed
+    pub fn with_children(mut self, with_children: bool) -> Self {
+        self.with_children = Some(with_children);
+
+        self
+    }
+}
+
+impl From<ContentParams> for ListingParams {
+    fn from(params: ContentParams) -> Self {
+        ListingParams {
+            params,
+            ..Default::default()
         }
     }
 }
+
+impl From<Option<ContentParams>> for ListingParams {
+    fn from(params: Option<ContentParams>) -> Self {
+        params.map(ListingParams::from).unwrap_or_default()
+    }
+}
+
+impl From<Strategy> for ListingParams {
+    fn from(strategy: Strategy) -> Self {
+        ContentParams::from(strategy).into()
+    }
+}
@@ -1,5 +1,8 @@
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
 
+/// Error body returned by the TabNews API when a request fails
 #[derive(Serialize, Deserialize, Debug)]
 pub struct TabnewsError {
     pub name: String,
@@ -10,3 +13,129 @@ pub struct TabnewsError {
     pub request_id: String,
     pub error_location_code: String,
 }
+
+impl fmt::Display for TabnewsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} {}", self.name, self.message, self.action)
+    }
+}
+
+impl std::error::Error for TabnewsError {}
+
+/// Error returned by every fallible method of this crate
+#[derive(Debug)]
+pub enum Error {
+    /// The request could not be sent or the response could not be read
+    Transport(Box<dyn std::error::Error + Send + Sync>),
+    /// The request did not complete in time
+    Timeout,
+    /// The server answered with a non-success status.
+    ///
+    /// `error` holds the parsed TabNews error body, when the body is one.
+    Http {
+        status: u16,
+        error: Option<Box<TabnewsError>>,
+    },
+    /// The response body could not be decoded into the expected type.
+    ///
+    /// `body` holds the raw response body.
+    Decode {
+        source: serde_json::Error,
+        body: String,
+    },
+    /// A header name or value is not valid
+    InvalidHeader(String),
+    /// The method requires a session, but the `Cookie` header is not set
+    NotLoggedIn,
+    /// An argument passed to the method is not valid
+    InvalidInput(String),
+    /// A file could not be read or written
+    Io(std::io::Error),
+    /// A replayed cassette has no interaction matching the request
+    Replay(String),
+    /// Following the parents of a content led back to the content with this id
+    Cycle(String),
+    /// A feed is not well-formed or misses a required element
+    Feed(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Transport(err) => write!(f, "transport error: {}", err),
+            Error::Timeout => write!(f, "request timed out"),
+            Error::Http {
+                status,
+                error: Some(error),
+            } => write!(f, "http status {}: {}", status, error),
+            Error::Http {
+                status,
+                error: None,
+            } => write!(f, "http status {}", status),
+            Error::Decode { source, .. } => write!(f, "failed to decode response: {}", source),
+            Error::InvalidHeader(header) => write!(f, "invalid header: {}", header),
+            Error::NotLoggedIn => write!(
+                f,
+                "`Cookie` header with `session_id=<token>` is required, login first"
+            ),
+            Error::InvalidInput(reason) => write!(f, "invalid input: {}", reason),
+            Error::Io(err) => write!(f, "io error: {}", err),
+            Error::Replay(request) => {
+                write!(f, "no recorded interaction matches {}", request)
+            }
+            Error::Cycle(id) => write!(f, "content {} is its own ancestor", id),
+            Error::Feed(reason) => write!(f, "invalid feed: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Transport(err) => Some(err.as_ref()),
+            Error::Http {
+                error: Some(error), ..
+            } => Some(error.as_ref()),
+            Error::Decode { source, .. } => Some(source),
+            Error::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(err: reqwest::Error) -> Self {
+        if err.is_timeout() {
+            return Error::Timeout;
+        }
+
+        Error::Transport(Box::new(err))
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+impl From<reqwest::header::InvalidHeaderName> for Error {
+    fn from(err: reqwest::header::InvalidHeaderName) -> Self {
+        Error::InvalidHeader(err.to_string())
+    }
+}
+
+impl From<reqwest::header::InvalidHeaderValue> for Error {
+    fn from(err: reqwest::header::InvalidHeaderValue) -> Self {
+        Error::InvalidHeader(err.to_string())
+    }
+}
+
+impl From<reqwest::header::ToStrError> for Error {
+    fn from(err: reqwest::header::ToStrError) -> Self {
+        Error::InvalidHeader(err.to_string())
+    }
+}
+
+/// Shorthand for `Result<T, tabnews::Error>`
+pub type Result<T> = std::result::Result<T, Error>;
@@ -1,4 +1,9 @@
 pub mod analytics;
+pub mod ancestors;
+pub mod comment_tree;
 pub mod content;
 pub mod error;
+pub mod rss;
+pub mod text;
+pub mod thumbnail;
 pub mod user;
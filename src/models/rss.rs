@@ -0,0 +1,302 @@
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use quick_xml::escape::resolve_html5_entity;
+use quick_xml::events::Event;
+use quick_xml::Reader;
+
+use super::error::{Error, Result};
+use crate::internal::dates::{days_from_civil, days_in_month};
+
+/// TabNews RSS feed, returned by
+/// [`PostsApi::get_rss_feed`](crate::internal::posts::PostsApi::get_rss_feed)
+///
+/// Entries are read leniently: a missing or malformed field of an item,
+/// such as a `pubDate` that is not a valid date, is left as `None` instead
+/// of failing the whole feed. Only a document that is not well-formed XML
+/// or has no `<channel>` is an [`Error::Feed`].
+///
+/// # Examples
+///
+/// ```
+/// # use tabnews::models::rss::RssFeed;
+/// # use tabnews::Error;
+/// # fn main() -> Result<(), Error> {
+/// let feed: RssFeed = r#"
+///     <rss version="2.0">
+///       <channel>
+///         <title>TabNews</title>
+///         <item>
+///           <title>Documentação da API</title>
+///           <link>https://www.tabnews.com.br/GabrielSozinho/documentacao-da-api-do-tabnews</link>
+///           <pubDate>Sun, 01 Jan 2023 00:00:00 GMT</pubDate>
+///         </item>
+///       </channel>
+///     </rss>
+/// "#.parse()?;
+///
+/// let item = &feed.items[0];
+///
+/// assert_eq!(
+///     item.username_and_slug(),
+///     Some(("GabrielSozinho", "documentacao-da-api-do-tabnews"))
+/// );
+/// assert!(item.pub_date.is_some());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct RssFeed {
+    pub title: Option<String>,
+    pub link: Option<String>,
+    pub description: Option<String>,
+    pub language: Option<String>,
+    pub last_build_date: Option<SystemTime>,
+    pub items: Vec<RssItem>,
+}
+
+/// A post of the [`RssFeed`]
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct RssItem {
+    pub title: Option<String>,
+    pub link: Option<String>,
+    pub guid: Option<String>,
+    pub pub_date: Option<SystemTime>,
+    pub author: Option<String>,
+    pub description: Option<String>,
+}
+
+impl RssFeed {
+    /// Parses an RSS 2.0 document
+    pub fn parse(xml: &str) -> Result<Self> {
+        let mut reader = Reader::from_str(xml);
+
+        let mut feed = RssFeed::default();
+        let mut has_channel = false;
+        let mut item: Option<RssItem> = None;
+        // Open elements with the text read so far inside each of them
+        let mut open: Vec<(String, String)> = Vec::new();
+
+        loop {
+            let event = reader.read_event().map_err(|err| {
+                Error::Feed(format!("{} at position {}", err, reader.error_position()))
+            })?;
+
+            match event {
+                Event::Start(tag) => {
+                    let name = String::from_utf8_lossy(tag.name().as_ref()).into_owned();
+                    let parent = open.last().map(|(parent, _)| parent.as_str());
+
+                    match (parent, name.as_str()) {
+                        (Some("rss"), "channel") => has_channel = true,
+                        (Some("channel"), "item") => item = Some(RssItem::default()),
+                        _ => {}
+                    }
+
+                    open.push((name, String::new()));
+                }
+                Event::Text(text) => {
+                    // HTML entities such as `&nbsp;` are common in feeds, and
+                    // unknown ones are kept as they were written
+                    let text = text
+                        .unescape_with(resolve_html5_entity)
+                        .unwrap_or_else(|_| String::from_utf8_lossy(&text).into_owned().into());
+
+                    if let Some((_, buffer)) = open.last_mut() {
+                        buffer.push_str(&text);
+                    }
+                }
+                Event::CData(data) => {
+                    if let Some((_, buffer)) = open.last_mut() {
+                        buffer.push_str(&String::from_utf8_lossy(&data));
+                    }
+                }
+                Event::End(_) => {
+                    let Some((name, text)) = open.pop() else {
+                        continue;
+                    };
+                    let parent = open.last().map(|(parent, _)| parent.as_str());
+                    let value = non_empty(text);
+
+                    match (parent, name.as_str()) {
+                        (Some("channel"), "item") => feed.items.extend(item.take()),
+                        (Some("channel"), _) => feed.set_field(&name, value),
+                        (Some("item"), _) => {
+                            if let Some(item) = item.as_mut() {
+                                item.set_field(&name, value);
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+
+        if let Some((name, _)) = open.last() {
+            return Err(Error::Feed(format!("unclosed element <{}>", name)));
+        }
+
+        if !has_channel {
+            return Err(Error::Feed("missing <channel> element".to_owned()));
+        }
+
+        Ok(feed)
+    }
+
+    fn set_field(&mut self, name: &str, value: Option<String>) {
+        match name {
+            "title" => self.title = value,
+            "link" => self.link = value,
+            "description" => self.description = value,
+            "language" => self.language = value,
+            "lastBuildDate" => self.last_build_date = value.as_deref().and_then(parse_rfc_2822),
+            _ => {}
+        }
+    }
+}
+
+impl FromStr for RssFeed {
+    type Err = Error;
+
+    fn from_str(xml: &str) -> Result<Self> {
+        RssFeed::parse(xml)
+    }
+}
+
+impl RssItem {
+    /// Username of the author and slug of the post, read from the `link`
+    /// or, when it is missing, from the `guid`
+    ///
+    /// They are the arguments expected by
+    /// [`PostsApi::get_post_details`](crate::internal::posts::PostsApi::get_post_details).
+    pub fn username_and_slug(&self) -> Option<(&str, &str)> {
+        self.link
+            .as_deref()
+            .and_then(username_and_slug)
+            .or_else(|| self.guid.as_deref().and_then(username_and_slug))
+    }
+
+    fn set_field(&mut self, name: &str, value: Option<String>) {
+        match name {
+            "title" => self.title = value,
+            "link" => self.link = value,
+            "guid" => self.guid = value,
+            "pubDate" => self.pub_date = value.as_deref().and_then(parse_rfc_2822),
+            "author" | "dc:creator" => self.author = value,
+            "description" => self.description = value,
+            _ => {}
+        }
+    }
+}
+
+fn non_empty(text: String) -> Option<String> {
+    let trimmed = text.trim();
+
+    if trimmed.is_empty() {
+        return None;
+    }
+
+    Some(trimmed.to_owned())
+}
+
+/// Reads `username` and `slug` from `https://www.tabnews.com.br/{username}/{slug}`
+fn username_and_slug(url: &str) -> Option<(&str, &str)> {
+    let (_, rest) = url.split_once("://")?;
+    let (_, path) = rest.split_once('/')?;
+    let path = path.split(['?', '#']).next()?.trim_end_matches('/');
+
+    let (username, slug) = path.split_once('/')?;
+
+    if username.is_empty() || slug.is_empty() || slug.contains('/') {
+        return None;
+    }
+
+    Some((username, slug))
+}
+
+/// Parses dates such as `Sun, 01 Jan 2023 00:00:00 GMT` or
+/// `1 Jan 2023 00:00 -0300`
+fn parse_rfc_2822(date: &str) -> Option<SystemTime> {
+    const MONTHS: [&str; 12] = [
+        "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+    ];
+
+    // The day of the week is optional and redundant
+    let date = date.split_once(',').map_or(date, |(_, date)| date);
+    let mut parts = date.split_whitespace();
+
+    let day: u32 = parts.next()?.parse().ok()?;
+    let month = parts.next()?.to_ascii_lowercase();
+    let month = MONTHS.iter().position(|name| *name == month)? as u32 + 1;
+    let year: i64 = match parts.next()? {
+        year if year.len() == 2 => {
+            let year: i64 = year.parse().ok()?;
+            if year < 50 {
+                2000 + year
+            } else {
+                1900 + year
+            }
+        }
+        year => year.parse().ok()?,
+    };
+
+    let mut time = parts.next()?.split(':');
+    let hour: i64 = time.next()?.parse().ok()?;
+    let minute: i64 = time.next()?.parse().ok()?;
+    let second: i64 = time.next().map_or(Some(0), |second| second.parse().ok())?;
+
+    let offset = zone_offset(parts.next()?)?;
+
+    if parts.next().is_some()
+        || !(1..=9999).contains(&year)
+        || !(1..=days_in_month(year, month)).contains(&day)
+        || hour > 23
+        || minute > 59
+        || second > 60
+    {
+        return None;
+    }
+
+    let seconds = days_from_civil(year, month, day)
+        .checked_mul(86_400)?
+        .checked_add(hour * 3_600 + minute * 60 + second)?
+        .checked_sub(offset)?;
+
+    if seconds >= 0 {
+        UNIX_EPOCH.checked_add(Duration::from_secs(seconds as u64))
+    } else {
+        UNIX_EPOCH.checked_sub(Duration::from_secs(seconds.unsigned_abs()))
+    }
+}
+
+/// Offset of the zone from UTC in seconds
+fn zone_offset(zone: &str) -> Option<i64> {
+    let hours = match zone.to_ascii_uppercase().as_str() {
+        "GMT" | "UT" | "UTC" | "Z" => 0,
+        "EDT" => -4,
+        "EST" | "CDT" => -5,
+        "CST" | "MDT" => -6,
+        "MST" | "PDT" => -7,
+        "PST" => -8,
+        _ => {
+            let (sign, digits) = match zone.split_at_checked(1)? {
+                ("+", digits) => (1, digits),
+                ("-", digits) => (-1, digits),
+                _ => return None,
+            };
+
+            if digits.len() != 4 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+
+            let hours: i64 = digits[..2].parse().ok()?;
+            let minutes: i64 = digits[2..].parse().ok()?;
+
+            return Some(sign * (hours * 3_600 + minutes * 60));
+        }
+    };
+
+    Some(hours * 3_600)
+}
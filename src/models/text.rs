@@ -0,0 +1,382 @@
+//! Plain text read from the markdown of a [`Content`], for listings and
+//! notifications that cannot show the rendered body
+
+use std::time::Duration;
+
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd, TextMergeStream};
+
+use super::content::Content;
+
+/// Average reading speed used by [`Content::reading_time`]
+pub const WORDS_PER_MINUTE: u64 = 200;
+
+impl Content {
+    /// [`Content::body`] without the markdown syntax
+    ///
+    /// Links and images are replaced by their text, code is kept without
+    /// its backticks and raw HTML tags are dropped. Whitespace inside lines
+    /// is collapsed and paragraphs are separated by a blank line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tabnews::models::content::Content;
+    /// # fn main() {
+    /// let mut content = Content::default();
+    /// content.set_body("# Olá\n\nUm **wrapper** pra API do [TabNews](https://www.tabnews.com.br).");
+    ///
+    /// assert_eq!(content.plain_text(), "Olá\n\nUm wrapper pra API do TabNews.");
+    /// # }
+    /// ```
+    pub fn plain_text(&self) -> String {
+        Document::parse(self.body.as_deref().unwrap_or_default()).text
+    }
+
+    /// Up to `length` characters of the plain text on a single line, cut at
+    /// a word boundary and ending with `…` when cut
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tabnews::models::content::Content;
+    /// # fn main() {
+    /// let mut content = Content::default();
+    /// content.set_body("Um **wrapper** pra API\ndo TabNews escrito em Rust");
+    ///
+    /// assert_eq!(content.excerpt(24), "Um wrapper pra API do…");
+    /// assert_eq!(content.excerpt(100), "Um wrapper pra API do TabNews escrito em Rust");
+    /// # }
+    /// ```
+    pub fn excerpt(&self, length: usize) -> String {
+        excerpt(&self.plain_text(), length)
+    }
+
+    /// Number of words of the plain text
+    pub fn word_count(&self) -> usize {
+        word_count(&self.plain_text())
+    }
+
+    /// Estimated time to read the body at [`WORDS_PER_MINUTE`], rounded up
+    /// to the second
+    pub fn reading_time(&self) -> Duration {
+        let words = self.word_count() as u64;
+
+        Duration::from_secs((words * 60).div_ceil(WORDS_PER_MINUTE))
+    }
+
+    /// Address of the first image of the body, written in markdown or as an
+    /// `<img>` tag
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tabnews::models::content::Content;
+    /// # fn main() {
+    /// let mut content = Content::default();
+    /// content.set_body("Veja [o site](https://example.com):\n\n![Capa](https://example.com/capa.png \"Capa\")");
+    ///
+    /// assert_eq!(content.first_image().as_deref(), Some("https://example.com/capa.png"));
+    /// assert_eq!(content.first_link().as_deref(), Some("https://example.com"));
+    /// # }
+    /// ```
+    pub fn first_image(&self) -> Option<String> {
+        Document::parse(self.body.as_deref().unwrap_or_default()).first_image
+    }
+
+    /// Address of the first link of the body, written in markdown, as an
+    /// `<a>` tag or as a bare `http(s)://` address. Links inside code are
+    /// ignored.
+    pub fn first_link(&self) -> Option<String> {
+        Document::parse(self.body.as_deref().unwrap_or_default()).first_link
+    }
+}
+
+/// Parser of TabNews markdown, with the extensions the site renders
+pub(crate) fn parser(markdown: &str) -> Parser<'_> {
+    let options = Options::ENABLE_TABLES
+        | Options::ENABLE_TASKLISTS
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_MATH;
+
+    Parser::new_ext(markdown, options)
+}
+
+/// Up to `length` characters of `text` with the whitespace collapsed, cut at
+/// the last word that fits and ending with `…` when cut
+pub(crate) fn excerpt(text: &str, length: usize) -> String {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let whole = words.join(" ");
+
+    if whole.chars().count() <= length {
+        return whole;
+    }
+
+    if length == 0 {
+        return String::new();
+    }
+
+    // Leaves room for the ellipsis
+    let room = length - 1;
+    let mut excerpt = String::new();
+    let mut count = 0;
+
+    for word in words {
+        let separator = usize::from(count > 0);
+        let word_length = word.chars().count();
+
+        if count + separator + word_length > room {
+            // A single word longer than the excerpt is cut anywhere
+            if count == 0 {
+                excerpt.extend(word.chars().take(room));
+            }
+
+            break;
+        }
+
+        if separator == 1 {
+            excerpt.push(' ');
+        }
+        excerpt.push_str(word);
+        count += separator + word_length;
+    }
+
+    excerpt.push('…');
+
+    excerpt
+}
+
+fn word_count(text: &str) -> usize {
+    text.split_whitespace()
+        .filter(|word| word.chars().any(char::is_alphanumeric))
+        .count()
+}
+
+/// Plain text, first image and first link of a markdown document
+#[derive(Default)]
+struct Document {
+    text: String,
+    first_image: Option<String>,
+    first_link: Option<String>,
+    /// Separator owed before the next text
+    pending: Break,
+}
+
+/// Separator between two pieces of text, from the weakest to the strongest
+#[derive(Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Break {
+    #[default]
+    None,
+    Space,
+    Line,
+    Paragraph,
+}
+
+impl Document {
+    fn parse(markdown: &str) -> Self {
+        let mut document = Document::default();
+        // Labels of links and images and code are not searched for addresses
+        let mut labels = 0usize;
+        let mut in_code = false;
+
+        for event in TextMergeStream::new(parser(markdown)) {
+            match event {
+                Event::Start(tag) => match tag {
+                    Tag::Paragraph
+                    | Tag::Heading { .. }
+                    | Tag::BlockQuote(_)
+                    | Tag::Table(_)
+                    | Tag::HtmlBlock => document.separate(Break::Paragraph),
+                    Tag::CodeBlock(_) => {
+                        in_code = true;
+                        document.separate(Break::Paragraph);
+                    }
+                    Tag::Item | Tag::TableHead | Tag::TableRow => document.separate(Break::Line),
+                    Tag::TableCell => document.separate(Break::Space),
+                    Tag::Link { dest_url, .. } => {
+                        labels += 1;
+                        document
+                            .first_link
+                            .get_or_insert_with(|| dest_url.to_string());
+                    }
+                    Tag::Image { dest_url, .. } => {
+                        labels += 1;
+                        document
+                            .first_image
+                            .get_or_insert_with(|| dest_url.to_string());
+                    }
+                    _ => {}
+                },
+                Event::End(tag) => match tag {
+                    TagEnd::Paragraph
+                    | TagEnd::Heading(_)
+                    | TagEnd::BlockQuote(_)
+                    | TagEnd::Table
+                    | TagEnd::HtmlBlock => document.separate(Break::Paragraph),
+                    TagEnd::CodeBlock => {
+                        in_code = false;
+                        document.separate(Break::Paragraph);
+                    }
+                    TagEnd::Item | TagEnd::TableHead | TagEnd::TableRow => {
+                        document.separate(Break::Line)
+                    }
+                    TagEnd::TableCell => document.separate(Break::Space),
+                    TagEnd::Link | TagEnd::Image => labels = labels.saturating_sub(1),
+                    _ => {}
+                },
+                Event::Text(text) => {
+                    if labels == 0 && !in_code && document.first_link.is_none() {
+                        document.first_link = bare_url(&text).map(str::to_owned);
+                    }
+
+                    document.push(&text);
+                }
+                Event::Code(text) | Event::InlineMath(text) | Event::DisplayMath(text) => {
+                    document.push(&text)
+                }
+                Event::Html(html) | Event::InlineHtml(html) => document.html(&html),
+                Event::SoftBreak | Event::HardBreak => document.separate(Break::Line),
+                _ => {}
+            }
+        }
+
+        document.text = join_paragraphs(&document.text);
+
+        document
+    }
+
+    fn separate(&mut self, separator: Break) {
+        self.pending = self.pending.max(separator);
+    }
+
+    fn push(&mut self, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+
+        if !self.text.is_empty() {
+            self.text.push_str(match self.pending {
+                Break::None => "",
+                Break::Space => " ",
+                Break::Line => "\n",
+                Break::Paragraph => "\n\n",
+            });
+        }
+
+        self.pending = Break::None;
+        self.text.push_str(text);
+    }
+
+    /// Drops the tags of raw HTML, keeping the text between them and the
+    /// addresses of `<img>` and `<a>`
+    fn html(&mut self, html: &str) {
+        let mut rest = html;
+
+        while let Some(start) = rest.find('<') {
+            let Some(length) = rest[start..].find('>') else {
+                break;
+            };
+            let tag = &rest[start + 1..start + length];
+
+            self.push(&rest[..start]);
+
+            if self.first_image.is_none() {
+                self.first_image = html_attribute(tag, "img", "src");
+            }
+            if self.first_link.is_none() {
+                self.first_link = html_attribute(tag, "a", "href");
+            }
+
+            rest = &rest[start + length + 1..];
+        }
+
+        self.push(rest);
+    }
+}
+
+/// Lines with their whitespace collapsed, and runs of blank lines reduced
+/// to a single one
+fn join_paragraphs(text: &str) -> String {
+    let mut joined = String::new();
+    let mut blank = false;
+
+    for line in text.lines() {
+        let line = line.split_whitespace().collect::<Vec<_>>().join(" ");
+
+        if line.is_empty() {
+            blank = !joined.is_empty();
+            continue;
+        }
+
+        if !joined.is_empty() {
+            joined.push_str(if blank { "\n\n" } else { "\n" });
+        }
+
+        joined.push_str(&line);
+        blank = false;
+    }
+
+    joined
+}
+
+/// Value of `attribute` when `html` is the inside of an opening `element` tag
+fn html_attribute(html: &str, element: &str, attribute: &str) -> Option<String> {
+    let mut parts = html.splitn(2, char::is_whitespace);
+
+    if !parts.next()?.eq_ignore_ascii_case(element) {
+        return None;
+    }
+
+    let attributes = parts.next()?;
+    let lowercase = attributes.to_ascii_lowercase();
+    let mut search = 0;
+
+    while let Some(found) = lowercase[search..].find(attribute) {
+        let at = search + found;
+        search = at + attribute.len();
+
+        let is_name_start = at == 0 || attributes[..at].ends_with(char::is_whitespace);
+        let rest = attributes[search..].trim_start();
+
+        if !is_name_start || !rest.starts_with('=') {
+            continue;
+        }
+
+        let value = rest[1..].trim_start();
+        let value = match value.chars().next()? {
+            quote @ ('"' | '\'') => value[1..].split(quote).next()?,
+            _ => value
+                .split(|c: char| c.is_whitespace() || c == '/')
+                .next()?,
+        };
+
+        return (!value.is_empty()).then(|| value.to_owned());
+    }
+
+    None
+}
+
+/// First address such as `https://example.com/a_b` written as text, without
+/// the punctuation that usually follows it
+fn bare_url(text: &str) -> Option<&str> {
+    for (start, _) in text.match_indices("http") {
+        let rest = &text[start..];
+        let scheme = if rest.starts_with("https://") {
+            8
+        } else if rest.starts_with("http://") {
+            7
+        } else {
+            continue;
+        };
+
+        let end = rest
+            .find(|c: char| c.is_whitespace() || matches!(c, '<' | '>' | '"' | '`'))
+            .unwrap_or(rest.len());
+        let url = rest[..end].trim_end_matches(['.', ',', ':', ';', '!', '?', ')', '*', '_']);
+
+        if url.len() > scheme {
+            return Some(url);
+        }
+    }
+
+    None
+}
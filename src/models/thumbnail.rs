@@ -0,0 +1,253 @@
+use std::fmt;
+use std::path::Path;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures::stream::{BoxStream, Stream, StreamExt};
+use reqwest::header::{HeaderMap, CONTENT_LENGTH, CONTENT_TYPE};
+use tokio::io::AsyncWriteExt;
+
+use super::error::Result;
+use crate::internal::http_client::{HttpResponse, StreamingResponse};
+
+/// Thumbnail image of a post, returned by
+/// [`PostsApi::get_post_thumbnail`](crate::internal::posts::PostsApi::get_post_thumbnail)
+///
+/// # Examples
+///
+/// ```no_run
+/// # use tabnews::TabnewsClient;
+/// # use tabnews::Error;
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Error> {
+/// let client = TabnewsClient::default();
+///
+/// let thumbnail = client.posts_api.get_post_thumbnail(
+///     "GabrielSozinho",
+///     "documentacao-da-api-do-tabnews"
+/// ).await?;
+///
+/// if let Some((width, height)) = thumbnail.dimensions() {
+///     println!("{}x{} {:?}", width, height, thumbnail.content_type());
+/// }
+///
+/// thumbnail.save_to("thumbnail.png")?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Thumbnail {
+    bytes: Vec<u8>,
+    content_type: Option<String>,
+}
+
+impl fmt::Debug for Thumbnail {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Thumbnail")
+            .field("content_type", &self.content_type)
+            .field("dimensions", &self.dimensions())
+            .field("len", &self.bytes.len())
+            .finish()
+    }
+}
+
+impl Thumbnail {
+    pub fn new(bytes: Vec<u8>, content_type: Option<String>) -> Self {
+        Thumbnail {
+            bytes,
+            content_type,
+        }
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    /// The `Content-Type` sent by the server, such as `image/png`
+    pub fn content_type(&self) -> Option<&str> {
+        self.content_type.as_deref()
+    }
+
+    /// Width and height in pixels, read from the header of PNG and JPEG images
+    pub fn dimensions(&self) -> Option<(u32, u32)> {
+        image_dimensions(&self.bytes)
+    }
+
+    /// Writes the image to `path`, replacing the file if it exists
+    pub fn save_to(&self, path: impl AsRef<Path>) -> Result<()> {
+        std::fs::write(path, &self.bytes)?;
+
+        Ok(())
+    }
+}
+
+impl From<HttpResponse> for Thumbnail {
+    fn from(response: HttpResponse) -> Self {
+        Thumbnail {
+            content_type: content_type(&response.headers),
+            bytes: response.body,
+        }
+    }
+}
+
+/// Thumbnail image of a post whose bytes are read as they arrive, returned by
+/// [`PostsApi::stream_post_thumbnail`](crate::internal::posts::PostsApi::stream_post_thumbnail)
+///
+/// It is a [`Stream`] of chunks of the image.
+pub struct ThumbnailStream {
+    content_type: Option<String>,
+    content_length: Option<u64>,
+    body: BoxStream<'static, Result<Vec<u8>>>,
+}
+
+impl fmt::Debug for ThumbnailStream {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ThumbnailStream")
+            .field("content_type", &self.content_type)
+            .field("content_length", &self.content_length)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ThumbnailStream {
+    /// The `Content-Type` sent by the server, such as `image/png`
+    pub fn content_type(&self) -> Option<&str> {
+        self.content_type.as_deref()
+    }
+
+    /// Size of the image in bytes, when the server sent it
+    pub fn content_length(&self) -> Option<u64> {
+        self.content_length
+    }
+
+    /// Writes the image to `path` chunk by chunk and returns how many bytes
+    /// were written
+    pub async fn save_to(mut self, path: impl AsRef<Path>) -> Result<u64> {
+        let mut file = tokio::fs::File::create(path).await?;
+        let mut written = 0;
+
+        while let Some(chunk) = self.body.next().await {
+            let chunk = chunk?;
+
+            file.write_all(&chunk).await?;
+            written += chunk.len() as u64;
+        }
+
+        file.flush().await?;
+
+        Ok(written)
+    }
+
+    /// Reads the whole image
+    pub async fn collect(mut self) -> Result<Thumbnail> {
+        let mut bytes = Vec::new();
+
+        while let Some(chunk) = self.body.next().await {
+            bytes.extend(chunk?);
+        }
+
+        Ok(Thumbnail::new(bytes, self.content_type))
+    }
+}
+
+impl Stream for ThumbnailStream {
+    type Item = Result<Vec<u8>>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.body.poll_next_unpin(cx)
+    }
+}
+
+impl From<StreamingResponse> for ThumbnailStream {
+    fn from(response: StreamingResponse) -> Self {
+        ThumbnailStream {
+            content_type: content_type(&response.headers),
+            content_length: response
+                .headers
+                .get(CONTENT_LENGTH)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.parse().ok()),
+            body: response.body,
+        }
+    }
+}
+
+fn content_type(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_owned)
+}
+
+fn image_dimensions(bytes: &[u8]) -> Option<(u32, u32)> {
+    if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+        return png_dimensions(bytes);
+    }
+
+    if bytes.starts_with(&[0xff, 0xd8]) {
+        return jpeg_dimensions(bytes);
+    }
+
+    None
+}
+
+/// The IHDR chunk always comes first, right after the signature
+fn png_dimensions(bytes: &[u8]) -> Option<(u32, u32)> {
+    if bytes.get(12..16)? != b"IHDR" {
+        return None;
+    }
+
+    let width = u32::from_be_bytes(bytes.get(16..20)?.try_into().ok()?);
+    let height = u32::from_be_bytes(bytes.get(20..24)?.try_into().ok()?);
+
+    Some((width, height))
+}
+
+/// Walks the segments until a start of frame (SOFn) marker
+fn jpeg_dimensions(bytes: &[u8]) -> Option<(u32, u32)> {
+    let mut index = 2;
+
+    loop {
+        if *bytes.get(index)? != 0xff {
+            return None;
+        }
+
+        // Markers can be padded with any number of 0xff
+        while *bytes.get(index)? == 0xff {
+            index += 1;
+        }
+
+        let marker = *bytes.get(index)?;
+        index += 1;
+
+        // End of image or start of scan before any frame
+        if matches!(marker, 0xd9 | 0xda) {
+            return None;
+        }
+
+        // Standalone markers have no length
+        if matches!(marker, 0x01 | 0xd0..=0xd7) {
+            continue;
+        }
+
+        let length = usize::from(u16::from_be_bytes(
+            bytes.get(index..index + 2)?.try_into().ok()?,
+        ));
+
+        let is_start_of_frame =
+            matches!(marker, 0xc0..=0xcf) && !matches!(marker, 0xc4 | 0xc8 | 0xcc);
+
+        if is_start_of_frame {
+            let height = u16::from_be_bytes(bytes.get(index + 3..index + 5)?.try_into().ok()?);
+            let width = u16::from_be_bytes(bytes.get(index + 5..index + 7)?.try_into().ok()?);
+
+            return Some((u32::from(width), u32::from(height)));
+        }
+
+        index += length;
+    }
+}
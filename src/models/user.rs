@@ -1,5 +1,9 @@
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
 
+use crate::internal::http_client::redact::REDACTED;
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct User {
     pub id: String,
@@ -14,7 +18,10 @@ pub struct User {
     pub updated_at: String,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+/// Session created by [`AuthApi`](crate::internal::auth::AuthApi).
+///
+/// Its `Debug` output hides the `token`.
+#[derive(Serialize, Deserialize)]
 pub struct UserSession {
     pub id: String,
     pub token: String,
@@ -23,7 +30,19 @@ pub struct UserSession {
     pub updated_at: String,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+impl fmt::Debug for UserSession {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UserSession")
+            .field("id", &self.id)
+            .field("token", &REDACTED)
+            .field("expires_at", &self.expires_at)
+            .field("created_at", &self.created_at)
+            .field("updated_at", &self.updated_at)
+            .finish()
+    }
+}
+
+#[derive(Serialize, Deserialize)]
 pub struct EditProfilePayload {
     #[serde(rename = "username")]
     pub new_username: Option<String>,
@@ -33,6 +52,20 @@ pub struct EditProfilePayload {
     pub new_password: Option<String>,
     pub notifications: Option<bool>,
 }
+
+impl fmt::Debug for EditProfilePayload {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EditProfilePayload")
+            .field("new_username", &self.new_username)
+            .field("new_email", &self.new_email)
+            .field(
+                "new_password",
+                &self.new_password.as_ref().map(|_| REDACTED),
+            )
+            .field("notifications", &self.notifications)
+            .finish()
+    }
+}
 //
 // impl Serialize for EditProfilePayload {
 //     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
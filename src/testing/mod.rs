@@ -0,0 +1,167 @@
+//! Offline replacement for the TabNews API.
+//!
+//! [`FakeServer`] starts a local HTTP server that emulates the endpoints
+//! used by this crate, keeping its data in memory: contents published through
+//! it can be read back, votes change tabcoins and sessions created through
+//! `/sessions` are accepted by `/user`.
+//!
+//! Only available with the `testing` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! # use tabnews::testing::FakeServer;
+//! # use tabnews::{Error, TabnewsClient};
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Error> {
+//! let server = FakeServer::start();
+//!
+//! let client = TabnewsClient::default();
+//! client.http_client.set_host(server.url());
+//!
+//! let posts = client.posts_api.get_posts_by_user("fadiinho", None).await?;
+//!
+//! assert!(!posts.is_empty());
+//! # Ok(())
+//! # }
+//! ```
+
+mod routes;
+mod state;
+
+use std::convert::Infallible;
+use std::net::{SocketAddr, TcpListener};
+use std::sync::{mpsc, Arc, Mutex, MutexGuard, PoisonError};
+use std::thread::JoinHandle;
+
+use hyper::service::{make_service_fn, service_fn};
+use tokio::sync::oneshot;
+
+pub use state::{FakeContent, FakeSession, FakeState, FakeUser};
+
+/// Password of every seeded user
+pub const SEEDED_PASSWORD: &str = "password";
+
+/// Local HTTP server emulating the TabNews API.
+///
+/// The server runs on its own thread, so it can be used both from async
+/// tests and from synchronous code. It stops when dropped.
+///
+/// [`FakeServer::start`] seeds the following data:
+///
+/// - users `GabrielSozinho` (`gabriel@tabnews.test`) and `fadiinho`
+///   (`fadiinho@tabnews.test`), both with the password [`SEEDED_PASSWORD`];
+/// - the post `GabrielSozinho/documentacao-da-api-do-tabnews`,
+///   a comment on it by `fadiinho` and a reply
+///   `GabrielSozinho/ae53bb7c-264f-4ad1-aeb0-340dde9b1647`;
+/// - the post `fadiinho/tabnews-rs`.
+pub struct FakeServer {
+    address: SocketAddr,
+    state: Arc<Mutex<FakeState>>,
+    shutdown: Option<oneshot::Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl FakeServer {
+    /// Starts a server with the seeded data
+    ///
+    /// # Panics
+    ///
+    /// It panics if the server can't bind a local port.
+    pub fn start() -> Self {
+        FakeServer::with_state(FakeState::seeded())
+    }
+
+    /// Starts a server without any user or content
+    pub fn empty() -> Self {
+        FakeServer::with_state(FakeState::default())
+    }
+
+    /// Starts a server serving `state`
+    ///
+    /// # Panics
+    ///
+    /// It panics if the server can't bind a local port.
+    pub fn with_state(state: FakeState) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("failed to bind the fake server");
+        listener
+            .set_nonblocking(true)
+            .expect("failed to configure the fake server");
+
+        let address = listener
+            .local_addr()
+            .expect("failed to read the fake server address");
+
+        let state = Arc::new(Mutex::new(state));
+        let (shutdown, shutdown_receiver) = oneshot::channel::<()>();
+        let (ready, ready_receiver) = mpsc::channel::<()>();
+
+        let server_state = Arc::clone(&state);
+        let thread = std::thread::spawn(move || {
+            let runtime = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .expect("failed to start the fake server runtime");
+
+            runtime.block_on(async move {
+                let make_service = make_service_fn(move |_| {
+                    let state = Arc::clone(&server_state);
+
+                    async move {
+                        Ok::<_, Infallible>(service_fn(move |request| {
+                            let state = Arc::clone(&state);
+
+                            async move { Ok::<_, Infallible>(routes::handle(state, request).await) }
+                        }))
+                    }
+                });
+
+                let server = hyper::Server::from_tcp(listener)
+                    .expect("failed to start the fake server")
+                    .serve(make_service)
+                    .with_graceful_shutdown(async {
+                        shutdown_receiver.await.ok();
+                    });
+
+                ready.send(()).ok();
+                server.await.ok();
+            });
+        });
+
+        ready_receiver.recv().ok();
+
+        FakeServer {
+            address,
+            state,
+            shutdown: Some(shutdown),
+            thread: Some(thread),
+        }
+    }
+
+    /// Base url of the API, to be used with
+    /// [`HttpClient::set_host`](crate::internal::http_client::HttpClient::set_host)
+    pub fn url(&self) -> String {
+        format!("http://{}/api/v1", self.address)
+    }
+
+    pub fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    /// Locks the in-memory data, so tests can inspect or change it
+    pub fn state(&self) -> MutexGuard<'_, FakeState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Drop for FakeServer {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            shutdown.send(()).ok();
+        }
+
+        if let Some(thread) = self.thread.take() {
+            thread.join().ok();
+        }
+    }
+}
@@ -0,0 +1,881 @@
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use hyper::{
+    header::{HeaderValue, CONTENT_TYPE, COOKIE, LINK},
+    Body, Method, Request, Response, StatusCode,
+};
+use serde_json::{json, Value};
+
+use super::state::{day_month, rfc_2822, slugify, FakeContent, FakeSession, FakeState};
+
+const API_PREFIX: &str = "/api/v1";
+const SITE_URL: &str = "https://www.tabnews.com.br";
+
+type Query = HashMap<String, String>;
+
+pub(crate) async fn handle(state: Arc<Mutex<FakeState>>, request: Request<Body>) -> Response<Body> {
+    let (parts, body) = request.into_parts();
+
+    let body = match hyper::body::to_bytes(body).await {
+        Ok(body) => body,
+        Err(_) => {
+            return error(
+                StatusCode::BAD_REQUEST,
+                "ValidationError",
+                "Corpo inválido.",
+            )
+        }
+    };
+
+    let body: Value = if body.is_empty() {
+        Value::Null
+    } else {
+        match serde_json::from_slice(&body) {
+            Ok(body) => body,
+            Err(_) => {
+                return error(
+                    StatusCode::BAD_REQUEST,
+                    "ValidationError",
+                    "Body enviado deve ser do tipo Object.",
+                )
+            }
+        }
+    };
+
+    let query: Query = parts
+        .uri
+        .query()
+        .and_then(|query| serde_urlencoded::from_str(query).ok())
+        .unwrap_or_default();
+
+    let token = parts
+        .headers
+        .get(COOKIE)
+        .and_then(|cookie| cookie.to_str().ok())
+        .and_then(session_token);
+
+    let full_path = parts.uri.path().to_owned();
+    let path = full_path.strip_prefix(API_PREFIX).unwrap_or(&full_path);
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+
+    let mut state = state.lock().unwrap_or_else(PoisonError::into_inner);
+    let request = FakeRequest {
+        method: parts.method,
+        path: full_path.clone(),
+        query,
+        body,
+        token,
+    };
+
+    let mut response = route(&mut state, &request, &segments);
+
+    if let Ok(request_id) = HeaderValue::try_from(state.next_request_id()) {
+        response.headers_mut().insert("x-request-id", request_id);
+    }
+
+    response
+}
+
+struct FakeRequest {
+    method: Method,
+    path: String,
+    query: Query,
+    body: Value,
+    token: Option<String>,
+}
+
+fn route(state: &mut FakeState, request: &FakeRequest, segments: &[&str]) -> Response<Body> {
+    match (&request.method, segments) {
+        (&Method::GET, ["contents"]) => list_contents(state, request, None),
+        (&Method::POST, ["contents"]) => create_content(state, request),
+        (&Method::GET, ["contents", "rss"]) => rss(state),
+        (&Method::GET, ["contents", username]) => list_contents(state, request, Some(username)),
+        (&Method::GET, ["contents", username, slug]) => get_content(state, request, username, slug),
+        (&Method::PATCH, ["contents", username, slug]) => {
+            update_content(state, request, username, slug)
+        }
+        (&Method::GET, ["contents", username, slug, "children"]) => {
+            get_children(state, username, slug)
+        }
+        (&Method::GET, ["contents", username, slug, "parent"]) => get_parent(state, username, slug),
+        (&Method::GET, ["contents", username, slug, "root"]) => get_root(state, username, slug),
+        (&Method::GET, ["contents", username, slug, "thumbnail"]) => {
+            get_thumbnail(state, username, slug)
+        }
+        (&Method::POST, ["contents", username, slug, "tabcoins"]) => {
+            tabcoins(state, request, username, slug)
+        }
+        (&Method::POST, ["sessions"]) => create_session(state, request),
+        (&Method::GET, ["user"]) => current_user(state, request),
+        (&Method::GET, ["users"]) => list_users(state, request),
+        (&Method::POST, ["users"]) => create_user(state, request),
+        (&Method::GET, ["users", username]) => get_user(state, username),
+        (&Method::GET, ["analytics", "users-created"]) => analytics_users(state),
+        (&Method::GET, ["analytics", "root-content-published"]) => {
+            analytics_contents(state, true, "conteudos")
+        }
+        (&Method::GET, ["analytics", "child-content-published"]) => {
+            analytics_contents(state, false, "respostas")
+        }
+        _ => error(
+            StatusCode::NOT_FOUND,
+            "NotFoundError",
+            "Não foi possível encontrar este recurso no sistema.",
+        ),
+    }
+}
+
+fn session_token(cookie: &str) -> Option<String> {
+    cookie
+        .split(';')
+        .filter_map(|pair| pair.trim().split_once('='))
+        .find(|(key, _)| *key == "session_id")
+        .map(|(_, value)| value.to_owned())
+}
+
+fn json_response(status: StatusCode, body: &Value) -> Response<Body> {
+    let mut response = Response::new(Body::from(body.to_string()));
+
+    *response.status_mut() = status;
+    response
+        .headers_mut()
+        .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+    response
+}
+
+fn error(status: StatusCode, name: &str, message: &str) -> Response<Body> {
+    json_response(
+        status,
+        &json!({
+            "name": name,
+            "message": message,
+            "action": "Verifique os dados enviados e tente novamente.",
+            "status_code": status.as_u16(),
+            "error_id": "00000000-0000-4000-8000-000000000000",
+            "request_id": "00000000-0000-4000-8000-000000000000",
+            "error_location_code": "FAKE_TABNEWS",
+        }),
+    )
+}
+
+fn not_found() -> Response<Body> {
+    error(
+        StatusCode::NOT_FOUND,
+        "NotFoundError",
+        "O conteúdo informado não foi encontrado no sistema.",
+    )
+}
+
+fn forbidden() -> Response<Body> {
+    error(
+        StatusCode::FORBIDDEN,
+        "ForbiddenError",
+        "Usuário não pode executar esta operação.",
+    )
+}
+
+fn logged_user_id(state: &FakeState, request: &FakeRequest) -> Option<String> {
+    let token = request.token.as_deref()?;
+
+    state.user_by_token(token).map(|user| user.id.clone())
+}
+
+fn content_json(state: &FakeState, content: &FakeContent) -> Value {
+    content.to_json(state.children_deep_count(&content.id))
+}
+
+fn list_contents(
+    state: &FakeState,
+    request: &FakeRequest,
+    username: Option<&str>,
+) -> Response<Body> {
+    if let Some(username) = username {
+        if state.user_by_username(username).is_none() {
+            return error(
+                StatusCode::NOT_FOUND,
+                "NotFoundError",
+                "O \"username\" informado não foi encontrado no sistema.",
+            );
+        }
+    }
+
+    // The listing of a user includes their comments unless asked otherwise
+    let with_root = parse_bool(&request.query, "with_root", true);
+    let with_children = parse_bool(&request.query, "with_children", username.is_some());
+
+    let (with_root, with_children) = match (with_root, with_children) {
+        (Some(with_root), Some(with_children)) => (with_root, with_children),
+        _ => {
+            return error(
+                StatusCode::BAD_REQUEST,
+                "ValidationError",
+                "\"with_root\" e \"with_children\" devem ser \"true\" ou \"false\".",
+            )
+        }
+    };
+
+    // Drafts are only listed to their owner
+    let status = match request.query.get("status").map(String::as_str) {
+        None | Some("published") => "published",
+        Some("draft") => {
+            let owner = logged_user_id(state, request)
+                .and_then(|id| state.user_by_id(&id))
+                .zip(username)
+                .is_some_and(|(user, username)| user.username.eq_ignore_ascii_case(username));
+
+            if !owner {
+                return forbidden();
+            }
+
+            "draft"
+        }
+        Some(_) => {
+            return error(
+                StatusCode::BAD_REQUEST,
+                "ValidationError",
+                "\"status\" deve possuir um dos seguintes valores: \"draft\" ou \"published\".",
+            )
+        }
+    };
+
+    let mut contents: Vec<&FakeContent> = state
+        .contents
+        .iter()
+        .filter(|c| c.status == status)
+        .filter(|c| match username {
+            Some(username) => c.owner_username.eq_ignore_ascii_case(username),
+            None => true,
+        })
+        .filter(|c| match c.parent_id {
+            Some(_) => with_children,
+            None => with_root,
+        })
+        .collect();
+
+    match request.query.get("strategy").map(String::as_str) {
+        Some("new") => contents.sort_by_key(|c| Reverse(c.published_at)),
+        Some("old") => contents.sort_by_key(|c| c.published_at),
+        Some("relevant") | None => contents.sort_by(|a, b| {
+            b.tabcoins
+                .cmp(&a.tabcoins)
+                .then(b.published_at.cmp(&a.published_at))
+        }),
+        Some(_) => return error(
+            StatusCode::BAD_REQUEST,
+            "ValidationError",
+            "\"strategy\" deve possuir um dos seguintes valores: \"new\", \"old\" ou \"relevant\".",
+        ),
+    }
+
+    let items = contents
+        .iter()
+        .map(|content| content_json(state, content))
+        .collect();
+
+    paginated_response(request, items)
+}
+
+/// Responds with the page asked by the `page` and `per_page` params,
+/// with the `Link` and `X-Pagination-Total-Rows` headers sent by TabNews
+fn paginated_response(request: &FakeRequest, items: Vec<Value>) -> Response<Body> {
+    let page = parse_number(&request.query, "page", 1);
+    let per_page = parse_number(&request.query, "per_page", 30);
+
+    if page < 1 || !(1..=100).contains(&per_page) {
+        return error(
+            StatusCode::BAD_REQUEST,
+            "ValidationError",
+            "\"page\" deve ser maior que 0 e \"per_page\" deve estar entre 1 e 100.",
+        );
+    }
+
+    let total = items.len();
+    let last_page = total.div_ceil(per_page).max(1);
+
+    let link = |page: usize, rel: &str| {
+        let mut query = request.query.clone();
+        query.insert("page".to_owned(), page.to_string());
+        query.insert("per_page".to_owned(), per_page.to_string());
+
+        let mut query: Vec<_> = query.into_iter().collect();
+        query.sort();
+
+        format!(
+            "<{}{}?{}>; rel=\"{}\"",
+            SITE_URL,
+            request.path,
+            serde_urlencoded::to_string(query).unwrap_or_default(),
+            rel
+        )
+    };
+
+    let mut links = vec![link(1, "first")];
+
+    if page > 1 && page <= last_page {
+        links.push(link(page - 1, "prev"));
+    }
+
+    if page < last_page {
+        links.push(link(page + 1, "next"));
+    }
+
+    links.push(link(last_page, "last"));
+
+    let items = items
+        .into_iter()
+        .skip((page - 1) * per_page)
+        .take(per_page)
+        .collect();
+
+    let mut response = json_response(StatusCode::OK, &Value::Array(items));
+    let headers = response.headers_mut();
+
+    if let Ok(link) = HeaderValue::try_from(links.join(", ")) {
+        headers.insert(LINK, link);
+    }
+
+    headers.insert("x-pagination-total-rows", HeaderValue::from(total));
+
+    response
+}
+
+fn parse_number(query: &Query, key: &str, default: usize) -> usize {
+    query
+        .get(key)
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(default)
+}
+
+fn parse_bool(query: &Query, key: &str, default: bool) -> Option<bool> {
+    match query.get(key).map(String::as_str) {
+        None => Some(default),
+        Some("true") => Some(true),
+        Some("false") => Some(false),
+        Some(_) => None,
+    }
+}
+
+fn create_content(state: &mut FakeState, request: &FakeRequest) -> Response<Body> {
+    let owner_id = match logged_user_id(state, request) {
+        Some(owner_id) => owner_id,
+        None => return forbidden(),
+    };
+
+    let field = |key: &str| request.body.get(key).and_then(Value::as_str);
+
+    let parent_id = field("parent_id");
+    let title = field("title");
+
+    let body = match field("body") {
+        Some(body) if !body.is_empty() => body,
+        _ => {
+            return error(
+                StatusCode::BAD_REQUEST,
+                "ValidationError",
+                "\"body\" é um campo obrigatório.",
+            )
+        }
+    };
+
+    if parent_id.is_none() && title.is_none() {
+        return error(
+            StatusCode::BAD_REQUEST,
+            "ValidationError",
+            "\"title\" é um campo obrigatório.",
+        );
+    }
+
+    if let Some(parent_id) = parent_id {
+        if state.content_by_id(parent_id).is_none() {
+            return error(
+                StatusCode::BAD_REQUEST,
+                "ValidationError",
+                "Você está tentando criar um comentário em um conteúdo que não existe.",
+            );
+        }
+    }
+
+    let owner_username = state
+        .user_by_id(&owner_id)
+        .map(|user| user.username.clone())
+        .unwrap_or_default();
+
+    let slug = field("slug")
+        .map(str::to_owned)
+        .or_else(|| title.map(slugify));
+
+    if let Some(slug) = &slug {
+        if state.content_by_slug(&owner_username, slug).is_some() {
+            return error(
+                StatusCode::BAD_REQUEST,
+                "ValidationError",
+                "O conteúdo enviado parece ser duplicado.",
+            );
+        }
+    }
+
+    let id = state.add_content(&owner_id, parent_id, slug.as_deref(), title, body);
+
+    let status = field("status").unwrap_or("published").to_owned();
+    let source_url = field("source_url").map(str::to_owned);
+
+    if let Some(content) = state.contents.iter_mut().find(|c| c.id == id) {
+        if status != "published" {
+            content.published_at = None;
+        }
+        content.status = status;
+        content.source_url = source_url;
+    }
+
+    match state.content_by_id(&id) {
+        Some(content) => json_response(StatusCode::CREATED, &content_json(state, content)),
+        None => not_found(),
+    }
+}
+
+fn get_content(
+    state: &FakeState,
+    request: &FakeRequest,
+    username: &str,
+    slug: &str,
+) -> Response<Body> {
+    let user_id = logged_user_id(state, request);
+
+    match state.content_by_slug(username, slug) {
+        Some(content) if content.is_published() => {
+            json_response(StatusCode::OK, &content_json(state, content))
+        }
+        // Owners can preview their drafts
+        Some(content)
+            if content.status == "draft" && user_id.as_deref() == Some(&content.owner_id) =>
+        {
+            json_response(StatusCode::OK, &content_json(state, content))
+        }
+        _ => not_found(),
+    }
+}
+
+fn update_content(
+    state: &mut FakeState,
+    request: &FakeRequest,
+    username: &str,
+    slug: &str,
+) -> Response<Body> {
+    let user_id = match logged_user_id(state, request) {
+        Some(user_id) => user_id,
+        None => return forbidden(),
+    };
+
+    let (id, owner_id, owner_username, is_root) = match state.content_by_slug(username, slug) {
+        Some(content) if content.status != "deleted" => (
+            content.id.clone(),
+            content.owner_id.clone(),
+            content.owner_username.clone(),
+            content.parent_id.is_none(),
+        ),
+        _ => return not_found(),
+    };
+
+    if owner_id != user_id {
+        return forbidden();
+    }
+
+    let invalid = |message: &str| error(StatusCode::BAD_REQUEST, "ValidationError", message);
+    let field = |key: &str| request.body.get(key);
+
+    let title = match field("title") {
+        None => None,
+        Some(Value::String(title)) if !title.is_empty() => Some(Some(title.clone())),
+        Some(Value::Null) if !is_root => Some(None),
+        Some(_) => return invalid("\"title\" deve ser um texto não vazio."),
+    };
+
+    let body = match field("body") {
+        None => None,
+        Some(Value::String(body)) if !body.is_empty() => Some(body.clone()),
+        Some(_) => return invalid("\"body\" deve ser um texto não vazio."),
+    };
+
+    let source_url = match field("source_url") {
+        None => None,
+        Some(Value::String(url)) => Some(Some(url.clone())),
+        Some(Value::Null) => Some(None),
+        Some(_) => return invalid("\"source_url\" deve ser uma URL."),
+    };
+
+    let new_slug = match field("slug") {
+        None => None,
+        Some(Value::String(new_slug)) if !new_slug.is_empty() => Some(new_slug.clone()),
+        Some(_) => return invalid("\"slug\" deve ser um texto não vazio."),
+    };
+
+    let status = match field("status").map(|status| status.as_str()) {
+        None => None,
+        Some(Some(status @ ("draft" | "published" | "deleted"))) => Some(status.to_owned()),
+        Some(_) => {
+            return invalid(
+                "\"status\" deve possuir um dos seguintes valores: \"draft\", \"published\" ou \"deleted\".",
+            )
+        }
+    };
+
+    if let Some(new_slug) = &new_slug {
+        let taken = state
+            .content_by_slug(&owner_username, new_slug)
+            .is_some_and(|content| content.id != id);
+
+        if taken {
+            return invalid("O conteúdo enviado parece ser duplicado.");
+        }
+    }
+
+    let now = state.tick();
+
+    if let Some(content) = state.contents.iter_mut().find(|c| c.id == id) {
+        if let Some(title) = title {
+            content.title = title;
+        }
+        if let Some(body) = body {
+            content.body = body;
+        }
+        if let Some(source_url) = source_url {
+            content.source_url = source_url;
+        }
+        if let Some(new_slug) = new_slug {
+            content.slug = new_slug;
+        }
+        if let Some(status) = status {
+            match status.as_str() {
+                "published" if content.published_at.is_none() => content.published_at = Some(now),
+                "deleted" => content.deleted_at = Some(now),
+                _ => {}
+            }
+            content.status = status;
+        }
+        content.updated_at = now;
+    }
+
+    match state.content_by_id(&id) {
+        Some(content) => json_response(StatusCode::OK, &content_json(state, content)),
+        None => not_found(),
+    }
+}
+
+fn children_tree(state: &FakeState, id: &str) -> Value {
+    let children = state
+        .children_of(id)
+        .into_iter()
+        .map(|child| {
+            let mut value = content_json(state, child);
+            value["children"] = children_tree(state, &child.id);
+
+            value
+        })
+        .collect();
+
+    Value::Array(children)
+}
+
+fn get_children(state: &FakeState, username: &str, slug: &str) -> Response<Body> {
+    match state.content_by_slug(username, slug) {
+        Some(content) => json_response(StatusCode::OK, &children_tree(state, &content.id)),
+        None => not_found(),
+    }
+}
+
+fn get_parent(state: &FakeState, username: &str, slug: &str) -> Response<Body> {
+    let parent = state
+        .content_by_slug(username, slug)
+        .and_then(|content| content.parent_id.as_deref())
+        .and_then(|parent_id| state.content_by_id(parent_id));
+
+    match parent {
+        Some(parent) => json_response(StatusCode::OK, &content_json(state, parent)),
+        None => not_found(),
+    }
+}
+
+fn get_root(state: &FakeState, username: &str, slug: &str) -> Response<Body> {
+    let mut current = match state.content_by_slug(username, slug) {
+        Some(content) => content,
+        None => return not_found(),
+    };
+
+    if current.parent_id.is_none() {
+        return not_found();
+    }
+
+    while let Some(parent) = current
+        .parent_id
+        .as_deref()
+        .and_then(|parent_id| state.content_by_id(parent_id))
+    {
+        current = parent;
+    }
+
+    json_response(StatusCode::OK, &content_json(state, current))
+}
+
+/// 1x1 transparent PNG
+const THUMBNAIL: [u8; 70] = [
+    0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x48, 0x44, 0x52,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x08, 0x06, 0x00, 0x00, 0x00, 0x1f, 0x15, 0xc4,
+    0x89, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x44, 0x41, 0x54, 0x78, 0xda, 0x63, 0x64, 0x60, 0xf8, 0x5f,
+    0x0f, 0x00, 0x02, 0x87, 0x01, 0x80, 0xeb, 0x47, 0xba, 0x92, 0x00, 0x00, 0x00, 0x00, 0x49, 0x45,
+    0x4e, 0x44, 0xae, 0x42, 0x60, 0x82,
+];
+
+fn get_thumbnail(state: &FakeState, username: &str, slug: &str) -> Response<Body> {
+    if state.content_by_slug(username, slug).is_none() {
+        return not_found();
+    }
+
+    let mut response = Response::new(Body::from(THUMBNAIL.to_vec()));
+    response
+        .headers_mut()
+        .insert(CONTENT_TYPE, HeaderValue::from_static("image/png"));
+
+    response
+}
+
+fn tabcoins(
+    state: &mut FakeState,
+    request: &FakeRequest,
+    username: &str,
+    slug: &str,
+) -> Response<Body> {
+    let voter_id = match logged_user_id(state, request) {
+        Some(voter_id) => voter_id,
+        None => return forbidden(),
+    };
+
+    let delta = match request.body.get("transaction_type").and_then(Value::as_str) {
+        Some("credit") => 1,
+        Some("debit") => -1,
+        _ => return error(
+            StatusCode::BAD_REQUEST,
+            "ValidationError",
+            "\"transaction_type\" deve possuir um dos seguintes valores: \"credit\" ou \"debit\".",
+        ),
+    };
+
+    let content = match state
+        .contents
+        .iter_mut()
+        .find(|c| c.owner_username.eq_ignore_ascii_case(username) && c.slug == slug)
+    {
+        Some(content) => content,
+        None => return not_found(),
+    };
+
+    if content.owner_id == voter_id {
+        return error(
+            StatusCode::BAD_REQUEST,
+            "ValidationError",
+            "Você não pode realizar esta operação em conteúdos de sua própria autoria.",
+        );
+    }
+
+    content.tabcoins += delta;
+
+    json_response(
+        StatusCode::CREATED,
+        &json!({ "tabcoins": content.tabcoins }),
+    )
+}
+
+fn create_session(state: &mut FakeState, request: &FakeRequest) -> Response<Body> {
+    let field = |key: &str| request.body.get(key).and_then(Value::as_str);
+
+    let user_id = state
+        .users
+        .iter()
+        .find(|user| Some(user.email.as_str()) == field("email"))
+        .filter(|user| Some(user.password.as_str()) == field("password"))
+        .map(|user| user.id.clone());
+
+    let user_id = match user_id {
+        Some(user_id) => user_id,
+        None => {
+            return error(
+                StatusCode::UNAUTHORIZED,
+                "UnauthorizedError",
+                "Dados não conferem.",
+            )
+        }
+    };
+
+    let now = state.tick();
+    let id = state.next_id();
+    let token = format!("token-{}", id);
+
+    state.sessions.push(FakeSession {
+        id: id.clone(),
+        token: token.clone(),
+        user_id,
+        created_at: now,
+    });
+
+    json_response(
+        StatusCode::CREATED,
+        &json!({
+            "id": id,
+            "token": token,
+            "expires_at": super::state::iso_8601(now + 30 * 86_400_000),
+            "created_at": super::state::iso_8601(now),
+            "updated_at": super::state::iso_8601(now),
+        }),
+    )
+}
+
+fn current_user(state: &FakeState, request: &FakeRequest) -> Response<Body> {
+    let user = request
+        .token
+        .as_deref()
+        .and_then(|token| state.user_by_token(token));
+
+    match user {
+        Some(user) => json_response(StatusCode::OK, &user.to_private_json()),
+        None => forbidden(),
+    }
+}
+
+fn list_users(state: &FakeState, request: &FakeRequest) -> Response<Body> {
+    let allowed = request
+        .token
+        .as_deref()
+        .and_then(|token| state.user_by_token(token))
+        .is_some_and(|user| user.features.iter().any(|f| f == "read:user:list"));
+
+    if !allowed {
+        return forbidden();
+    }
+
+    let users = state
+        .users
+        .iter()
+        .map(|user| user.to_public_json())
+        .collect();
+
+    paginated_response(request, users)
+}
+
+fn create_user(state: &mut FakeState, request: &FakeRequest) -> Response<Body> {
+    let field = |key: &str| request.body.get(key).and_then(Value::as_str);
+
+    let (username, email, password) = match (field("username"), field("email"), field("password")) {
+        (Some(username), Some(email), Some(password)) => (username, email, password),
+        _ => {
+            return error(
+                StatusCode::BAD_REQUEST,
+                "ValidationError",
+                "\"username\", \"email\" e \"password\" são campos obrigatórios.",
+            )
+        }
+    };
+
+    if state.user_by_username(username).is_some() {
+        return error(
+            StatusCode::BAD_REQUEST,
+            "ValidationError",
+            "O \"username\" informado já está sendo usado.",
+        );
+    }
+
+    let id = state.add_user(username, email, password);
+
+    match state.user_by_id(&id) {
+        Some(user) => json_response(StatusCode::CREATED, &user.to_public_json()),
+        None => not_found(),
+    }
+}
+
+fn get_user(state: &FakeState, username: &str) -> Response<Body> {
+    match state.user_by_username(username) {
+        Some(user) => json_response(StatusCode::OK, &user.to_public_json()),
+        None => error(
+            StatusCode::NOT_FOUND,
+            "NotFoundError",
+            "O \"username\" informado não foi encontrado no sistema.",
+        ),
+    }
+}
+
+/// Groups `timestamps` by day, keeping the order of the first occurrence
+fn count_per_day(timestamps: impl Iterator<Item = u64>, key: &str) -> Value {
+    let mut days: Vec<(String, u64)> = Vec::new();
+
+    for timestamp in timestamps {
+        let day = day_month(timestamp);
+
+        match days.iter_mut().find(|(d, _)| *d == day) {
+            Some((_, count)) => *count += 1,
+            None => days.push((day, 1)),
+        }
+    }
+
+    Value::Array(
+        days.into_iter()
+            .map(|(date, count)| json!({ "date": date, key: count }))
+            .collect(),
+    )
+}
+
+fn analytics_users(state: &FakeState) -> Response<Body> {
+    let timestamps = state.users.iter().map(|user| user.created_at);
+
+    json_response(StatusCode::OK, &count_per_day(timestamps, "cadastros"))
+}
+
+fn analytics_contents(state: &FakeState, root: bool, key: &str) -> Response<Body> {
+    let timestamps = state
+        .contents
+        .iter()
+        .filter(|c| c.parent_id.is_none() == root)
+        .filter_map(|c| c.published_at);
+
+    json_response(StatusCode::OK, &count_per_day(timestamps, key))
+}
+
+fn rss(state: &FakeState) -> Response<Body> {
+    let mut contents: Vec<&FakeContent> = state
+        .contents
+        .iter()
+        .filter(|c| c.is_published() && c.parent_id.is_none())
+        .collect();
+    contents.sort_by_key(|c| Reverse(c.published_at));
+
+    let items: String = contents
+        .iter()
+        .map(|content| {
+            let link = format!("{}/{}/{}", SITE_URL, content.owner_username, content.slug);
+
+            format!(
+                "<item><title><![CDATA[{}]]></title><link>{}</link><guid>{}</guid><pubDate>{}</pubDate><description><![CDATA[{}]]></description><content:encoded><![CDATA[{}]]></content:encoded><author>{}</author></item>",
+                content.title.as_deref().unwrap_or_default(),
+                link,
+                link,
+                rfc_2822(content.published_at.unwrap_or(content.created_at)),
+                content.body,
+                content.body,
+                content.owner_username,
+            )
+        })
+        .collect();
+
+    let xml = format!(
+        "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<rss version=\"2.0\" xmlns:content=\"http://purl.org/rss/1.0/modules/content/\"><channel><title>TabNews</title><link>{}</link><description>Conteúdos para quem trabalha com Programação e Tecnologia</description><language>pt</language>{}</channel></rss>",
+        SITE_URL, items
+    );
+
+    let mut response = Response::new(Body::from(xml));
+    response.headers_mut().insert(
+        CONTENT_TYPE,
+        HeaderValue::from_static("application/rss+xml; charset=utf-8"),
+    );
+
+    response
+}
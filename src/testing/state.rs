@@ -0,0 +1,430 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::Serialize;
+use serde_json::{json, Value};
+
+/// Users registered in the fake server
+#[derive(Clone, Debug)]
+pub struct FakeUser {
+    pub id: String,
+    pub username: String,
+    pub email: String,
+    pub password: String,
+    pub features: Vec<String>,
+    pub notifications: bool,
+    pub tabcoins: i64,
+    pub tabcash: i64,
+    pub created_at: u64,
+    pub updated_at: u64,
+}
+
+impl FakeUser {
+    /// Public representation, as returned by `/users/{username}`
+    pub(crate) fn to_public_json(&self) -> Value {
+        json!({
+            "id": self.id,
+            "username": self.username,
+            "features": self.features,
+            "tabcoins": self.tabcoins,
+            "tabcash": self.tabcash,
+            "created_at": iso_8601(self.created_at),
+            "updated_at": iso_8601(self.updated_at),
+        })
+    }
+
+    /// Private representation, as returned by `/user`
+    pub(crate) fn to_private_json(&self) -> Value {
+        let mut value = self.to_public_json();
+
+        value["email"] = json!(self.email);
+        value["notifications"] = json!(self.notifications);
+
+        value
+    }
+}
+
+/// Posts and comments stored in the fake server
+#[derive(Clone, Debug)]
+pub struct FakeContent {
+    pub id: String,
+    pub owner_id: String,
+    pub owner_username: String,
+    pub parent_id: Option<String>,
+    pub slug: String,
+    pub title: Option<String>,
+    pub body: String,
+    pub status: String,
+    pub source_url: Option<String>,
+    pub tabcoins: i64,
+    pub created_at: u64,
+    pub updated_at: u64,
+    pub published_at: Option<u64>,
+    pub deleted_at: Option<u64>,
+}
+
+#[derive(Serialize)]
+struct ContentJson<'a> {
+    id: &'a str,
+    owner_id: &'a str,
+    parent_id: &'a Option<String>,
+    slug: &'a str,
+    title: &'a Option<String>,
+    body: &'a str,
+    status: &'a str,
+    source_url: &'a Option<String>,
+    created_at: String,
+    updated_at: String,
+    published_at: Option<String>,
+    deleted_at: Option<String>,
+    tabcoins: i64,
+    owner_username: &'a str,
+    children_deep_count: u64,
+}
+
+impl FakeContent {
+    pub(crate) fn to_json(&self, children_deep_count: u64) -> Value {
+        let content = ContentJson {
+            id: &self.id,
+            owner_id: &self.owner_id,
+            parent_id: &self.parent_id,
+            slug: &self.slug,
+            title: &self.title,
+            body: &self.body,
+            status: &self.status,
+            source_url: &self.source_url,
+            created_at: iso_8601(self.created_at),
+            updated_at: iso_8601(self.updated_at),
+            published_at: self.published_at.map(iso_8601),
+            deleted_at: self.deleted_at.map(iso_8601),
+            tabcoins: self.tabcoins,
+            owner_username: &self.owner_username,
+            children_deep_count,
+        };
+
+        serde_json::to_value(content).unwrap_or(Value::Null)
+    }
+
+    pub fn is_published(&self) -> bool {
+        self.status == "published"
+    }
+}
+
+/// Sessions created through `/sessions`
+#[derive(Clone, Debug)]
+pub struct FakeSession {
+    pub id: String,
+    pub token: String,
+    pub user_id: String,
+    pub created_at: u64,
+}
+
+/// In-memory data of the fake server
+#[derive(Default, Debug)]
+pub struct FakeState {
+    pub users: Vec<FakeUser>,
+    pub contents: Vec<FakeContent>,
+    pub sessions: Vec<FakeSession>,
+    next_id: u64,
+    next_request_id: u64,
+}
+
+impl FakeState {
+    /// State with a couple of users, posts and comments
+    pub(crate) fn seeded() -> Self {
+        let mut state = FakeState::default();
+
+        let gabriel = state.add_user("GabrielSozinho", "gabriel@tabnews.test", "password");
+        let fadiinho = state.add_user("fadiinho", "fadiinho@tabnews.test", "password");
+
+        let documentation = state.add_content(
+            &gabriel,
+            None,
+            Some("documentacao-da-api-do-tabnews"),
+            Some("Documentação da API do TabNews"),
+            "Essa é a documentação **não oficial** da API do TabNews.",
+        );
+        state.set_tabcoins(&documentation, 42);
+
+        let comment = state.add_content(
+            &fadiinho,
+            Some(&documentation),
+            Some("comentario-na-documentacao"),
+            None,
+            "Muito obrigado pela documentação!",
+        );
+
+        state.add_content(
+            &gabriel,
+            Some(&comment),
+            Some("ae53bb7c-264f-4ad1-aeb0-340dde9b1647"),
+            None,
+            "De nada!",
+        );
+
+        state.add_content(
+            &fadiinho,
+            None,
+            Some("tabnews-rs"),
+            Some("tabnews-rs: um wrapper da API do TabNews em Rust"),
+            "Um wrapper pra API do [TabNews](https://www.tabnews.com.br).",
+        );
+
+        state
+    }
+
+    pub(crate) fn next_id(&mut self) -> String {
+        self.next_id += 1;
+
+        format!("00000000-0000-4000-8000-{:012x}", self.next_id)
+    }
+
+    /// Id sent in the `x-request-id` header of each response
+    pub(crate) fn next_request_id(&mut self) -> String {
+        self.next_request_id += 1;
+
+        format!("00000000-0000-4000-9000-{:012x}", self.next_request_id)
+    }
+
+    /// Registers a user and returns its id
+    pub fn add_user(&mut self, username: &str, email: &str, password: &str) -> String {
+        let now = self.tick();
+        let id = self.next_id();
+
+        self.users.push(FakeUser {
+            id: id.clone(),
+            username: username.to_owned(),
+            email: email.to_owned(),
+            password: password.to_owned(),
+            features: vec![
+                "create:session".to_owned(),
+                "read:session".to_owned(),
+                "create:content".to_owned(),
+                "create:content:text_root".to_owned(),
+                "create:content:text_child".to_owned(),
+                "update:content".to_owned(),
+                "update:user".to_owned(),
+            ],
+            notifications: true,
+            tabcoins: 0,
+            tabcash: 0,
+            created_at: now,
+            updated_at: now,
+        });
+
+        id
+    }
+
+    /// Publishes a content owned by `owner_id` and returns its id
+    pub fn add_content(
+        &mut self,
+        owner_id: &str,
+        parent_id: Option<&str>,
+        slug: Option<&str>,
+        title: Option<&str>,
+        body: &str,
+    ) -> String {
+        let now = self.tick();
+        let id = self.next_id();
+
+        let owner_username = self
+            .user_by_id(owner_id)
+            .map(|user| user.username.clone())
+            .unwrap_or_default();
+
+        let slug = match (slug, title) {
+            (Some(slug), _) => slug.to_owned(),
+            (None, Some(title)) => slugify(title),
+            (None, None) => id.clone(),
+        };
+
+        self.contents.push(FakeContent {
+            id: id.clone(),
+            owner_id: owner_id.to_owned(),
+            owner_username,
+            parent_id: parent_id.map(str::to_owned),
+            slug,
+            title: title.map(str::to_owned),
+            body: body.to_owned(),
+            status: "published".to_owned(),
+            source_url: None,
+            tabcoins: 0,
+            created_at: now,
+            updated_at: now,
+            published_at: Some(now),
+            deleted_at: None,
+        });
+
+        id
+    }
+
+    /// Overrides the tabcoins of the content with `content_id`
+    pub fn set_tabcoins(&mut self, content_id: &str, tabcoins: i64) {
+        if let Some(content) = self.contents.iter_mut().find(|c| c.id == content_id) {
+            content.tabcoins = tabcoins;
+        }
+    }
+
+    /// Grants `feature` to the user named `username`
+    pub fn grant_feature(&mut self, username: &str, feature: &str) {
+        if let Some(user) = self.users.iter_mut().find(|u| u.username == username) {
+            user.features.push(feature.to_owned());
+        }
+    }
+
+    pub fn user_by_id(&self, id: &str) -> Option<&FakeUser> {
+        self.users.iter().find(|user| user.id == id)
+    }
+
+    pub fn user_by_username(&self, username: &str) -> Option<&FakeUser> {
+        self.users
+            .iter()
+            .find(|user| user.username.eq_ignore_ascii_case(username))
+    }
+
+    pub fn user_by_token(&self, token: &str) -> Option<&FakeUser> {
+        let session = self.sessions.iter().find(|s| s.token == token)?;
+
+        self.user_by_id(&session.user_id)
+    }
+
+    pub fn content_by_id(&self, id: &str) -> Option<&FakeContent> {
+        self.contents.iter().find(|content| content.id == id)
+    }
+
+    pub fn content_by_slug(&self, username: &str, slug: &str) -> Option<&FakeContent> {
+        self.contents
+            .iter()
+            .find(|c| c.owner_username.eq_ignore_ascii_case(username) && c.slug == slug)
+    }
+
+    pub fn children_of(&self, id: &str) -> Vec<&FakeContent> {
+        self.contents
+            .iter()
+            .filter(|c| c.parent_id.as_deref() == Some(id) && c.is_published())
+            .collect()
+    }
+
+    pub fn children_deep_count(&self, id: &str) -> u64 {
+        self.children_of(id)
+            .iter()
+            .map(|child| 1 + self.children_deep_count(&child.id))
+            .sum()
+    }
+
+    /// Returns a timestamp strictly greater than every previous one,
+    /// so contents created in a row keep a stable order.
+    pub(crate) fn tick(&mut self) -> u64 {
+        let now = unix_millis();
+        let last = self
+            .contents
+            .iter()
+            .map(|c| c.updated_at)
+            .chain(self.users.iter().map(|u| u.updated_at))
+            .max()
+            .unwrap_or(0);
+
+        now.max(last + 1)
+    }
+}
+
+fn unix_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+pub(crate) fn slugify(title: &str) -> String {
+    let slug: String = title
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect();
+
+    slug.split('-')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Converts days since 1970-01-01 into `(year, month, day)`
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    (year, month, day)
+}
+
+struct DateTime {
+    year: i64,
+    month: u32,
+    day: u32,
+    weekday: usize,
+    hour: u64,
+    minute: u64,
+    second: u64,
+    millis: u64,
+}
+
+fn split_millis(millis: u64) -> DateTime {
+    let days = (millis / 86_400_000) as i64;
+    let (year, month, day) = civil_from_days(days);
+    let in_day = millis % 86_400_000;
+
+    DateTime {
+        year,
+        month,
+        day,
+        // 1970-01-01 was a thursday
+        weekday: ((days + 4) % 7) as usize,
+        hour: in_day / 3_600_000,
+        minute: in_day / 60_000 % 60,
+        second: in_day / 1000 % 60,
+        millis: in_day % 1000,
+    }
+}
+
+/// Formats a unix timestamp in milliseconds as `2023-01-01T00:00:00.000Z`
+pub(crate) fn iso_8601(millis: u64) -> String {
+    let t = split_millis(millis);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+        t.year, t.month, t.day, t.hour, t.minute, t.second, t.millis
+    )
+}
+
+/// Formats a unix timestamp in milliseconds as `Sun, 01 Jan 2023 00:00:00 GMT`
+pub(crate) fn rfc_2822(millis: u64) -> String {
+    const WEEKDAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+
+    let t = split_millis(millis);
+
+    format!(
+        "{}, {:02} {} {:04} {:02}:{:02}:{:02} GMT",
+        WEEKDAYS[t.weekday],
+        t.day,
+        MONTHS[(t.month - 1) as usize],
+        t.year,
+        t.hour,
+        t.minute,
+        t.second
+    )
+}
+
+/// Formats a unix timestamp in milliseconds as `dd/mm`, like `/analytics`
+pub(crate) fn day_month(millis: u64) -> String {
+    let t = split_millis(millis);
+
+    format!("{:02}/{:02}", t.day, t.month)
+}
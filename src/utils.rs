@@ -1,9 +1,15 @@
-use reqwest::Client;
+use reqwest::{
+    header::{HeaderValue, USER_AGENT},
+    Method,
+};
 use serde::Deserialize;
 
-const GIT_REPO_API_URL: &'static str = "https://api.github.com/repos";
-const TABNEWS_REPO: &'static str = "filipedeschamps/tabnews.com.br";
-const QUERY: &'static str = "environment=preview&per_page=1";
+use crate::internal::http_client::{HttpRequest, ReqwestTransport, Transport};
+use crate::models::error::{Error, Result};
+
+const GIT_REPO_API_URL: &str = "https://api.github.com/repos";
+const TABNEWS_REPO: &str = "filipedeschamps/tabnews.com.br";
+const QUERY: &str = "environment=preview&per_page=1";
 
 #[derive(Deserialize, Debug)]
 struct GithubDeploymentResponse {
@@ -16,30 +22,37 @@ struct GithubStatusResponse {
 }
 
 // Thanks to https://github.com/Gustavosta/TabNews.py/blob/d91da137b31c45f1e8f958790aeb60cbb1acc877/tabnews/utils.py
-pub async fn get_preview_url() -> String {
+pub async fn get_preview_url() -> Result<String> {
     let url = format!(
         "{}/{}/deployments?{}",
         GIT_REPO_API_URL, TABNEWS_REPO, QUERY
     );
 
-    let response = Client::new()
-        .get(url)
-        .header("User-Agent", "Tabnews-RS")
-        .send()
-        .await
-        .unwrap();
-
-    let deployment_response: Vec<GithubDeploymentResponse> = response.json().await.unwrap();
-    let url = deployment_response.first().unwrap();
-
-    let response = Client::new()
-        .get(url.statuses_url.to_owned())
-        .header("User-Agent", "Tabnews-RS")
-        .send()
-        .await
-        .unwrap();
-
-    let json_response: Vec<GithubStatusResponse> = response.json().await.unwrap();
-
-    json_response.first().unwrap().target_url.to_owned()
+    let transport = ReqwestTransport::default();
+
+    let response = transport
+        .send(
+            HttpRequest::new(Method::GET, url)
+                .header(USER_AGENT, HeaderValue::from_static("Tabnews-RS")),
+        )
+        .await?;
+
+    let deployment_response: Vec<GithubDeploymentResponse> = response.error_for_status()?.json()?;
+    let url = deployment_response
+        .first()
+        .ok_or_else(|| Error::InvalidInput("no preview deployment found".to_owned()))?;
+
+    let response = transport
+        .send(
+            HttpRequest::new(Method::GET, url.statuses_url.to_owned())
+                .header(USER_AGENT, HeaderValue::from_static("Tabnews-RS")),
+        )
+        .await?;
+
+    let json_response: Vec<GithubStatusResponse> = response.error_for_status()?.json()?;
+
+    json_response
+        .first()
+        .map(|status| status.target_url.to_owned())
+        .ok_or_else(|| Error::InvalidInput("preview deployment has no status".to_owned()))
 }
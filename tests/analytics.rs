@@ -1,30 +1,42 @@
 #[cfg(test)]
 mod analytics_tests {
+    use std::sync::Arc;
+
     use tabnews::internal::analytics::AnalyticsApi;
+    use tabnews::internal::http_client::HttpClient;
+    use tabnews::testing::FakeServer;
+
+    fn fake_analytics_api() -> (AnalyticsApi, FakeServer) {
+        let server = FakeServer::start();
+        let client = Arc::new(HttpClient::default());
+        client.set_host(server.url());
+
+        (AnalyticsApi::new(client), server)
+    }
 
     #[tokio::test]
     async fn get_users_created_analytics() {
-        let posts_api = AnalyticsApi::default();
+        let (posts_api, _server) = fake_analytics_api();
 
-        let response = posts_api.get_users_created().await;
+        let response = posts_api.get_users_created().await.unwrap();
 
         assert!(!response.is_empty())
     }
 
     #[tokio::test]
     async fn get_posts_published_analytics() {
-        let posts_api = AnalyticsApi::default();
+        let (posts_api, _server) = fake_analytics_api();
 
-        let response = posts_api.get_posts_published().await;
+        let response = posts_api.get_posts_published().await.unwrap();
 
         assert!(!response.is_empty())
     }
 
     #[tokio::test]
     async fn get_comments_published_analytics() {
-        let posts_api = AnalyticsApi::default();
+        let (posts_api, _server) = fake_analytics_api();
 
-        let response = posts_api.get_comments_published().await;
+        let response = posts_api.get_comments_published().await.unwrap();
 
         assert!(!response.is_empty())
     }
@@ -0,0 +1,176 @@
+#[cfg(test)]
+mod ancestors_tests {
+    use std::sync::Arc;
+
+    use reqwest::StatusCode;
+    use serde_json::{json, Value};
+    use tabnews::internal::http_client::{mock::MockTransport, HttpClient};
+    use tabnews::internal::posts::PostsApi;
+    use tabnews::models::ancestors::KnownContents;
+    use tabnews::models::content::Content;
+    use tabnews::testing::FakeServer;
+    use tabnews::Error;
+
+    const REPLY: &str = "ae53bb7c-264f-4ad1-aeb0-340dde9b1647";
+
+    fn fake_posts_api() -> (PostsApi, FakeServer) {
+        let server = FakeServer::start();
+        let client = Arc::new(HttpClient::default());
+        client.set_host(server.url());
+
+        (PostsApi::new(client), server)
+    }
+
+    fn mocked_posts_api() -> (PostsApi, Arc<MockTransport>) {
+        let transport = Arc::new(MockTransport::default());
+        let client =
+            HttpClient::with_transport("http://tabnews.test/api/v1".to_owned(), transport.clone());
+
+        (PostsApi::new(Arc::new(client)), transport)
+    }
+
+    fn content(id: &str, parent_id: Option<&str>) -> Value {
+        json!({
+            "id": id,
+            "parent_id": parent_id,
+            "owner_username": "fadiinho",
+            "slug": id,
+        })
+    }
+
+    fn slugs<'a>(contents: impl IntoIterator<Item = &'a Content>) -> Vec<&'a str> {
+        contents
+            .into_iter()
+            .map(|c| c.slug.as_deref().unwrap())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn get_ancestors() {
+        let (posts_api, _server) = fake_posts_api();
+
+        let ancestors = posts_api
+            .get_ancestors("GabrielSozinho", REPLY)
+            .await
+            .unwrap();
+
+        assert_eq!(ancestors.depth(), 2);
+        assert_eq!(ancestors.content().slug.as_deref(), Some(REPLY));
+        assert_eq!(
+            ancestors.parent().and_then(|c| c.slug.as_deref()),
+            Some("comentario-na-documentacao")
+        );
+        assert_eq!(
+            ancestors.grandparent().and_then(|c| c.slug.as_deref()),
+            Some("documentacao-da-api-do-tabnews")
+        );
+        assert_eq!(
+            ancestors.root().slug.as_deref(),
+            Some("documentacao-da-api-do-tabnews")
+        );
+
+        assert_eq!(
+            slugs(ancestors.context(1)),
+            ["comentario-na-documentacao", REPLY]
+        );
+        assert_eq!(
+            slugs(ancestors.thread()),
+            [
+                "documentacao-da-api-do-tabnews",
+                "comentario-na-documentacao",
+                REPLY
+            ]
+        );
+        assert_eq!(ancestors.context(10).len(), 3);
+    }
+
+    #[tokio::test]
+    async fn get_ancestors_of_a_post() {
+        let (posts_api, _server) = fake_posts_api();
+
+        let ancestors = posts_api
+            .get_ancestors("fadiinho", "tabnews-rs")
+            .await
+            .unwrap();
+
+        assert_eq!(ancestors.depth(), 0);
+        assert!(ancestors.parent().is_none());
+        assert_eq!(ancestors.root().slug.as_deref(), Some("tabnews-rs"));
+        assert_eq!(slugs(ancestors.context(2)), ["tabnews-rs"]);
+    }
+
+    #[tokio::test]
+    async fn get_ancestors_reuses_known_contents() {
+        let (posts_api, transport) = mocked_posts_api();
+        transport
+            .push_json(StatusCode::OK, &content("a", None))
+            .unwrap();
+
+        let mut known = KnownContents::default();
+        known.insert(serde_json::from_value(content("c", Some("b"))).unwrap());
+        known.insert(serde_json::from_value(content("b", Some("a"))).unwrap());
+
+        let ancestors = posts_api
+            .get_ancestors_with("fadiinho", "c", &mut known)
+            .await
+            .unwrap();
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].path(), "/api/v1/contents/fadiinho/b/parent");
+
+        assert_eq!(slugs(ancestors.thread()), ["a", "b", "c"]);
+        assert!(known.get("a").is_some());
+
+        posts_api
+            .get_ancestors_with("fadiinho", "c", &mut known)
+            .await
+            .unwrap();
+        assert_eq!(transport.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn get_ancestors_from_comment_tree() {
+        let (posts_api, _server) = fake_posts_api();
+
+        let tree = posts_api
+            .get_comment_tree("GabrielSozinho", "documentacao-da-api-do-tabnews")
+            .await
+            .unwrap();
+        let mut known = KnownContents::from(tree);
+        assert_eq!(known.len(), 2);
+
+        let ancestors = posts_api
+            .get_ancestors_with("GabrielSozinho", REPLY, &mut known)
+            .await
+            .unwrap();
+
+        assert_eq!(ancestors.depth(), 2);
+        assert_eq!(known.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn get_ancestors_detects_cycles() {
+        let (posts_api, transport) = mocked_posts_api();
+        transport
+            .push_json(StatusCode::OK, &content("a", Some("b")))
+            .unwrap();
+        transport
+            .push_json(StatusCode::OK, &content("b", Some("a")))
+            .unwrap();
+
+        let response = posts_api.get_ancestors("fadiinho", "a").await;
+
+        assert!(matches!(response, Err(Error::Cycle(id)) if id == "a"));
+        assert_eq!(transport.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn get_ancestors_not_found() {
+        let (posts_api, _server) = fake_posts_api();
+
+        let response = posts_api.get_ancestors("fadiinho", "nao-existe").await;
+
+        assert!(matches!(response, Err(Error::Http { status: 404, .. })));
+    }
+}
@@ -0,0 +1,122 @@
+#[cfg(test)]
+mod auth_tests {
+    use std::sync::Arc;
+
+    use reqwest::{header::COOKIE, StatusCode};
+    use serde_json::json;
+    use tabnews::internal::http_client::{mock::MockTransport, HttpClient};
+    use tabnews::{Error, TabnewsClient};
+
+    fn mocked_client() -> (TabnewsClient, Arc<MockTransport>) {
+        let transport = Arc::new(MockTransport::default());
+        let client =
+            HttpClient::with_transport("http://tabnews.test/api/v1".to_owned(), transport.clone());
+
+        (TabnewsClient::from_http_client(Arc::new(client)), transport)
+    }
+
+    fn session() -> serde_json::Value {
+        json!({
+            "id": "session-id",
+            "token": "session-token",
+            "expires_at": "2023-01-31T00:00:00.000Z",
+            "created_at": "2023-01-01T00:00:00.000Z",
+            "updated_at": "2023-01-01T00:00:00.000Z"
+        })
+    }
+
+    #[tokio::test]
+    async fn get_user_session() {
+        let (client, transport) = mocked_client();
+        transport
+            .push_json(StatusCode::CREATED, &session())
+            .unwrap();
+
+        let response = client
+            .auth_api
+            .get_user_session("email@example.com".to_owned(), "password".to_owned())
+            .await
+            .unwrap();
+
+        let request = &transport.requests()[0];
+        let body: serde_json::Value =
+            serde_json::from_slice(request.body.as_ref().unwrap()).unwrap();
+        assert_eq!(request.path(), "/api/v1/sessions");
+        assert_eq!(body["email"], "email@example.com");
+        assert_eq!(response.token, "session-token");
+        assert!(!client.is_logged())
+    }
+
+    #[tokio::test]
+    async fn login_sets_cookie() {
+        let (client, transport) = mocked_client();
+        transport
+            .push_json(StatusCode::CREATED, &session())
+            .unwrap();
+        transport
+            .push_json(
+                StatusCode::OK,
+                &json!({
+                    "id": "user-id",
+                    "username": "fadiinho",
+                    "features": [],
+                    "tabcoins": 0,
+                    "tabcash": 0,
+                    "created_at": "2023-01-01T00:00:00.000Z",
+                    "updated_at": "2023-01-01T00:00:00.000Z"
+                }),
+            )
+            .unwrap();
+
+        client
+            .auth_api
+            .login("email@example.com".to_owned(), "password".to_owned())
+            .await
+            .unwrap();
+
+        assert!(client.is_logged());
+
+        let user = client.user_api.get_current_user().await.unwrap();
+
+        let request = &transport.requests()[1];
+        assert_eq!(request.headers[COOKIE], "session_id=session-token");
+        assert_eq!(user.username, "fadiinho")
+    }
+
+    #[tokio::test]
+    async fn login_with_wrong_password() {
+        let (client, transport) = mocked_client();
+        transport
+            .push_json(
+                StatusCode::UNAUTHORIZED,
+                &json!({
+                    "name": "UnauthorizedError",
+                    "message": "Dados não conferem.",
+                    "action": "Verifique se os dados enviados estão corretos.",
+                    "status_code": 401,
+                    "error_id": "error-id",
+                    "request_id": "request-id",
+                    "error_location_code": "CONTROLLER:SESSIONS:POST_HANDLER:DATA_MISMATCH"
+                }),
+            )
+            .unwrap();
+
+        let response = client
+            .auth_api
+            .login("email@example.com".to_owned(), "wrong".to_owned())
+            .await;
+
+        assert!(matches!(response, Err(Error::Http { status: 401, .. })));
+        assert!(!client.is_logged())
+    }
+
+    #[tokio::test]
+    async fn get_current_user_without_login() {
+        let (client, transport) = mocked_client();
+
+        let response = client.user_api.get_current_user().await;
+
+        assert!(matches!(response, Err(Error::NotLoggedIn)));
+        assert!(transport.requests().is_empty())
+    }
+}
@@ -0,0 +1,143 @@
+#[cfg(test)]
+mod blocking_tests {
+    use tabnews::blocking::TabnewsClient;
+    use tabnews::models::content::Content;
+    use tabnews::testing::{FakeServer, SEEDED_PASSWORD};
+    use tabnews::Error;
+
+    fn fake_client() -> (TabnewsClient, FakeServer) {
+        let server = FakeServer::start();
+        let client = TabnewsClient::new().unwrap();
+        client.http_client.set_host(server.url());
+
+        (client, server)
+    }
+
+    #[test]
+    fn get_homepage_posts() {
+        let (client, _server) = fake_client();
+
+        let response = client.posts_api.get_homepage_posts(None).unwrap();
+
+        assert!(!response.is_empty())
+    }
+
+    #[test]
+    fn get_post_details_and_comments() {
+        let (client, _server) = fake_client();
+
+        let post = client
+            .posts_api
+            .get_post_details("GabrielSozinho", "documentacao-da-api-do-tabnews")
+            .unwrap();
+        let comments = client
+            .posts_api
+            .get_post_comments("GabrielSozinho", "documentacao-da-api-do-tabnews")
+            .unwrap();
+
+        assert_eq!(post.tabcoins, Some(42));
+        assert!(!comments.is_empty())
+    }
+
+    #[test]
+    fn get_user() {
+        let (client, _server) = fake_client();
+
+        let response = client.users_api.get_user("fadiinho").unwrap();
+
+        assert!(!response.id.is_empty())
+    }
+
+    #[test]
+    fn get_analytics() {
+        let (client, _server) = fake_client();
+
+        assert!(client.analytics_api.get_users_created().is_ok());
+        assert!(client.analytics_api.get_posts_published().is_ok());
+        assert!(client.analytics_api.get_comments_published().is_ok());
+    }
+
+    #[test]
+    fn current_user_without_login() {
+        let (client, _server) = fake_client();
+
+        let response = client.user_api.get_current_user();
+
+        assert!(matches!(response, Err(Error::NotLoggedIn)))
+    }
+
+    #[test]
+    fn login_is_shared_by_every_api() {
+        let (client, _server) = fake_client();
+
+        client
+            .auth_api
+            .login(
+                "fadiinho@tabnews.test".to_owned(),
+                SEEDED_PASSWORD.to_owned(),
+            )
+            .unwrap();
+
+        let user = client.user_api.get_current_user().unwrap();
+
+        assert!(client.is_logged());
+        assert_eq!(user.username, "fadiinho");
+    }
+
+    #[test]
+    fn publish_post_and_read_it_back() {
+        let (client, _server) = fake_client();
+        client
+            .auth_api
+            .login(
+                "fadiinho@tabnews.test".to_owned(),
+                SEEDED_PASSWORD.to_owned(),
+            )
+            .unwrap();
+
+        let mut content = Content::default();
+        content
+            .set_title("Post síncrono")
+            .set_body("Publicado pelo cliente bloqueante");
+
+        let published = client.posts_api.publish_post(content).unwrap();
+        let slug = published.slug.unwrap();
+
+        let details = client
+            .posts_api
+            .get_post_details("fadiinho", &slug)
+            .unwrap();
+
+        assert_eq!(details.title.as_deref(), Some("Post síncrono"));
+    }
+
+    #[test]
+    fn from_async_shares_the_http_client() {
+        let server = FakeServer::start();
+        let async_client = tabnews::TabnewsClient::default();
+        async_client.http_client.set_host(server.url());
+
+        let client = TabnewsClient::from_async(async_client.clone()).unwrap();
+
+        assert_eq!(client.http_client.host(), async_client.http_client.host());
+        assert!(client.posts_api.get_rss().unwrap().contains("<rss"));
+        assert!(!client.posts_api.get_rss_feed().unwrap().items.is_empty());
+    }
+
+    #[test]
+    fn iter_homepage_posts() {
+        let (client, _server) = fake_client();
+
+        let posts: Vec<Content> = client
+            .posts_api
+            .iter_homepage_posts(None)
+            .per_page(1)
+            .prefetch(true)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        let limited = client.posts_api.iter_homepage_posts(None).limit(1).count();
+
+        assert_eq!(posts.len(), 2);
+        assert_eq!(limited, 1);
+    }
+}
@@ -0,0 +1,167 @@
+#[cfg(test)]
+mod builder_tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use reqwest::{
+        header::{COOKIE, USER_AGENT},
+        StatusCode,
+    };
+    use serde_json::json;
+    use tabnews::internal::http_client::mock::MockTransport;
+    use tabnews::testing::{FakeServer, SEEDED_PASSWORD};
+    use tabnews::{Error, TabnewsClient};
+
+    #[tokio::test]
+    async fn build_with_fake_server() {
+        let server = FakeServer::start();
+
+        let client = TabnewsClient::builder()
+            .base_url(server.url())
+            .connect_timeout(Duration::from_secs(5))
+            .request_timeout(Duration::from_secs(5))
+            .build()
+            .unwrap();
+
+        let posts = client.posts_api.get_homepage_posts(None).await.unwrap();
+
+        assert_eq!(client.http_client.host(), server.url());
+        assert!(!posts.is_empty());
+    }
+
+    #[tokio::test]
+    async fn session_token_logs_in() {
+        let server = FakeServer::start();
+        let session = TabnewsClient::builder()
+            .base_url(server.url())
+            .build()
+            .unwrap()
+            .auth_api
+            .get_user_session(
+                "gabriel@tabnews.test".to_owned(),
+                SEEDED_PASSWORD.to_owned(),
+            )
+            .await
+            .unwrap();
+
+        let client = TabnewsClient::builder()
+            .base_url(server.url())
+            .session_token(session.token)
+            .build()
+            .unwrap();
+
+        let user = client.user_api.get_current_user().await.unwrap();
+
+        assert!(client.is_logged());
+        assert_eq!(user.username, "GabrielSozinho");
+    }
+
+    #[tokio::test]
+    async fn sends_user_agent_and_default_headers() {
+        let transport = Arc::new(MockTransport::default());
+        transport.push_json(StatusCode::OK, &json!([])).unwrap();
+
+        let client = TabnewsClient::builder()
+            .base_url("http://tabnews.test/api/v1/")
+            .transport(transport.clone())
+            .user_agent("my-app/1.0")
+            .default_header("Accept-Language", "pt-BR")
+            .default_headers(HashMap::from([("X-Trace".to_owned(), "1".to_owned())]))
+            .session_token("token")
+            .build()
+            .unwrap();
+
+        client.posts_api.get_homepage_posts(None).await.unwrap();
+
+        let request = &transport.requests()[0];
+
+        assert_eq!(request.url, "http://tabnews.test/api/v1/contents");
+        assert_eq!(request.headers[USER_AGENT], "my-app/1.0");
+        assert_eq!(request.headers["accept-language"], "pt-BR");
+        assert_eq!(request.headers["x-trace"], "1");
+        assert_eq!(request.headers[COOKIE], "session_id=token");
+    }
+
+    #[test]
+    fn default_user_agent() {
+        let client = TabnewsClient::builder().build().unwrap();
+
+        let user_agent = client.http_client.get_header("User-Agent").unwrap();
+
+        assert!(user_agent.starts_with("Tabnews-RS/"));
+        assert!(!client.is_logged());
+    }
+
+    #[test]
+    fn invalid_base_url() {
+        for base_url in [
+            "not a url",
+            "ftp://tabnews.com.br",
+            "https://x.com/api?page=1",
+        ] {
+            let result = TabnewsClient::builder().base_url(base_url).build();
+
+            assert!(
+                matches!(result, Err(Error::InvalidInput(_))),
+                "{} should be rejected",
+                base_url
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_proxy() {
+        let result = TabnewsClient::builder().proxy("not a proxy").build();
+
+        assert!(matches!(result, Err(Error::InvalidInput(_))))
+    }
+
+    #[test]
+    fn invalid_root_certificate() {
+        let result = TabnewsClient::builder()
+            .add_root_certificate("not a certificate")
+            .build();
+
+        assert!(matches!(result, Err(Error::InvalidInput(_))))
+    }
+
+    #[test]
+    fn zero_timeout() {
+        let result = TabnewsClient::builder()
+            .request_timeout(Duration::ZERO)
+            .build();
+
+        assert!(matches!(result, Err(Error::InvalidInput(_))))
+    }
+
+    #[test]
+    fn invalid_headers() {
+        let name = TabnewsClient::builder()
+            .default_header("invalid header", "value")
+            .build();
+        let user_agent = TabnewsClient::builder().user_agent("new\nline").build();
+
+        assert!(matches!(name, Err(Error::InvalidHeader(_))));
+        assert!(matches!(user_agent, Err(Error::InvalidHeader(_))));
+    }
+
+    #[test]
+    fn invalid_session_token() {
+        for token in ["", "token; admin=true", "with space"] {
+            let result = TabnewsClient::builder().session_token(token).build();
+
+            assert!(matches!(result, Err(Error::InvalidInput(_))));
+        }
+    }
+
+    #[test]
+    fn custom_transport_with_reqwest_settings() {
+        let result = TabnewsClient::builder()
+            .transport(Arc::new(MockTransport::default()))
+            .proxy("http://localhost:8080")
+            .build();
+
+        assert!(matches!(result, Err(Error::InvalidInput(_))))
+    }
+}
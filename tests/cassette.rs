@@ -0,0 +1,310 @@
+#[cfg(test)]
+mod cassette_tests {
+    use std::path::PathBuf;
+    use std::sync::Arc;
+
+    use serde_json::{json, Value};
+    use tabnews::internal::http_client::cassette::{
+        Cassette, MatchRules, RecordedBody, RecordingTransport, ReplayTransport, Scrubber,
+    };
+    use tabnews::internal::http_client::{retry::RetryPolicy, ReqwestTransport};
+    use tabnews::models::content::Content;
+    use tabnews::testing::{FakeServer, SEEDED_PASSWORD};
+    use tabnews::{Error, TabnewsClient};
+
+    fn cassette_path(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("tabnews-cassettes-{}", std::process::id()))
+            .join(name)
+    }
+
+    fn recording_client(server: &FakeServer, path: &PathBuf) -> TabnewsClient {
+        let recorder = RecordingTransport::new(Arc::new(ReqwestTransport::default()), path);
+
+        TabnewsClient::builder()
+            .base_url(server.url())
+            .transport(Arc::new(recorder))
+            .build()
+            .unwrap()
+    }
+
+    fn replaying_client(replay: ReplayTransport) -> TabnewsClient {
+        TabnewsClient::builder()
+            .base_url("http://tabnews.test/api/v1")
+            .transport(Arc::new(replay))
+            .retry_policy(RetryPolicy::none())
+            .build()
+            .unwrap()
+    }
+
+    async fn record_session(path: &PathBuf) {
+        let server = FakeServer::start();
+        let client = recording_client(&server, path);
+
+        client.posts_api.get_homepage_posts(None).await.unwrap();
+        client
+            .posts_api
+            .get_post_tabcoins("GabrielSozinho", "documentacao-da-api-do-tabnews")
+            .await
+            .unwrap();
+        client
+            .posts_api
+            .get_post_thumbnail("GabrielSozinho", "documentacao-da-api-do-tabnews")
+            .await
+            .unwrap();
+        client.users_api.get_user("fadiinho").await.unwrap();
+        client.analytics_api.get_users_created().await.unwrap();
+        client
+            .auth_api
+            .login(
+                "fadiinho@tabnews.test".to_owned(),
+                SEEDED_PASSWORD.to_owned(),
+            )
+            .await
+            .unwrap();
+        client
+            .posts_api
+            .upvote("GabrielSozinho", "documentacao-da-api-do-tabnews")
+            .await
+            .unwrap();
+        client
+            .posts_api
+            .get_post_tabcoins("GabrielSozinho", "documentacao-da-api-do-tabnews")
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn concurrent_requests_are_all_saved() {
+        let path = cassette_path("concurrent.json");
+        let server = FakeServer::start();
+        let client = recording_client(&server, &path);
+
+        let requests = (0..16).map(|_| {
+            let client = client.clone();
+            tokio::spawn(async move { client.users_api.get_user("fadiinho").await })
+        });
+
+        for request in futures::future::join_all(requests).await {
+            request.unwrap().unwrap();
+        }
+
+        let cassette = Cassette::load(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(cassette.interactions.len(), 16);
+    }
+
+    #[tokio::test]
+    async fn record_and_replay_yaml() {
+        let path = cassette_path("session.yaml");
+        record_session(&path).await;
+
+        let replay = ReplayTransport::open(&path).unwrap();
+        let client = replaying_client(replay);
+
+        let posts = client.posts_api.get_homepage_posts(None).await.unwrap();
+        let before = client
+            .posts_api
+            .get_post_tabcoins("GabrielSozinho", "documentacao-da-api-do-tabnews")
+            .await
+            .unwrap();
+        let thumbnail = client
+            .posts_api
+            .get_post_thumbnail("GabrielSozinho", "documentacao-da-api-do-tabnews")
+            .await
+            .unwrap();
+        let user = client.users_api.get_user("fadiinho").await.unwrap();
+        let created = client.analytics_api.get_users_created().await.unwrap();
+        client
+            .auth_api
+            .login("fadiinho@tabnews.test".to_owned(), "any".to_owned())
+            .await
+            .unwrap();
+        client
+            .posts_api
+            .upvote("GabrielSozinho", "documentacao-da-api-do-tabnews")
+            .await
+            .unwrap();
+        let after = client
+            .posts_api
+            .get_post_tabcoins("GabrielSozinho", "documentacao-da-api-do-tabnews")
+            .await
+            .unwrap();
+
+        std::fs::remove_file(&path).ok();
+
+        assert!(!posts.is_empty());
+        assert!(thumbnail.bytes().starts_with(b"\x89PNG"));
+        assert_eq!(user.username, "fadiinho");
+        assert!(!created.is_empty());
+        assert_eq!(after, before + 1);
+    }
+
+    #[tokio::test]
+    async fn secrets_are_scrubbed() {
+        let path = cassette_path("scrubbed.json");
+        record_session(&path).await;
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let cassette = Cassette::load(&path).unwrap();
+
+        std::fs::remove_file(&path).ok();
+
+        let login = cassette
+            .interactions
+            .iter()
+            .find(|interaction| interaction.request.url.ends_with("/sessions"))
+            .unwrap();
+        let body = |body: &RecordedBody| match body {
+            RecordedBody::Text(text) => serde_json::from_str::<Value>(text).unwrap(),
+            RecordedBody::Bytes(_) => panic!("expected a json body"),
+        };
+
+        assert_eq!(
+            body(login.request.body.as_ref().unwrap())["password"],
+            "[redacted]"
+        );
+        assert_eq!(body(&login.response.body)["token"], "[redacted]");
+        assert!(!content.contains("session_id="));
+    }
+
+    #[tokio::test]
+    async fn custom_scrubber() {
+        let path = cassette_path("custom-scrubber.json");
+        let server = FakeServer::start();
+        let recorder = RecordingTransport::new(Arc::new(ReqwestTransport::default()), &path)
+            .scrubber(Scrubber::default().json_key("email"));
+        let client = TabnewsClient::builder()
+            .base_url(server.url())
+            .transport(Arc::new(recorder))
+            .build()
+            .unwrap();
+
+        client
+            .auth_api
+            .get_user_session(
+                "fadiinho@tabnews.test".to_owned(),
+                SEEDED_PASSWORD.to_owned(),
+            )
+            .await
+            .unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert!(!content.contains("fadiinho@tabnews.test"));
+    }
+
+    #[tokio::test]
+    async fn unmatched_request_fails() {
+        let path = cassette_path("unmatched.json");
+        let server = FakeServer::start();
+        recording_client(&server, &path)
+            .users_api
+            .get_user("fadiinho")
+            .await
+            .unwrap();
+
+        let replay = ReplayTransport::open(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+        let client = replaying_client(replay);
+
+        let other_user = client.users_api.get_user("GabrielSozinho").await;
+        let first = client.users_api.get_user("fadiinho").await;
+        let played_twice = client.users_api.get_user("fadiinho").await;
+
+        assert!(matches!(other_user, Err(Error::Replay(_))));
+        assert!(first.is_ok());
+        assert!(matches!(played_twice, Err(Error::Replay(_))));
+    }
+
+    #[tokio::test]
+    async fn allow_repeats() {
+        let path = cassette_path("repeats.json");
+        let server = FakeServer::start();
+        recording_client(&server, &path)
+            .users_api
+            .get_user("fadiinho")
+            .await
+            .unwrap();
+
+        let replay = ReplayTransport::open(&path).unwrap().allow_repeats(true);
+        std::fs::remove_file(&path).ok();
+        let client = replaying_client(replay);
+
+        client.users_api.get_user("fadiinho").await.unwrap();
+        client.users_api.get_user("fadiinho").await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn query_and_body_rules() {
+        let path = cassette_path("rules.json");
+        let server = FakeServer::start();
+        let client = recording_client(&server, &path);
+        client
+            .auth_api
+            .login(
+                "fadiinho@tabnews.test".to_owned(),
+                SEEDED_PASSWORD.to_owned(),
+            )
+            .await
+            .unwrap();
+        let mut content = Content::default();
+        content.set_title("Gravado").set_body("Corpo gravado");
+        client.posts_api.publish_post(content).await.unwrap();
+
+        let cassette = Cassette::load(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        let strict = replaying_client(
+            ReplayTransport::new(cassette.clone(), "rules")
+                .match_rules(MatchRules::default().body(true)),
+        );
+        let other = || {
+            let mut other = Content::default();
+            other.set_title("Outro").set_body("Outro corpo");
+
+            other
+        };
+
+        assert!(matches!(
+            strict.posts_api.publish_post(other()).await,
+            Err(Error::Replay(_))
+        ));
+
+        let loose = replaying_client(ReplayTransport::new(cassette, "rules"));
+
+        assert!(loose.posts_api.publish_post(other()).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn query_order_is_ignored() {
+        let path = cassette_path("query.json");
+        let server = FakeServer::start();
+        let client = recording_client(&server, &path);
+        client
+            .http_client
+            .get("/contents?page=1&per_page=2".to_owned())
+            .await
+            .unwrap();
+
+        let replay = ReplayTransport::open(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+        let client = replaying_client(replay);
+
+        let reordered = client
+            .http_client
+            .get("/contents?per_page=2&page=1".to_owned())
+            .await;
+
+        assert!(reordered.is_ok());
+        assert!(matches!(
+            client
+                .http_client
+                .get_with_params("/contents".to_owned(), Some(&json!({ "page": 3 })))
+                .await,
+            Err(Error::Replay(_))
+        ));
+    }
+}
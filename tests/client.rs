@@ -0,0 +1,48 @@
+#[cfg(test)]
+mod client_tests {
+    use std::sync::Arc;
+
+    use reqwest::header::{HeaderValue, COOKIE};
+    use tabnews::internal::http_client::HttpClient;
+    use tabnews::TabnewsClient;
+
+    fn assert_send_sync<T: Send + Sync + 'static>() {}
+
+    #[test]
+    fn client_is_send_and_sync() {
+        assert_send_sync::<TabnewsClient>();
+        assert_send_sync::<HttpClient>();
+    }
+
+    #[test]
+    fn api_groups_share_headers() {
+        let client = TabnewsClient::default();
+
+        assert!(!client.is_logged());
+
+        let cloned = client.clone();
+        cloned
+            .http_client
+            .add_header(COOKIE, HeaderValue::from_static("session_id=token"));
+
+        assert!(client.is_logged());
+    }
+
+    #[tokio::test]
+    async fn client_can_be_used_across_tasks() {
+        let http_client = Arc::new(HttpClient::new("http://127.0.0.1:1/api/v1".to_owned()));
+        let client = TabnewsClient::from_http_client(http_client);
+
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let client = client.clone();
+
+                tokio::spawn(async move { client.posts_api.get_homepage_posts(None).await })
+            })
+            .collect();
+
+        for handle in handles {
+            assert!(handle.await.unwrap().is_err());
+        }
+    }
+}
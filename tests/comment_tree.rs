@@ -0,0 +1,191 @@
+#[cfg(test)]
+mod comment_tree_tests {
+    use serde_json::json;
+    use tabnews::models::comment_tree::{CommentOrder, CommentTree};
+    use tabnews::models::content::Content;
+    use tabnews::testing::FakeServer;
+    use tabnews::TabnewsClient;
+
+    /// a (1 tabcoin)
+    /// ├── b
+    /// │   └── d
+    /// └── c
+    ///     └── e
+    ///         └── f
+    /// g (5 tabcoins)
+    fn tree() -> CommentTree {
+        let comment = |id: &str, tabcoins: i64, date: &str, deep: u64, children| {
+            json!({
+                "id": id,
+                "tabcoins": tabcoins,
+                "published_at": date,
+                "children_deep_count": deep,
+                "children": children,
+            })
+        };
+
+        let comments = json!([
+            comment(
+                "a",
+                1,
+                "2022-05-01T00:00:00.000Z",
+                5,
+                json!([
+                    comment(
+                        "b",
+                        0,
+                        "2022-05-03T00:00:00.000Z",
+                        1,
+                        json!([comment("d", 0, "2022-05-04T00:00:00.000Z", 0, json!([]))]),
+                    ),
+                    comment(
+                        "c",
+                        2,
+                        "2022-05-02T00:00:00.000Z",
+                        2,
+                        json!([comment(
+                            "e",
+                            0,
+                            "2022-05-05T00:00:00.000Z",
+                            1,
+                            json!([comment("f", 0, "2022-05-06T00:00:00.000Z", 0, json!([]))]),
+                        )]),
+                    ),
+                ]),
+            ),
+            comment("g", 5, "2022-05-07T00:00:00.000Z", 0, json!([])),
+        ]);
+
+        let comments: Vec<Content> = serde_json::from_value(comments).unwrap();
+
+        CommentTree::new(comments)
+    }
+
+    fn ids<'a>(contents: impl Iterator<Item = &'a Content>) -> Vec<&'a str> {
+        contents.map(|c| c.id.as_deref().unwrap()).collect()
+    }
+
+    #[test]
+    fn depth_first() {
+        let tree = tree();
+
+        assert_eq!(tree.len(), 7);
+        assert_eq!(
+            ids(tree.depth_first().map(|node| node.content())),
+            ["a", "b", "d", "c", "e", "f", "g"]
+        );
+    }
+
+    #[test]
+    fn breadth_first() {
+        let tree = tree();
+
+        assert_eq!(
+            ids(tree.breadth_first().map(|node| node.content())),
+            ["a", "g", "b", "c", "d", "e", "f"]
+        );
+    }
+
+    #[test]
+    fn parents_and_depths() {
+        let tree = tree();
+
+        assert_eq!(tree.parent_of("f").and_then(|c| c.id.as_deref()), Some("e"));
+        assert!(tree.parent_of("a").is_none());
+        assert!(tree.parent_of("missing").is_none());
+
+        assert_eq!(tree.depth_of("a"), Some(0));
+        assert_eq!(tree.depth_of("f"), Some(3));
+        assert_eq!(tree.depth_of("missing"), None);
+
+        let children = tree.get("a").unwrap().children().map(|n| n.content());
+        assert_eq!(ids(children), ["b", "c"]);
+    }
+
+    #[test]
+    fn flatten_with_depth() {
+        let tree = tree();
+
+        let flat: Vec<(usize, &str)> = tree
+            .flatten()
+            .map(|(depth, c)| (depth, c.id.as_deref().unwrap()))
+            .collect();
+
+        assert_eq!(
+            flat,
+            [
+                (0, "a"),
+                (1, "b"),
+                (2, "d"),
+                (1, "c"),
+                (2, "e"),
+                (3, "f"),
+                (0, "g")
+            ]
+        );
+        assert!(tree.flatten().all(|(_, c)| c.children.is_none()));
+    }
+
+    #[test]
+    fn sort_siblings() {
+        let mut tree = tree();
+
+        tree.sort_by(CommentOrder::Tabcoins);
+        assert_eq!(
+            ids(tree.depth_first().map(|node| node.content())),
+            ["g", "a", "c", "e", "f", "b", "d"]
+        );
+
+        tree.sort_by(CommentOrder::Oldest);
+        assert_eq!(
+            ids(tree.depth_first().map(|node| node.content())),
+            ["a", "c", "e", "f", "b", "d", "g"]
+        );
+
+        tree.sort_by(CommentOrder::Newest);
+        assert_eq!(ids(tree.roots().map(|node| node.content())), ["g", "a"]);
+    }
+
+    #[test]
+    fn subtree_counts() {
+        let tree = tree();
+
+        for node in tree.depth_first() {
+            assert_eq!(
+                Some(node.subtree_count()),
+                node.content().children_deep_count
+            );
+            assert!(node.is_complete());
+        }
+    }
+
+    #[test]
+    fn into_contents() {
+        let tree = tree();
+
+        let contents = tree.into_contents();
+
+        assert_eq!(ids(contents.iter()), ["a", "b", "d", "c", "e", "f", "g"]);
+    }
+
+    #[tokio::test]
+    async fn get_comment_tree() {
+        let server = FakeServer::start();
+        let client = TabnewsClient::default();
+        client.http_client.set_host(server.url());
+
+        let tree = client
+            .posts_api
+            .get_comment_tree("GabrielSozinho", "documentacao-da-api-do-tabnews")
+            .await
+            .unwrap();
+
+        let flat: Vec<(usize, &str)> = tree
+            .flatten()
+            .map(|(depth, c)| (depth, c.owner_username.as_deref().unwrap()))
+            .collect();
+
+        assert_eq!(flat, [(0, "fadiinho"), (1, "GabrielSozinho")]);
+        assert!(tree.depth_first().all(|node| node.is_complete()));
+    }
+}
@@ -0,0 +1,221 @@
+#[cfg(test)]
+mod feed_tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use serde_json::{json, Value};
+    use tabnews::feed::Feed;
+    use tabnews::models::content::Content;
+    use tabnews::models::rss::RssFeed;
+    use tabnews::testing::FakeServer;
+    use tabnews::TabnewsClient;
+
+    fn contents() -> Vec<Content> {
+        serde_json::from_value(json!([
+            {
+                "id": "1",
+                "owner_username": "fadiinho",
+                "slug": "tabnews-rs",
+                "title": "TabNews & Rust",
+                "body": "Um wrapper pra API do TabNews escrito em Rust, com <tipos> para tudo.",
+                "source_url": "https://github.com/fadiinho/tabnews-rs",
+                "status": "published",
+                "created_at": "2022-12-31T23:00:00.000Z",
+                "published_at": "2023-01-01T00:00:00.000Z",
+                "updated_at": "2023-01-02T00:00:00.000Z",
+            },
+            {
+                "id": "2",
+                "parent_id": "1",
+                "owner_username": "GabrielSozinho",
+                "slug": "comentario",
+                "body": "Muito bom",
+                "status": "published",
+                "published_at": "2023-01-01T12:00:00.500-03:00",
+            },
+            {
+                "id": "3",
+                "owner_username": "fadiinho",
+                "slug": "rascunho",
+                "title": "Rascunho",
+                "status": "draft",
+            },
+            {
+                "id": "4",
+                "title": "Sem dono",
+                "status": "published",
+            },
+        ]))
+        .unwrap()
+    }
+
+    #[test]
+    fn atom() {
+        let atom = Feed::new("Posts de fadiinho", contents())
+            .feed_url("https://example.com/atom.xml")
+            .to_atom();
+
+        assert!(atom.starts_with("<?xml"));
+        assert!(atom.contains("<feed xmlns=\"http://www.w3.org/2005/Atom\">"));
+        assert!(atom.contains("<id>https://example.com/atom.xml</id>"));
+        assert!(atom.contains("<link rel=\"self\" href=\"https://example.com/atom.xml\"/>"));
+        assert!(atom.contains("<updated>2023-01-02T00:00:00Z</updated>"));
+
+        assert!(atom.contains("<id>https://www.tabnews.com.br/fadiinho/tabnews-rs</id>"));
+        assert!(atom.contains("<title>TabNews &amp; Rust</title>"));
+        assert!(atom.contains("<published>2023-01-01T00:00:00Z</published>"));
+        assert!(atom.contains("<name>fadiinho</name>"));
+        assert!(atom.contains("<uri>https://www.tabnews.com.br/fadiinho</uri>"));
+        assert!(atom.contains("com &lt;tipos&gt; para tudo"));
+
+        // Comments use their summary as title and keep the offset
+        assert!(atom.contains("<title>Muito bom</title>"));
+        assert!(atom.contains("<published>2023-01-01T15:00:00Z</published>"));
+
+        assert_eq!(atom.matches("<entry>").count(), 2);
+        assert!(!atom.contains("rascunho"));
+    }
+
+    #[test]
+    fn rss_is_read_back() {
+        let xml = Feed::new("Posts de fadiinho", contents())
+            .site_url("https://www.tabnews.com.br/")
+            .description("Conteúdos de fadiinho")
+            .to_rss();
+
+        let feed: RssFeed = xml.parse().unwrap();
+
+        assert_eq!(feed.title.as_deref(), Some("Posts de fadiinho"));
+        assert_eq!(feed.description.as_deref(), Some("Conteúdos de fadiinho"));
+        assert_eq!(
+            feed.last_build_date,
+            Some(UNIX_EPOCH + Duration::from_secs(1_672_617_600))
+        );
+        assert_eq!(feed.items.len(), 2);
+
+        let item = &feed.items[0];
+        assert_eq!(item.title.as_deref(), Some("TabNews & Rust"));
+        assert_eq!(item.link, item.guid);
+        assert_eq!(item.author.as_deref(), Some("fadiinho"));
+        assert_eq!(
+            item.pub_date,
+            Some(UNIX_EPOCH + Duration::from_secs(1_672_531_200))
+        );
+        assert_eq!(item.username_and_slug(), Some(("fadiinho", "tabnews-rs")));
+    }
+
+    #[test]
+    fn json_feed() {
+        let json = Feed::new("Posts de fadiinho", contents()).to_json_feed();
+        let feed: Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(feed["version"], "https://jsonfeed.org/version/1.1");
+        assert_eq!(feed["home_page_url"], "https://www.tabnews.com.br");
+        assert!(feed.get("feed_url").is_none());
+
+        let items = feed["items"].as_array().unwrap();
+        assert_eq!(items.len(), 2);
+
+        assert_eq!(
+            items[0]["url"],
+            "https://www.tabnews.com.br/fadiinho/tabnews-rs"
+        );
+        assert_eq!(
+            items[0]["external_url"],
+            "https://github.com/fadiinho/tabnews-rs"
+        );
+        assert_eq!(items[0]["date_published"], "2023-01-01T00:00:00Z");
+        assert_eq!(items[0]["date_modified"], "2023-01-02T00:00:00Z");
+        assert_eq!(items[0]["authors"][0]["name"], "fadiinho");
+
+        assert!(items[1].get("title").is_none());
+        assert!(items[1].get("date_modified").is_none());
+    }
+
+    #[test]
+    fn summary_length() {
+        let json = Feed::new("Posts de fadiinho", contents())
+            .summary_length(20)
+            .to_json_feed();
+        let feed: Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(feed["items"][0]["summary"], "Um wrapper pra API…");
+        assert_eq!(feed["items"][1]["summary"], "Muito bom");
+
+        let json = Feed::new("Posts de fadiinho", contents())
+            .summary_length(5)
+            .to_json_feed();
+        let feed: Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(feed["items"][0]["summary"], "Um…");
+    }
+
+    #[test]
+    fn out_of_range_dates() {
+        let dates = [
+            "10000-01-01T00:00:00.000Z",
+            "9999-12-31T23:00:00.000-05:00",
+            "9223372036854775807-01-01T00:00:00.000Z",
+            "1969-12-31T23:59:59.000Z",
+            "2023-02-29T00:00:00.000Z",
+            "2023-01-01T00:00:00.000+99999999999999:00",
+        ];
+        let contents: Vec<Content> = dates
+            .iter()
+            .enumerate()
+            .map(|(index, date)| {
+                serde_json::from_value(json!({
+                    "owner_username": "fadiinho",
+                    "slug": format!("post-{}", index),
+                    "status": "published",
+                    "published_at": date,
+                    "updated_at": date,
+                }))
+                .unwrap()
+            })
+            .collect();
+        let feed = Feed::new("Datas", contents);
+
+        assert!(!feed.to_rss().contains("pubDate"));
+        assert!(!feed.to_atom().contains("<published>"));
+
+        let json: Value = serde_json::from_str(&feed.to_json_feed()).unwrap();
+        let items = json["items"].as_array().unwrap();
+        assert_eq!(items.len(), dates.len());
+        assert!(items
+            .iter()
+            .all(|item| item.get("date_published").is_none()));
+    }
+
+    #[tokio::test]
+    async fn feed_of_an_author() {
+        let server = FakeServer::start();
+        let client = TabnewsClient::default();
+        client.http_client.set_host(server.url());
+
+        let posts = client
+            .posts_api
+            .get_posts_by_user("fadiinho", None)
+            .await
+            .unwrap()
+            .into_items();
+        let count = posts.len();
+
+        let feed: RssFeed = Feed::new("Posts de fadiinho", posts)
+            .to_rss()
+            .parse()
+            .unwrap();
+
+        assert_eq!(feed.items.len(), count);
+        for item in &feed.items {
+            let (username, slug) = item.username_and_slug().unwrap();
+            let post = client
+                .posts_api
+                .get_post_details(username, slug)
+                .await
+                .unwrap();
+
+            assert_eq!(post.owner_username.as_deref(), item.author.as_deref());
+            assert!(item.pub_date.is_some());
+        }
+    }
+}
@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod html_tests {
+    use tabnews::html::render_markdown;
+    use tabnews::models::content::Content;
+    use tabnews::testing::FakeServer;
+    use tabnews::TabnewsClient;
+
+    #[test]
+    fn commonmark() {
+        assert_eq!(
+            render_markdown("# Título\n\nTexto em **negrito** e ~~riscado~~."),
+            "<h1>Título</h1>\n<p>Texto em <strong>negrito</strong> e <del>riscado</del>.</p>\n"
+        );
+    }
+
+    #[test]
+    fn tables_and_task_lists() {
+        let html = render_markdown(
+            "| Método | Rota |\n| :--- | :---: |\n| GET | /contents |\n\n- [x] Feito\n- [ ] A fazer\n",
+        );
+
+        assert!(html.contains("<table>"));
+        assert!(html.contains("<th style=\"text-align: left\">Método</th>"));
+        assert!(html.contains("<td style=\"text-align: center\">/contents</td>"));
+        assert!(html.contains("<input disabled=\"\" type=\"checkbox\" checked=\"\">"));
+        assert!(html.contains("<input disabled=\"\" type=\"checkbox\">"));
+    }
+
+    #[test]
+    fn code_math_and_mermaid() {
+        let html = render_markdown(
+            "```rust\nfn main() {}\n```\n\nA área é $\\pi r^2$.\n\n$$\nx < y\n$$\n\n```mermaid\ngraph TD;\n  A-->B;\n```\n",
+        );
+
+        assert!(html.contains("<pre><code class=\"language-rust\">fn main() {}\n</code></pre>"));
+        assert!(html.contains("<span class=\"math math-inline\">\\pi r^2</span>"));
+        assert!(html.contains("<span class=\"math math-display\">\nx &lt; y\n</span>"));
+        assert!(html.contains("<pre class=\"mermaid\">graph TD;\n  A--&gt;B;\n</pre>"));
+    }
+
+    #[test]
+    fn sanitizes_raw_html() {
+        let html = render_markdown(
+            "<script>alert(1)</script>\n\n<img src=\"x.png\" onerror=\"alert(1)\">\n\n\
+             [clique](javascript:alert(1))\n\n<span class=\"evil\" style=\"color: red\">oi</span>\n\n\
+             <input type=\"text\" value=\"senha\">",
+        );
+
+        assert!(!html.contains("script"));
+        assert!(!html.contains("onerror"));
+        assert!(!html.contains("javascript"));
+        assert!(!html.contains("evil"));
+        assert!(!html.contains("color"));
+        assert!(!html.contains("text"));
+        assert!(!html.contains("senha"));
+        assert!(html.contains("<img src=\"x.png\">"));
+    }
+
+    #[test]
+    fn external_links() {
+        let html = render_markdown(
+            "[GitHub](https://github.com/fadiinho) \
+             [TabNews](https://www.tabnews.com.br/fadiinho) \
+             [perfil](/fadiinho) \
+             <a href=\"//example.com\" rel=\"author\" title='a \"b\" >c'>bruto</a>",
+        );
+
+        assert!(html.contains(
+            "<a href=\"https://github.com/fadiinho\" rel=\"nofollow noopener\">GitHub</a>"
+        ));
+        assert!(html.contains("<a href=\"https://www.tabnews.com.br/fadiinho\">TabNews</a>"));
+        assert!(html.contains("<a href=\"/fadiinho\">perfil</a>"));
+        assert!(html.contains(
+            "<a href=\"//example.com\" title=\"a &quot;b&quot; &gt;c\" rel=\"nofollow noopener\">bruto</a>"
+        ));
+        assert!(!html.contains("author"));
+    }
+
+    #[tokio::test]
+    async fn render_post_and_comments() {
+        let server = FakeServer::start();
+        let client = TabnewsClient::default();
+        client.http_client.set_host(server.url());
+
+        let post = client
+            .posts_api
+            .get_post_details("fadiinho", "tabnews-rs")
+            .await
+            .unwrap();
+
+        assert!(post
+            .render_html()
+            .contains("<a href=\"https://www.tabnews.com.br\">TabNews</a>"));
+
+        let comments = client
+            .posts_api
+            .get_post_comments("GabrielSozinho", "documentacao-da-api-do-tabnews")
+            .await
+            .unwrap();
+
+        assert!(comments
+            .iter()
+            .all(|comment| comment.render_html().starts_with("<p>")));
+        assert_eq!(Content::default().render_html(), "");
+    }
+}
@@ -0,0 +1,597 @@
+#[cfg(test)]
+mod http_client_tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
+
+    use reqwest::header::{HeaderValue, CACHE_CONTROL, COOKIE, ETAG, IF_NONE_MATCH, RETRY_AFTER};
+    use reqwest::{Method, StatusCode};
+    use serde_json::json;
+    use tabnews::internal::http_client::cache::{CacheEntry, CacheStore, DiskCache, HttpCache};
+    use tabnews::internal::http_client::middleware::{Middleware, Next};
+    use tabnews::internal::http_client::rate_limit::RateLimiter;
+    use tabnews::internal::http_client::retry::{RetryEvent, RetryPolicy, RetryReason};
+    use tabnews::internal::http_client::transport::BoxFuture;
+    use tabnews::internal::http_client::{
+        mock::MockTransport, HttpClient, HttpRequest, HttpResponse,
+    };
+    use tabnews::Error;
+
+    fn mocked_client(policy: RetryPolicy) -> (HttpClient, Arc<MockTransport>) {
+        let transport = Arc::new(MockTransport::default());
+        let client =
+            HttpClient::with_transport("http://tabnews.test/api/v1".to_owned(), transport.clone());
+        client.set_retry_policy(policy.base_delay(Duration::from_millis(1)));
+
+        (client, transport)
+    }
+
+    fn recorded_events(policy: RetryPolicy) -> (RetryPolicy, Arc<Mutex<Vec<RetryEvent>>>) {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let recorder = Arc::clone(&events);
+
+        let policy = policy.on_retry(move |event| recorder.lock().unwrap().push(event.clone()));
+
+        (policy, events)
+    }
+
+    #[tokio::test]
+    async fn get_is_retried_on_bad_gateway() {
+        let (policy, events) = recorded_events(RetryPolicy::default());
+        let (client, transport) = mocked_client(policy);
+        transport.push_response(HttpResponse::new(StatusCode::BAD_GATEWAY, Vec::new()));
+        transport.push_json(StatusCode::OK, &json!([])).unwrap();
+
+        let response = client.get("/contents".to_owned()).await.unwrap();
+
+        let events = events.lock().unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(transport.requests().len(), 2);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].attempt, 1);
+        assert_eq!(events[0].method, Method::GET);
+        assert_eq!(
+            events[0].reason,
+            RetryReason::Status(StatusCode::BAD_GATEWAY)
+        )
+    }
+
+    #[tokio::test]
+    async fn get_gives_up_after_max_attempts() {
+        let (client, transport) = mocked_client(RetryPolicy::default().max_attempts(2));
+        transport.push_response(HttpResponse::new(
+            StatusCode::SERVICE_UNAVAILABLE,
+            Vec::new(),
+        ));
+        transport.push_response(HttpResponse::new(
+            StatusCode::SERVICE_UNAVAILABLE,
+            Vec::new(),
+        ));
+        transport.push_json(StatusCode::OK, &json!([])).unwrap();
+
+        let response = client.get("/contents".to_owned()).await;
+
+        assert!(matches!(response, Err(Error::Http { status: 503, .. })));
+        assert_eq!(transport.pending(), 1)
+    }
+
+    #[tokio::test]
+    async fn get_is_not_retried_on_not_found() {
+        let (client, transport) = mocked_client(RetryPolicy::default());
+        transport.push_response(HttpResponse::new(StatusCode::NOT_FOUND, Vec::new()));
+        transport.push_json(StatusCode::OK, &json!([])).unwrap();
+
+        let response = client.get("/contents".to_owned()).await;
+
+        assert!(matches!(response, Err(Error::Http { status: 404, .. })));
+        assert_eq!(transport.requests().len(), 1)
+    }
+
+    #[tokio::test]
+    async fn get_is_retried_on_transport_error() {
+        let (client, transport) = mocked_client(RetryPolicy::default());
+        transport.push_error(Error::Timeout);
+        transport.push_json(StatusCode::OK, &json!([])).unwrap();
+
+        let response = client.get("/contents".to_owned()).await;
+
+        assert!(response.is_ok());
+        assert_eq!(transport.requests().len(), 2)
+    }
+
+    #[tokio::test]
+    async fn post_is_not_retried_by_default() {
+        let (client, transport) = mocked_client(RetryPolicy::default());
+        transport.push_response(HttpResponse::new(StatusCode::BAD_GATEWAY, Vec::new()));
+        transport
+            .push_json(StatusCode::CREATED, &json!({}))
+            .unwrap();
+
+        let response = client.post("/contents".to_owned(), json!({})).await;
+
+        assert!(matches!(response, Err(Error::Http { status: 502, .. })));
+        assert_eq!(transport.requests().len(), 1)
+    }
+
+    #[tokio::test]
+    async fn post_is_retried_when_opted_in() {
+        let (client, transport) = mocked_client(RetryPolicy::default().retry_non_idempotent(true));
+        transport.push_response(HttpResponse::new(StatusCode::BAD_GATEWAY, Vec::new()));
+        transport
+            .push_json(StatusCode::CREATED, &json!({}))
+            .unwrap();
+
+        let response = client.post("/contents".to_owned(), json!({})).await;
+
+        assert!(response.is_ok());
+        assert_eq!(transport.requests().len(), 2)
+    }
+
+    #[tokio::test]
+    async fn retry_after_is_honored() {
+        let (policy, events) = recorded_events(RetryPolicy::default());
+        let (client, transport) = mocked_client(policy);
+
+        let mut too_many_requests = HttpResponse::new(StatusCode::TOO_MANY_REQUESTS, Vec::new());
+        too_many_requests
+            .headers
+            .insert(RETRY_AFTER, HeaderValue::from_static("1"));
+        transport.push_response(too_many_requests);
+        transport.push_json(StatusCode::OK, &json!([])).unwrap();
+
+        client.get("/contents".to_owned()).await.unwrap();
+
+        let delay = events.lock().unwrap()[0].delay;
+        assert_eq!(delay, Duration::from_secs(1))
+    }
+
+    #[tokio::test]
+    async fn retry_after_is_clamped_to_max_delay() {
+        let (policy, events) =
+            recorded_events(RetryPolicy::default().max_delay(Duration::from_millis(10)));
+        let (client, transport) = mocked_client(policy);
+
+        let mut too_many_requests = HttpResponse::new(StatusCode::TOO_MANY_REQUESTS, Vec::new());
+        too_many_requests
+            .headers
+            .insert(RETRY_AFTER, HeaderValue::from_static("86400"));
+        transport.push_response(too_many_requests);
+        transport.push_json(StatusCode::OK, &json!([])).unwrap();
+
+        let start = Instant::now();
+        client.get("/contents".to_owned()).await.unwrap();
+
+        let delay = events.lock().unwrap()[0].delay;
+        assert_eq!(delay, Duration::from_millis(10));
+        assert!(start.elapsed() < Duration::from_secs(5))
+    }
+
+    #[tokio::test]
+    async fn no_retry_policy() {
+        let (client, transport) = mocked_client(RetryPolicy::none());
+        transport.push_response(HttpResponse::new(StatusCode::BAD_GATEWAY, Vec::new()));
+
+        let response = client.get("/contents".to_owned()).await;
+
+        assert!(matches!(response, Err(Error::Http { status: 502, .. })));
+        assert_eq!(transport.requests().len(), 1)
+    }
+
+    #[tokio::test]
+    async fn rate_limiter_delays_requests() {
+        let (client, transport) = mocked_client(RetryPolicy::none());
+        client.set_rate_limiter(RateLimiter::new(1, Duration::from_millis(100)));
+
+        for _ in 0..4 {
+            transport.push_json(StatusCode::OK, &json!([])).unwrap();
+        }
+
+        let start = Instant::now();
+
+        for _ in 0..4 {
+            client.get("/contents".to_owned()).await.unwrap();
+        }
+
+        assert!(start.elapsed() >= Duration::from_millis(300))
+    }
+
+    #[tokio::test]
+    async fn rate_limiter_only_limits_matching_routes() {
+        let (client, transport) = mocked_client(RetryPolicy::none());
+        client.set_rate_limiter(RateLimiter::unlimited().route(
+            "/analytics",
+            1,
+            Duration::from_secs(60),
+        ));
+
+        for _ in 0..4 {
+            transport.push_json(StatusCode::OK, &json!([])).unwrap();
+        }
+
+        let start = Instant::now();
+
+        client
+            .get("/analytics/users-created".to_owned())
+            .await
+            .unwrap();
+        for _ in 0..3 {
+            client.get("/contents".to_owned()).await.unwrap();
+        }
+
+        assert!(start.elapsed() < Duration::from_secs(1))
+    }
+
+    #[tokio::test]
+    async fn rate_limiter_queues_concurrent_tasks() {
+        let (client, transport) = mocked_client(RetryPolicy::none());
+        client.set_rate_limiter(RateLimiter::new(2, Duration::from_millis(100)));
+        let client = Arc::new(client);
+
+        for _ in 0..6 {
+            transport.push_json(StatusCode::OK, &json!([])).unwrap();
+        }
+
+        let start = Instant::now();
+
+        let handles: Vec<_> = (0..6)
+            .map(|_| {
+                let client = Arc::clone(&client);
+
+                tokio::spawn(async move { client.get("/contents".to_owned()).await })
+            })
+            .collect();
+
+        for handle in handles {
+            assert!(handle.await.unwrap().is_ok());
+        }
+
+        assert!(start.elapsed() >= Duration::from_millis(200))
+    }
+
+    fn cacheable(cache_control: &'static str, body: serde_json::Value) -> HttpResponse {
+        let mut response = HttpResponse::from_json(StatusCode::OK, &body).unwrap();
+        response
+            .headers
+            .insert(CACHE_CONTROL, HeaderValue::from_static(cache_control));
+
+        response
+    }
+
+    #[tokio::test]
+    async fn cache_serves_fresh_responses() {
+        let (client, transport) = mocked_client(RetryPolicy::none());
+        client.set_cache(HttpCache::memory());
+        transport.push_response(cacheable("max-age=60", json!(["cached"])));
+
+        let first = client.get("/contents".to_owned()).await.unwrap();
+        let second = client.get("/contents".to_owned()).await.unwrap();
+
+        assert_eq!(transport.requests().len(), 1);
+        assert_eq!(first.text(), second.text())
+    }
+
+    #[tokio::test]
+    async fn cache_revalidates_with_etag() {
+        let (client, transport) = mocked_client(RetryPolicy::none());
+        client.set_cache(HttpCache::memory());
+
+        let mut response = cacheable("no-cache", json!(["cached"]));
+        response
+            .headers
+            .insert(ETAG, HeaderValue::from_static("\"v1\""));
+        transport.push_response(response);
+        transport.push_response(HttpResponse::new(StatusCode::NOT_MODIFIED, Vec::new()));
+
+        client.get("/contents".to_owned()).await.unwrap();
+        let revalidated = client.get("/contents".to_owned()).await.unwrap();
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].headers[IF_NONE_MATCH], "\"v1\"");
+        assert_eq!(revalidated.status(), StatusCode::OK);
+        assert_eq!(revalidated.text(), "[\"cached\"]")
+    }
+
+    #[tokio::test]
+    async fn cache_skips_no_store() {
+        let (client, transport) = mocked_client(RetryPolicy::none());
+        client.set_cache(HttpCache::memory());
+        transport.push_response(cacheable("no-store, max-age=60", json!([])));
+        transport.push_response(cacheable("no-store, max-age=60", json!([])));
+
+        client.get("/contents".to_owned()).await.unwrap();
+        client.get("/contents".to_owned()).await.unwrap();
+
+        assert_eq!(transport.requests().len(), 2)
+    }
+
+    #[tokio::test]
+    async fn cache_is_keyed_by_session() {
+        let (client, transport) = mocked_client(RetryPolicy::none());
+        client.set_cache(HttpCache::memory());
+        transport.push_response(cacheable(
+            "private, max-age=60",
+            json!({ "username": "anonymous" }),
+        ));
+        transport.push_response(cacheable(
+            "private, max-age=60",
+            json!({ "username": "fadiinho" }),
+        ));
+
+        client.get("/user".to_owned()).await.unwrap();
+        client.add_header(COOKIE, HeaderValue::from_static("session_id=token"));
+        let logged = client.get("/user".to_owned()).await.unwrap();
+
+        assert_eq!(transport.requests().len(), 2);
+        assert_eq!(logged.text(), "{\"username\":\"fadiinho\"}")
+    }
+
+    #[tokio::test]
+    async fn cache_is_invalidated_by_writes() {
+        let (client, transport) = mocked_client(RetryPolicy::none());
+        client.set_cache(HttpCache::memory());
+        transport.push_response(cacheable("max-age=60", json!({ "tabcoins": 1 })));
+        transport.push_response(cacheable("max-age=60", json!({ "tabcoins": 1 })));
+        transport
+            .push_json(StatusCode::CREATED, &json!({ "tabcoins": 2 }))
+            .unwrap();
+        transport.push_response(cacheable("max-age=60", json!({ "tabcoins": 2 })));
+
+        client
+            .get("/contents/fadiinho/tabnews-rs".to_owned())
+            .await
+            .unwrap();
+        client
+            .get("/analytics/users-created".to_owned())
+            .await
+            .unwrap();
+        client
+            .post(
+                "/contents/fadiinho/tabnews-rs/tabcoins".to_owned(),
+                json!({ "transaction_type": "credit" }),
+            )
+            .await
+            .unwrap();
+        let refreshed = client
+            .get("/contents/fadiinho/tabnews-rs".to_owned())
+            .await
+            .unwrap();
+        client
+            .get("/analytics/users-created".to_owned())
+            .await
+            .unwrap();
+
+        assert_eq!(transport.requests().len(), 4);
+        assert_eq!(refreshed.text(), "{\"tabcoins\":2}")
+    }
+
+    #[tokio::test]
+    async fn disk_cache_persists_entries() {
+        let directory = std::env::temp_dir().join(format!("tabnews-cache-{}", std::process::id()));
+
+        let (client, transport) = mocked_client(RetryPolicy::none());
+        client.set_cache(HttpCache::disk(&directory).unwrap());
+        transport.push_response(cacheable("max-age=60", json!(["cached"])));
+        client.get("/contents".to_owned()).await.unwrap();
+
+        let (other_client, other_transport) = mocked_client(RetryPolicy::none());
+        other_client.set_cache(HttpCache::disk(&directory).unwrap());
+        let response = other_client.get("/contents".to_owned()).await.unwrap();
+
+        std::fs::remove_dir_all(&directory).ok();
+
+        assert!(other_transport.requests().is_empty());
+        assert_eq!(response.text(), "[\"cached\"]")
+    }
+
+    #[test]
+    fn disk_cache_checks_the_key_on_read() {
+        let root = std::env::temp_dir().join(format!("tabnews-collision-{}", std::process::id()));
+        let (first, second) = (root.join("first"), root.join("second"));
+
+        let entry = |body: &str| CacheEntry {
+            path: "/contents".to_owned(),
+            status: 200,
+            headers: Vec::new(),
+            body: body.as_bytes().to_vec(),
+            stored_at: 0,
+            max_age: Some(60),
+            no_cache: false,
+            etag: None,
+            last_modified: None,
+        };
+
+        DiskCache::new(&first)
+            .unwrap()
+            .put("/contents?page=1", entry("first"));
+        let cache = DiskCache::new(&second).unwrap();
+        cache.put("/contents?page=2", entry("second"));
+        assert_eq!(cache.get("/contents?page=2").unwrap().body, b"second");
+
+        // Simulates a hash collision: the files of the other key end up
+        // under the name of this one
+        for file in std::fs::read_dir(&second).unwrap().flatten() {
+            let name = file.file_name();
+            let extension = file.path().extension().unwrap().to_owned();
+            let source = std::fs::read_dir(&first)
+                .unwrap()
+                .flatten()
+                .find(|source| source.path().extension() == Some(extension.as_os_str()))
+                .unwrap();
+
+            std::fs::copy(source.path(), second.join(name)).unwrap();
+        }
+
+        let collided = cache.get("/contents?page=2");
+
+        std::fs::remove_dir_all(&root).ok();
+
+        assert!(collided.is_none());
+    }
+
+    /// Appends its name to the `x-order` header of requests and responses
+    struct Tag(&'static str);
+
+    impl Middleware for Tag {
+        fn handle<'a>(
+            &'a self,
+            mut request: HttpRequest,
+            next: Next<'a>,
+        ) -> BoxFuture<'a, tabnews::Result<HttpResponse>> {
+            request
+                .headers
+                .append("x-order", HeaderValue::from_static(self.0));
+
+            Box::pin(async move {
+                let mut response = next.run(request).await?;
+                response
+                    .headers
+                    .append("x-order", HeaderValue::from_static(self.0));
+
+                Ok(response)
+            })
+        }
+    }
+
+    /// Answers every request without reaching the transport
+    struct Stub(StatusCode);
+
+    impl Middleware for Stub {
+        fn handle<'a>(
+            &'a self,
+            _request: HttpRequest,
+            _next: Next<'a>,
+        ) -> BoxFuture<'a, tabnews::Result<HttpResponse>> {
+            let status = self.0;
+
+            Box::pin(async move { HttpResponse::from_json(status, &json!({ "stub": true })) })
+        }
+    }
+
+    struct RewriteHost(&'static str);
+
+    impl Middleware for RewriteHost {
+        fn handle<'a>(
+            &'a self,
+            mut request: HttpRequest,
+            next: Next<'a>,
+        ) -> BoxFuture<'a, tabnews::Result<HttpResponse>> {
+            request.url = request.url.replace("http://tabnews.test", self.0);
+
+            next.run(request)
+        }
+    }
+
+    #[derive(Default)]
+    struct Counter(AtomicUsize);
+
+    impl Middleware for Counter {
+        fn handle<'a>(
+            &'a self,
+            request: HttpRequest,
+            next: Next<'a>,
+        ) -> BoxFuture<'a, tabnews::Result<HttpResponse>> {
+            self.0.fetch_add(1, Ordering::SeqCst);
+
+            next.run(request)
+        }
+    }
+
+    #[tokio::test]
+    async fn middlewares_run_in_order() {
+        let (client, transport) = mocked_client(RetryPolicy::none());
+        transport.push_json(StatusCode::OK, &json!([])).unwrap();
+        client.add_middleware(Tag("first"));
+        client.add_middleware(Tag("second"));
+
+        let response = client.get("/contents".to_owned()).await.unwrap();
+
+        let request_order: Vec<_> = transport.requests()[0]
+            .headers
+            .get_all("x-order")
+            .iter()
+            .map(|value| value.to_str().unwrap().to_owned())
+            .collect();
+        let response_order: Vec<_> = response
+            .headers
+            .get_all("x-order")
+            .iter()
+            .map(|value| value.to_str().unwrap().to_owned())
+            .collect();
+
+        assert_eq!(request_order, ["first", "second"]);
+        assert_eq!(response_order, ["second", "first"]);
+    }
+
+    #[tokio::test]
+    async fn middleware_short_circuits_every_method() {
+        let (client, transport) = mocked_client(RetryPolicy::none());
+        client.add_middleware(Stub(StatusCode::OK));
+
+        let responses = [
+            client.get("/contents".to_owned()).await.unwrap(),
+            client
+                .get_with_params("/contents".to_owned(), Some(&[("page", 2)]))
+                .await
+                .unwrap(),
+            client
+                .post("/contents".to_owned(), json!({}))
+                .await
+                .unwrap(),
+            client
+                .patch("/contents/user/slug".to_owned(), json!({}))
+                .await
+                .unwrap(),
+        ];
+
+        assert!(responses
+            .iter()
+            .all(|response| response.text() == r#"{"stub":true}"#));
+        assert!(transport.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn synthetic_error_responses_are_errors() {
+        let (client, _transport) = mocked_client(RetryPolicy::none());
+        client.add_middleware(Stub(StatusCode::FORBIDDEN));
+
+        let response = client.get("/contents".to_owned()).await;
+
+        assert!(matches!(response, Err(Error::Http { status: 403, .. })))
+    }
+
+    #[tokio::test]
+    async fn middleware_rewrites_host() {
+        let (client, transport) = mocked_client(RetryPolicy::none());
+        transport.push_json(StatusCode::OK, &json!([])).unwrap();
+        client.add_middleware(RewriteHost("http://localhost:3000"));
+
+        client.get("/contents".to_owned()).await.unwrap();
+
+        assert_eq!(
+            transport.requests()[0].url,
+            "http://localhost:3000/api/v1/contents"
+        );
+    }
+
+    #[tokio::test]
+    async fn middleware_sees_each_call_once() {
+        let (client, transport) = mocked_client(RetryPolicy::default());
+        transport.push_response(HttpResponse::new(StatusCode::BAD_GATEWAY, Vec::new()));
+        transport.push_json(StatusCode::OK, &json!([])).unwrap();
+
+        let counter = Arc::new(Counter::default());
+        client.add_middleware(Arc::clone(&counter));
+
+        client.get("/contents".to_owned()).await.unwrap();
+
+        assert_eq!(counter.0.load(Ordering::SeqCst), 1);
+        assert_eq!(transport.requests().len(), 2);
+
+        client.clear_middlewares();
+        transport.push_json(StatusCode::OK, &json!([])).unwrap();
+        client.get("/contents".to_owned()).await.unwrap();
+
+        assert_eq!(counter.0.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,419 @@
+#[cfg(test)]
+mod pagination_tests {
+    use std::collections::HashSet;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use futures::{StreamExt, TryStreamExt};
+    use reqwest::header::{HeaderValue, LINK};
+    use reqwest::StatusCode;
+    use serde_json::json;
+    use tabnews::internal::http_client::middleware::{Middleware, Next};
+    use tabnews::internal::http_client::transport::BoxFuture;
+    use tabnews::internal::http_client::{mock::MockTransport, HttpRequest, HttpResponse};
+    use tabnews::internal::pagination::Page;
+    use tabnews::models::content::{Content, ContentParams};
+    use tabnews::testing::{FakeServer, SEEDED_PASSWORD};
+    use tabnews::{Error, TabnewsClient};
+
+    /// Counts the requests sent by the client
+    #[derive(Default)]
+    struct Requests(AtomicUsize);
+
+    impl Middleware for Requests {
+        fn handle<'a>(
+            &'a self,
+            request: HttpRequest,
+            next: Next<'a>,
+        ) -> BoxFuture<'a, tabnews::Result<HttpResponse>> {
+            self.0.fetch_add(1, Ordering::SeqCst);
+
+            next.run(request)
+        }
+    }
+
+    /// Points the `next` link of every response back to the first page
+    struct LinkBack;
+
+    impl Middleware for LinkBack {
+        fn handle<'a>(
+            &'a self,
+            request: HttpRequest,
+            next: Next<'a>,
+        ) -> BoxFuture<'a, tabnews::Result<HttpResponse>> {
+            Box::pin(async move {
+                let mut response = next.run(request).await?;
+                response.headers.insert(
+                    LINK,
+                    HeaderValue::from_static(
+                        "<http://tabnews.test/api/v1/contents?page=1&per_page=5>; rel=\"next\"",
+                    ),
+                );
+
+                Ok(response)
+            })
+        }
+    }
+
+    /// Server whose homepage has `extra` posts besides the seeded ones
+    fn fake_client(extra: usize) -> (TabnewsClient, FakeServer, Arc<Requests>) {
+        let server = FakeServer::start();
+
+        {
+            let mut state = server.state();
+            let owner = state.user_by_username("fadiinho").unwrap().id.clone();
+
+            for index in 0..extra {
+                let slug = format!("post-{}", index);
+                state.add_content(&owner, None, Some(&slug), Some(&slug), "corpo");
+            }
+        }
+
+        let requests = Arc::new(Requests::default());
+        let client = TabnewsClient::default();
+        client.http_client.set_host(server.url());
+        client.http_client.add_middleware(Arc::clone(&requests));
+
+        (client, server, requests)
+    }
+
+    fn requests(requests: &Requests) -> usize {
+        requests.0.load(Ordering::SeqCst)
+    }
+
+    #[tokio::test]
+    async fn streams_every_page() {
+        let (client, _server, counter) = fake_client(23);
+
+        let posts: Vec<Content> = client
+            .posts_api
+            .stream_homepage_posts(None)
+            .per_page(10)
+            .try_collect()
+            .await
+            .unwrap();
+
+        let slugs: HashSet<_> = posts.iter().map(|post| post.slug.clone()).collect();
+
+        // 2 seeded posts + 23 new ones, in 3 pages
+        assert_eq!(posts.len(), 25);
+        assert_eq!(slugs.len(), 25);
+        assert_eq!(requests(&counter), 3);
+    }
+
+    #[tokio::test]
+    async fn full_last_page_stops_with_links() {
+        let (client, _server, counter) = fake_client(8);
+
+        let posts: Vec<Content> = client
+            .posts_api
+            .stream_homepage_posts(None)
+            .per_page(5)
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(posts.len(), 10);
+        assert_eq!(requests(&counter), 2);
+    }
+
+    #[tokio::test]
+    async fn next_link_to_a_fetched_page_ends_the_stream() {
+        let (client, _server, counter) = fake_client(20);
+        client.http_client.add_middleware(Arc::new(LinkBack));
+
+        let posts: Vec<Content> = client
+            .posts_api
+            .stream_homepage_posts(None)
+            .per_page(5)
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(posts.len(), 5);
+        assert_eq!(requests(&counter), 1);
+
+        let params = ContentParams {
+            page: Some(3),
+            per_page: Some(5),
+            strategy: None,
+        };
+        let posts: Vec<Content> = client
+            .posts_api
+            .stream_homepage_posts(Some(params))
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(posts.len(), 5);
+        assert_eq!(requests(&counter), 2);
+    }
+
+    #[tokio::test]
+    async fn full_last_page_without_links_needs_one_more_request() {
+        let transport = Arc::new(MockTransport::default());
+        let posts = json!([{ "slug": "a" }, { "slug": "b" }]);
+        transport.push_json(StatusCode::OK, &posts).unwrap();
+        transport.push_json(StatusCode::OK, &json!([])).unwrap();
+
+        let client = TabnewsClient::builder()
+            .base_url("http://tabnews.test/api/v1")
+            .transport(transport.clone())
+            .build()
+            .unwrap();
+
+        let posts: Vec<Content> = client
+            .posts_api
+            .stream_homepage_posts(None)
+            .per_page(2)
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(posts.len(), 2);
+        assert_eq!(transport.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn take_only_fetches_needed_pages() {
+        let (client, _server, counter) = fake_client(40);
+
+        let posts: Vec<Content> = client
+            .posts_api
+            .stream_homepage_posts(None)
+            .per_page(10)
+            .take(15)
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(posts.len(), 15);
+        assert_eq!(requests(&counter), 2);
+    }
+
+    #[tokio::test]
+    async fn limit_stops_the_stream() {
+        let (client, _server, counter) = fake_client(40);
+
+        let posts: Vec<Content> = client
+            .posts_api
+            .stream_homepage_posts(None)
+            .per_page(10)
+            .limit(10)
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(posts.len(), 10);
+        assert_eq!(requests(&counter), 1);
+    }
+
+    #[tokio::test]
+    async fn params_set_strategy_and_first_page() {
+        let (client, _server, _counter) = fake_client(10);
+
+        let params = ContentParams {
+            page: Some(2),
+            per_page: Some(5),
+            strategy: Some("old".to_owned()),
+        };
+
+        let streamed: Vec<Content> = client
+            .posts_api
+            .stream_homepage_posts(Some(params.clone()))
+            .try_collect()
+            .await
+            .unwrap();
+        let second_page = client
+            .posts_api
+            .get_homepage_posts(Some(params))
+            .await
+            .unwrap();
+
+        assert_eq!(streamed.len(), 7);
+        assert_eq!(streamed[0].slug, second_page[0].slug);
+    }
+
+    #[tokio::test]
+    async fn prefetch_fetches_next_page_early() {
+        let (client, _server, counter) = fake_client(40);
+
+        let mut posts = client
+            .posts_api
+            .stream_homepage_posts(None)
+            .per_page(10)
+            .prefetch(true);
+
+        posts.next().await.unwrap().unwrap();
+        tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+
+        assert_eq!(requests(&counter), 2);
+
+        let rest: Vec<Content> = posts.try_collect().await.unwrap();
+
+        assert_eq!(rest.len(), 41);
+        assert_eq!(requests(&counter), 5);
+    }
+
+    #[tokio::test]
+    async fn stream_posts_by_user() {
+        let (client, _server, _counter) = fake_client(12);
+
+        let posts: Vec<Content> = client
+            .posts_api
+            .stream_posts_by_user("fadiinho", None)
+            .per_page(5)
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert!(posts
+            .iter()
+            .all(|post| post.owner_username.as_deref() == Some("fadiinho")));
+        // 12 new posts, plus the seeded post and comment
+        assert_eq!(posts.len(), 14);
+    }
+
+    #[tokio::test]
+    async fn invalid_per_page() {
+        let (client, _server, counter) = fake_client(0);
+
+        let posts: Vec<Result<Content, Error>> = client
+            .posts_api
+            .stream_homepage_posts(None)
+            .per_page(0)
+            .collect()
+            .await;
+
+        assert!(matches!(posts.as_slice(), [Err(Error::InvalidInput(_))]));
+        assert_eq!(requests(&counter), 0);
+    }
+
+    #[tokio::test]
+    async fn errors_end_the_stream() {
+        let (client, _server, _counter) = fake_client(0);
+
+        let posts: Vec<Result<Content, Error>> = client
+            .posts_api
+            .stream_posts_by_user("missing", None)
+            .collect()
+            .await;
+
+        assert!(matches!(
+            posts.as_slice(),
+            [Err(Error::Http { status: 404, .. })]
+        ));
+    }
+
+    #[tokio::test]
+    async fn stream_all_users() {
+        let (client, server, _counter) = fake_client(0);
+
+        {
+            let mut state = server.state();
+            state.grant_feature("fadiinho", "read:user:list");
+
+            for index in 0..6 {
+                state.add_user(
+                    &format!("user{}", index),
+                    &format!("user{}@tabnews.test", index),
+                    SEEDED_PASSWORD,
+                );
+            }
+        }
+
+        client
+            .auth_api
+            .login(
+                "fadiinho@tabnews.test".to_owned(),
+                SEEDED_PASSWORD.to_owned(),
+            )
+            .await
+            .unwrap();
+
+        let users: Vec<_> = client
+            .users_api
+            .stream_all_users()
+            .per_page(3)
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(users.len(), 8);
+    }
+
+    #[tokio::test]
+    async fn page_metadata() {
+        let (client, _server, _counter) = fake_client(23);
+
+        let params = ContentParams {
+            page: Some(2),
+            per_page: Some(10),
+            strategy: Some("new".to_owned()),
+        };
+
+        let page = client
+            .posts_api
+            .get_homepage_posts(Some(params))
+            .await
+            .unwrap();
+
+        assert_eq!(page.len(), 10);
+        assert_eq!(page.page, 2);
+        assert_eq!(page.per_page, 10);
+        assert_eq!(page.total_rows, Some(25));
+        assert_eq!(page.total_pages(), Some(3));
+        assert_eq!(page.first_page, Some(1));
+        assert_eq!(page.prev_page, Some(1));
+        assert_eq!(page.next_page, Some(3));
+        assert_eq!(page.last_page, Some(3));
+        assert!(page.request_id.is_some());
+    }
+
+    #[tokio::test]
+    async fn last_page_has_no_next() {
+        let (client, _server, _counter) = fake_client(0);
+
+        let page = client
+            .posts_api
+            .get_posts_by_user("fadiinho", None)
+            .await
+            .unwrap();
+
+        assert!(!page.has_next());
+        assert!(!page.has_prev());
+        assert_eq!(page.total_rows, Some(2));
+        assert_eq!(page.into_items().len(), 2);
+    }
+
+    #[test]
+    fn page_from_tabnews_headers() {
+        let mut response =
+            HttpResponse::from_json(StatusCode::OK, &json!([{ "slug": "a" }])).unwrap();
+        response.headers.insert(
+            LINK,
+            HeaderValue::from_static(
+                "<https://www.tabnews.com.br/api/v1/contents?strategy=relevant&page=1&per_page=1>; rel=\"first\", \
+                 <https://www.tabnews.com.br/api/v1/contents?strategy=relevant&page=4&per_page=1>; rel=\"prev\", \
+                 <https://www.tabnews.com.br/api/v1/contents?strategy=relevant&page=6&per_page=1>; rel=\"next\", \
+                 <https://www.tabnews.com.br/api/v1/contents?strategy=relevant&page=283&per_page=1>; rel=\"last\"",
+            ),
+        );
+        response
+            .headers
+            .insert("x-pagination-total-rows", HeaderValue::from_static("283"));
+        response
+            .headers
+            .insert("x-request-id", HeaderValue::from_static("request-id"));
+
+        let page: Page<Content> = Page::from_response(&response, 5, 1).unwrap();
+
+        assert_eq!(page.first_page, Some(1));
+        assert_eq!(page.prev_page, Some(4));
+        assert_eq!(page.next_page, Some(6));
+        assert_eq!(page.last_page, Some(283));
+        assert_eq!(page.total_rows, Some(283));
+        assert_eq!(page.request_id.as_deref(), Some("request-id"));
+        assert_eq!(page[0].slug.as_deref(), Some("a"));
+    }
+}
@@ -1,11 +1,36 @@
 #[cfg(test)]
 mod posts_tests {
+    use std::sync::Arc;
+
+    use reqwest::{Method, StatusCode};
+    use serde_json::json;
+    use tabnews::internal::http_client::{mock::MockTransport, HttpClient};
     use tabnews::internal::posts::PostsApi;
-    use tabnews::models::content::ContentParams;
+    use tabnews::models::content::{
+        Content, ContentParams, ContentPatch, ContentStatus, ListingParams, Strategy,
+    };
+    use tabnews::testing::{FakeServer, SEEDED_PASSWORD};
+    use tabnews::{Error, TabnewsClient};
+
+    fn fake_posts_api() -> (PostsApi, FakeServer) {
+        let server = FakeServer::start();
+        let client = Arc::new(HttpClient::default());
+        client.set_host(server.url());
+
+        (PostsApi::new(client), server)
+    }
+
+    fn mocked_posts_api() -> (PostsApi, Arc<MockTransport>) {
+        let transport = Arc::new(MockTransport::default());
+        let client =
+            HttpClient::with_transport("http://tabnews.test/api/v1".to_owned(), transport.clone());
+
+        (PostsApi::new(Arc::new(client)), transport)
+    }
 
     #[tokio::test]
     async fn get_homepage_posts() {
-        let posts_api = PostsApi::default();
+        let (posts_api, _server) = fake_posts_api();
 
         let response = posts_api.get_homepage_posts(None).await.unwrap();
 
@@ -20,16 +45,242 @@ mod posts_tests {
             page: Some(1),
         };
 
-        let posts_api = PostsApi::default();
+        let (posts_api, _server) = fake_posts_api();
 
         let response = posts_api.get_homepage_posts(Some(params)).await.unwrap();
 
         assert!(!response.is_empty())
     }
 
+    #[tokio::test]
+    async fn get_homepage_posts_unreachable_host() {
+        let client = HttpClient::new("http://127.0.0.1:1/api/v1".to_owned());
+        let posts_api = PostsApi::new(Arc::new(client));
+
+        let response = posts_api.get_homepage_posts(None).await;
+
+        assert!(matches!(response, Err(Error::Transport(_))))
+    }
+
+    #[tokio::test]
+    async fn get_homepage_posts_sends_params() {
+        let (posts_api, transport) = mocked_posts_api();
+        transport
+            .push_json(StatusCode::OK, &json!([{ "slug": "first-post" }]))
+            .unwrap();
+
+        let params = ContentParams {
+            per_page: Some(1),
+            strategy: Some("old".to_string()),
+            page: Some(2),
+        };
+
+        let response = posts_api.get_homepage_posts(Some(params)).await.unwrap();
+
+        let request = &transport.requests()[0];
+        assert_eq!(request.method, Method::GET);
+        assert_eq!(request.path(), "/api/v1/contents");
+        assert_eq!(request.query(), Some("page=2&per_page=1&strategy=old"));
+        assert_eq!(response[0].slug.as_deref(), Some("first-post"))
+    }
+
+    #[tokio::test]
+    async fn get_homepage_posts_sends_filters() {
+        let (posts_api, transport) = mocked_posts_api();
+        transport.push_json(StatusCode::OK, &json!([])).unwrap();
+
+        let params = ListingParams::from(Strategy::New)
+            .with_root(false)
+            .with_children(true);
+
+        posts_api.get_homepage_posts(params).await.unwrap();
+
+        let request = &transport.requests()[0];
+        assert_eq!(
+            request.query(),
+            Some("strategy=new&with_root=false&with_children=true")
+        );
+    }
+
+    #[tokio::test]
+    async fn get_homepage_posts_rejects_invalid_params() {
+        let (posts_api, transport) = mocked_posts_api();
+
+        for params in [
+            ContentParams {
+                page: Some(0),
+                ..Default::default()
+            },
+            ContentParams {
+                per_page: Some(101),
+                ..Default::default()
+            },
+            ContentParams {
+                strategy: Some("olds".to_owned()),
+                ..Default::default()
+            },
+        ] {
+            let response = posts_api.get_homepage_posts(Some(params)).await;
+
+            assert!(matches!(response, Err(Error::InvalidInput(_))));
+        }
+
+        assert!(transport.requests().is_empty());
+    }
+
+    #[test]
+    fn content_params_builder_validates() {
+        let params = ContentParams::builder()
+            .page(3)
+            .per_page(100)
+            .strategy(Strategy::Relevant)
+            .build()
+            .unwrap();
+
+        assert_eq!(params.page, Some(3));
+        assert_eq!(params.per_page, Some(100));
+        assert_eq!(params.strategy.as_deref(), Some("relevant"));
+
+        assert!(ContentParams::builder().page(0).build().is_err());
+        assert!(ContentParams::builder().per_page(0).build().is_err());
+        assert!(ContentParams::builder().per_page(101).build().is_err());
+    }
+
+    #[test]
+    fn strategy_conversions() {
+        assert_eq!("old".parse::<Strategy>().unwrap(), Strategy::Old);
+        assert_eq!(Strategy::try_from("new").unwrap(), Strategy::New);
+        assert!(matches!(
+            "olds".parse::<Strategy>(),
+            Err(Error::InvalidInput(_))
+        ));
+        assert_eq!(Strategy::default().to_string(), "relevant");
+
+        let params = ContentParams::from(Strategy::Old);
+        assert_eq!(params.strategy.as_deref(), Some("old"));
+        assert_eq!(params.page, None);
+
+        let params = ListingParams::from(Some(params.clone()));
+        assert_eq!(params.params.strategy.as_deref(), Some("old"));
+        assert_eq!(params.with_root, None);
+        assert_eq!(ListingParams::from(None), ListingParams::default());
+    }
+
+    #[tokio::test]
+    async fn get_homepage_posts_filters_comments() {
+        let (posts_api, _server) = fake_posts_api();
+
+        let params = ListingParams::default()
+            .with_root(false)
+            .with_children(true);
+
+        let comments = posts_api.get_homepage_posts(params).await.unwrap();
+
+        assert_eq!(comments.len(), 2);
+        assert!(comments.iter().all(|c| c.parent_id.is_some()));
+    }
+
+    #[tokio::test]
+    async fn get_posts_by_user_without_children() {
+        let (posts_api, _server) = fake_posts_api();
+
+        let all = posts_api.get_posts_by_user("fadiinho", None).await.unwrap();
+        let posts = posts_api
+            .get_posts_by_user(
+                "fadiinho",
+                ListingParams {
+                    with_children: Some(false),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(all.len(), 2);
+        assert_eq!(posts.len(), 1);
+        assert_eq!(posts[0].slug.as_deref(), Some("tabnews-rs"));
+    }
+
+    #[tokio::test]
+    async fn get_post_details_not_found() {
+        let (posts_api, transport) = mocked_posts_api();
+        transport
+            .push_json(
+                StatusCode::NOT_FOUND,
+                &json!({
+                    "name": "NotFoundError",
+                    "message": "O conteúdo informado não foi encontrado no sistema.",
+                    "action": "Verifique se o \"slug\" está digitado corretamente.",
+                    "status_code": 404,
+                    "error_id": "error-id",
+                    "request_id": "request-id",
+                    "error_location_code": "CONTROLLER:CONTENT:GET_HANDLER:SLUG_NOT_FOUND"
+                }),
+            )
+            .unwrap();
+
+        let response = posts_api.get_post_details("feijao", "arroz").await;
+
+        match response {
+            Err(Error::Http {
+                status: 404,
+                error: Some(error),
+            }) => assert_eq!(error.name, "NotFoundError"),
+            other => panic!("unexpected response: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn get_post_details_invalid_body() {
+        let (posts_api, transport) = mocked_posts_api();
+        transport
+            .push_json(StatusCode::OK, &"<html></html>")
+            .unwrap();
+
+        let response = posts_api.get_post_details("feijao", "arroz").await;
+
+        match response {
+            Err(Error::Decode { body, .. }) => assert_eq!(body, "\"<html></html>\""),
+            other => panic!("unexpected response: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn publish_comment_without_parent() {
+        let (posts_api, transport) = mocked_posts_api();
+
+        let response = posts_api.publish_comment(Content::default()).await;
+
+        assert!(matches!(response, Err(Error::InvalidInput(_))));
+        assert!(transport.requests().is_empty())
+    }
+
+    #[tokio::test]
+    async fn publish_post_sends_content() {
+        let (posts_api, transport) = mocked_posts_api();
+        transport
+            .push_json(
+                StatusCode::CREATED,
+                &json!({ "id": "post-id", "title": "Cool Title" }),
+            )
+            .unwrap();
+
+        let mut post = Content::default();
+        post.set_title("Cool Title");
+
+        let response = posts_api.publish_post(post).await.unwrap();
+
+        let request = &transport.requests()[0];
+        let body: serde_json::Value =
+            serde_json::from_slice(request.body.as_ref().unwrap()).unwrap();
+        assert_eq!(request.method, Method::POST);
+        assert_eq!(body["title"], "Cool Title");
+        assert_eq!(response.id.as_deref(), Some("post-id"))
+    }
+
     #[tokio::test]
     async fn get_user_posts() {
-        let posts_api = PostsApi::default();
+        let (posts_api, _server) = fake_posts_api();
 
         let response = posts_api.get_posts_by_user("fadiinho", None).await.unwrap();
 
@@ -39,7 +290,7 @@ mod posts_tests {
     #[tokio::test]
     #[should_panic]
     async fn get_non_existent_user_posts() {
-        let posts_api = PostsApi::default();
+        let (posts_api, _server) = fake_posts_api();
 
         let response = posts_api
             .get_posts_by_user("non-existent-user", None)
@@ -51,7 +302,7 @@ mod posts_tests {
 
     #[tokio::test]
     async fn get_post_details() {
-        let posts_api = PostsApi::default();
+        let (posts_api, _server) = fake_posts_api();
         let slug = "documentacao-da-api-do-tabnews";
 
         let response = posts_api
@@ -65,7 +316,7 @@ mod posts_tests {
     #[tokio::test]
     #[should_panic]
     async fn get_non_existent_post_details() {
-        let posts_api = PostsApi::default();
+        let (posts_api, _server) = fake_posts_api();
         let slug = "arroz";
 
         let response = posts_api.get_post_details("feijao", slug).await.unwrap();
@@ -75,7 +326,7 @@ mod posts_tests {
 
     #[tokio::test]
     async fn get_post_comments() {
-        let posts_api = PostsApi::default();
+        let (posts_api, _server) = fake_posts_api();
 
         let response = posts_api
             .get_post_comments("GabrielSozinho", "documentacao-da-api-do-tabnews")
@@ -86,23 +337,21 @@ mod posts_tests {
     }
 
     #[tokio::test]
-    async fn get_post_thumbnail() -> Result<(), String> {
-        let posts_api = PostsApi::default();
+    async fn get_post_thumbnail() {
+        let (posts_api, _server) = fake_posts_api();
 
-        let response = posts_api
+        let thumbnail = posts_api
             .get_post_thumbnail("GabrielSozinho", "documentacao-da-api-do-tabnews")
             .await
             .unwrap();
 
-        match response.error_for_status() {
-            Ok(_) => Ok(()),
-            Err(_) => Err("Something went wrong!".to_owned()),
-        }
+        assert_eq!(thumbnail.content_type(), Some("image/png"));
+        assert!(thumbnail.bytes().starts_with(b"\x89PNG"));
     }
 
     #[tokio::test]
     async fn get_post_root() {
-        let posts_api = PostsApi::default();
+        let (posts_api, _server) = fake_posts_api();
 
         let response = posts_api
             .get_post_root("GabrielSozinho", "ae53bb7c-264f-4ad1-aeb0-340dde9b1647")
@@ -114,7 +363,7 @@ mod posts_tests {
 
     #[tokio::test]
     async fn get_post_parent() {
-        let posts_api = PostsApi::default();
+        let (posts_api, _server) = fake_posts_api();
 
         let response = posts_api
             .get_post_parent("GabrielSozinho", "ae53bb7c-264f-4ad1-aeb0-340dde9b1647")
@@ -124,9 +373,411 @@ mod posts_tests {
         assert!(response.id.is_some())
     }
 
+    #[tokio::test]
+    async fn publish_post_and_upvote() {
+        let server = FakeServer::start();
+        let client = TabnewsClient::default();
+        client.http_client.set_host(server.url());
+
+        client
+            .auth_api
+            .login(
+                "gabriel@tabnews.test".to_owned(),
+                SEEDED_PASSWORD.to_owned(),
+            )
+            .await
+            .unwrap();
+
+        let mut post = Content::default();
+        post.set_title("Cool Title").set_body("Cool text/markdown");
+
+        let published = client.posts_api.publish_post(post).await.unwrap();
+        let slug = published.slug.unwrap();
+
+        let details = client
+            .posts_api
+            .get_post_details("GabrielSozinho", &slug)
+            .await
+            .unwrap();
+        assert_eq!(details.id, published.id);
+
+        let upvote_own_post = client.posts_api.upvote("GabrielSozinho", &slug).await;
+        assert!(matches!(
+            upvote_own_post,
+            Err(Error::Http { status: 400, .. })
+        ));
+
+        let tabcoins = client
+            .posts_api
+            .upvote("fadiinho", "tabnews-rs")
+            .await
+            .unwrap();
+        assert_eq!(tabcoins.tabcoins, 1);
+        assert_eq!(
+            client
+                .posts_api
+                .get_post_tabcoins("fadiinho", "tabnews-rs")
+                .await
+                .unwrap(),
+            1
+        )
+    }
+
+    async fn logged_client(server: &FakeServer, email: &str) -> TabnewsClient {
+        let client = TabnewsClient::default();
+        client.http_client.set_host(server.url());
+
+        client
+            .auth_api
+            .login(email.to_owned(), SEEDED_PASSWORD.to_owned())
+            .await
+            .unwrap();
+
+        client
+    }
+
+    #[tokio::test]
+    async fn update_content() {
+        let server = FakeServer::start();
+        let client = logged_client(&server, "fadiinho@tabnews.test").await;
+
+        let patch = ContentPatch::default()
+            .title("tabnews-rs 2.0")
+            .body("Agora com edição de posts.")
+            .source_url("https://github.com/Fadiinho/tabnews-rs")
+            .slug("tabnews-rs-2");
+
+        let updated = client
+            .posts_api
+            .update_content("fadiinho", "tabnews-rs", patch)
+            .await
+            .unwrap();
+
+        assert_eq!(updated.title.as_deref(), Some("tabnews-rs 2.0"));
+        assert_eq!(updated.slug.as_deref(), Some("tabnews-rs-2"));
+        assert_ne!(updated.updated_at, updated.created_at);
+
+        let details = client
+            .posts_api
+            .get_post_details("fadiinho", "tabnews-rs-2")
+            .await
+            .unwrap();
+        assert_eq!(details.body.as_deref(), Some("Agora com edição de posts."));
+        assert_eq!(
+            details.source_url.as_deref(),
+            Some("https://github.com/Fadiinho/tabnews-rs")
+        );
+
+        let old_slug = client
+            .posts_api
+            .get_post_details("fadiinho", "tabnews-rs")
+            .await;
+        assert!(matches!(old_slug, Err(Error::Http { status: 404, .. })));
+    }
+
+    #[tokio::test]
+    async fn update_content_sends_only_set_fields() {
+        let (posts_api, transport) = mocked_posts_api();
+        transport
+            .push_json(StatusCode::OK, &json!({ "slug": "tabnews-rs" }))
+            .unwrap();
+
+        let patch = ContentPatch::default()
+            .body("Novo corpo")
+            .clear_source_url();
+
+        posts_api
+            .update_content("fadiinho", "tabnews-rs", patch)
+            .await
+            .unwrap();
+
+        let request = &transport.requests()[0];
+        let body: serde_json::Value =
+            serde_json::from_slice(request.body.as_ref().unwrap()).unwrap();
+
+        assert_eq!(request.method, Method::PATCH);
+        assert_eq!(request.path(), "/api/v1/contents/fadiinho/tabnews-rs");
+        assert_eq!(body, json!({ "body": "Novo corpo", "source_url": null }));
+    }
+
+    #[tokio::test]
+    async fn update_content_with_empty_patch() {
+        let (posts_api, transport) = mocked_posts_api();
+
+        let response = posts_api
+            .update_content("fadiinho", "tabnews-rs", ContentPatch::default())
+            .await;
+
+        assert!(matches!(response, Err(Error::InvalidInput(_))));
+        assert!(transport.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn update_content_of_another_user() {
+        let server = FakeServer::start();
+        let client = logged_client(&server, "gabriel@tabnews.test").await;
+
+        let response = client
+            .posts_api
+            .update_content(
+                "fadiinho",
+                "tabnews-rs",
+                ContentPatch::default().title("Roubado"),
+            )
+            .await;
+
+        assert!(matches!(response, Err(Error::Http { status: 403, .. })));
+    }
+
+    #[tokio::test]
+    async fn update_content_with_taken_slug() {
+        let server = FakeServer::start();
+        let client = logged_client(&server, "gabriel@tabnews.test").await;
+
+        let response = client
+            .posts_api
+            .update_content(
+                "GabrielSozinho",
+                "ae53bb7c-264f-4ad1-aeb0-340dde9b1647",
+                ContentPatch::default().slug("documentacao-da-api-do-tabnews"),
+            )
+            .await;
+
+        assert!(matches!(response, Err(Error::Http { status: 400, .. })));
+    }
+
+    #[tokio::test]
+    async fn delete_content() {
+        let server = FakeServer::start();
+        let client = logged_client(&server, "fadiinho@tabnews.test").await;
+
+        let deleted = client
+            .posts_api
+            .delete_content("fadiinho", "tabnews-rs")
+            .await
+            .unwrap();
+
+        assert_eq!(deleted.status, Some(ContentStatus::Deleted));
+        assert!(deleted.deleted_at.is_some());
+
+        let details = client
+            .posts_api
+            .get_post_details("fadiinho", "tabnews-rs")
+            .await;
+        assert!(matches!(details, Err(Error::Http { status: 404, .. })));
+
+        let posts = client
+            .posts_api
+            .get_posts_by_user("fadiinho", None)
+            .await
+            .unwrap();
+        assert!(posts
+            .iter()
+            .all(|post| post.slug.as_deref() != Some("tabnews-rs")));
+
+        let deleted_again = client
+            .posts_api
+            .delete_content("fadiinho", "tabnews-rs")
+            .await;
+        assert!(matches!(
+            deleted_again,
+            Err(Error::Http { status: 404, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn unpublish_content() {
+        let server = FakeServer::start();
+        let client = logged_client(&server, "fadiinho@tabnews.test").await;
+
+        let draft = client
+            .posts_api
+            .unpublish_content("fadiinho", "tabnews-rs")
+            .await
+            .unwrap();
+
+        assert_eq!(draft.status, Some(ContentStatus::Draft));
+
+        let anonymous = TabnewsClient::default();
+        anonymous.http_client.set_host(server.url());
+
+        let details = anonymous
+            .posts_api
+            .get_post_details("fadiinho", "tabnews-rs")
+            .await;
+        assert!(matches!(details, Err(Error::Http { status: 404, .. })));
+    }
+
+    #[tokio::test]
+    async fn delete_content_sends_status() {
+        let (posts_api, transport) = mocked_posts_api();
+        transport
+            .push_json(StatusCode::OK, &json!({ "status": "deleted" }))
+            .unwrap();
+
+        posts_api
+            .delete_content("fadiinho", "tabnews-rs")
+            .await
+            .unwrap();
+
+        let request = &transport.requests()[0];
+        let body: serde_json::Value =
+            serde_json::from_slice(request.body.as_ref().unwrap()).unwrap();
+
+        assert_eq!(request.method, Method::PATCH);
+        assert_eq!(body, json!({ "status": "deleted" }));
+    }
+
+    #[test]
+    fn content_status() {
+        assert_eq!(Content::default().status, Some(ContentStatus::Published));
+        assert_eq!(
+            "draft".parse::<ContentStatus>().unwrap(),
+            ContentStatus::Draft
+        );
+        assert!(matches!(
+            "removed".parse::<ContentStatus>(),
+            Err(Error::InvalidInput(_))
+        ));
+
+        let content: Content = serde_json::from_value(json!({ "status": "deleted" })).unwrap();
+        assert_eq!(content.status, Some(ContentStatus::Deleted));
+
+        let contents: Vec<Content> = serde_json::from_value(json!([
+            { "slug": "bloqueado", "status": "firewall" },
+            { "slug": "publicado", "status": "published" },
+        ]))
+        .unwrap();
+        assert_eq!(contents[0].status, Some(ContentStatus::Unknown));
+        assert_eq!(contents[1].status, Some(ContentStatus::Published));
+    }
+
+    #[tokio::test]
+    async fn draft_workflow() {
+        let server = FakeServer::start();
+        let client = logged_client(&server, "fadiinho@tabnews.test").await;
+
+        let mut post = Content::default();
+        post.set_title("Rascunho").set_body("Ainda escrevendo");
+
+        let draft = client.posts_api.create_draft(post).await.unwrap();
+        let slug = draft.slug.unwrap();
+
+        assert_eq!(draft.status, Some(ContentStatus::Draft));
+        assert!(draft.published_at.is_none());
+
+        let drafts = client.posts_api.list_drafts(None).await.unwrap();
+        assert_eq!(drafts.len(), 1);
+        assert_eq!(drafts[0].slug.as_deref(), Some(slug.as_str()));
+
+        let posts = client
+            .posts_api
+            .get_posts_by_user("fadiinho", None)
+            .await
+            .unwrap();
+        assert!(posts
+            .iter()
+            .all(|p| p.slug.as_deref() != Some(slug.as_str())));
+
+        let preview = client
+            .posts_api
+            .preview_draft("fadiinho", &slug)
+            .await
+            .unwrap();
+        assert_eq!(preview.body.as_deref(), Some("Ainda escrevendo"));
+
+        let published = client
+            .posts_api
+            .publish_draft("fadiinho", &slug)
+            .await
+            .unwrap();
+        assert_eq!(published.status, Some(ContentStatus::Published));
+        assert_eq!(published.slug.as_deref(), Some(slug.as_str()));
+        assert!(published.published_at.is_some());
+
+        assert!(client.posts_api.list_drafts(None).await.unwrap().is_empty());
+
+        let preview = client.posts_api.preview_draft("fadiinho", &slug).await;
+        assert!(matches!(preview, Err(Error::InvalidInput(_))));
+    }
+
+    #[tokio::test]
+    async fn drafts_are_private() {
+        let server = FakeServer::start();
+        let owner = logged_client(&server, "fadiinho@tabnews.test").await;
+
+        let mut post = Content::default();
+        post.set_title("Segredo").set_body("Ninguém pode ver");
+        let slug = owner
+            .posts_api
+            .create_draft(post)
+            .await
+            .unwrap()
+            .slug
+            .unwrap();
+
+        let other = logged_client(&server, "gabriel@tabnews.test").await;
+
+        let preview = other.posts_api.preview_draft("fadiinho", &slug).await;
+        assert!(matches!(preview, Err(Error::Http { status: 404, .. })));
+
+        let drafts = other.posts_api.list_drafts(None).await.unwrap();
+        assert!(drafts.is_empty());
+    }
+
+    #[tokio::test]
+    async fn list_drafts_without_login() {
+        let (posts_api, _server) = fake_posts_api();
+
+        let response = posts_api.list_drafts(None).await;
+
+        assert!(matches!(response, Err(Error::NotLoggedIn)));
+    }
+
+    #[tokio::test]
+    async fn list_drafts_sends_status() {
+        let transport = Arc::new(MockTransport::default());
+        let client =
+            HttpClient::with_transport("http://tabnews.test/api/v1".to_owned(), transport.clone());
+        client.set_session_token("token").unwrap();
+        let posts_api = PostsApi::new(Arc::new(client));
+
+        transport
+            .push_json(
+                StatusCode::OK,
+                &json!({
+                    "id": "00000000-0000-4000-8000-000000000002",
+                    "username": "fadiinho",
+                    "features": [],
+                    "tabcoins": 0,
+                    "tabcash": 0,
+                    "created_at": "2022-05-01T00:00:00.000Z",
+                    "updated_at": "2022-05-01T00:00:00.000Z"
+                }),
+            )
+            .unwrap();
+        transport.push_json(StatusCode::OK, &json!([])).unwrap();
+
+        let params = ContentParams::builder().per_page(10).build().unwrap();
+        posts_api.list_drafts(Some(params)).await.unwrap();
+
+        let request = &transport.requests()[1];
+        assert_eq!(request.path(), "/api/v1/contents/fadiinho");
+        assert_eq!(request.query(), Some("per_page=10&status=draft"));
+    }
+
+    #[tokio::test]
+    async fn upvote_without_login() {
+        let (posts_api, _server) = fake_posts_api();
+
+        let response = posts_api.upvote("fadiinho", "tabnews-rs").await;
+
+        assert!(matches!(response, Err(Error::Http { status: 403, .. })))
+    }
+
     #[tokio::test]
     async fn get_posts_rss() {
-        let posts_api = PostsApi::default();
+        let (posts_api, _server) = fake_posts_api();
 
         let response = posts_api.get_rss().await.unwrap();
 
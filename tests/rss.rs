@@ -0,0 +1,204 @@
+#[cfg(test)]
+mod rss_tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use tabnews::models::rss::RssFeed;
+    use tabnews::testing::FakeServer;
+    use tabnews::{Error, TabnewsClient};
+
+    fn feed(items: &str) -> String {
+        format!(
+            r#"<?xml version="1.0" encoding="utf-8"?>
+            <rss version="2.0" xmlns:dc="http://purl.org/dc/elements/1.1/">
+              <channel>
+                <title>TabNews</title>
+                <link>https://www.tabnews.com.br</link>
+                <description>Conteúdos para quem trabalha com Programação e Tecnologia</description>
+                <language>pt</language>
+                <lastBuildDate>Sun, 01 Jan 2023 00:00:00 GMT</lastBuildDate>
+                {}
+              </channel>
+            </rss>"#,
+            items
+        )
+    }
+
+    #[tokio::test]
+    async fn get_rss_feed() {
+        let server = FakeServer::start();
+        let client = TabnewsClient::default();
+        client.http_client.set_host(server.url());
+
+        let feed = client.posts_api.get_rss_feed().await.unwrap();
+
+        assert_eq!(feed.title.as_deref(), Some("TabNews"));
+        assert!(!feed.items.is_empty());
+
+        for item in &feed.items {
+            assert!(item.title.is_some());
+            assert!(item.pub_date.is_some());
+            assert_eq!(item.link, item.guid);
+
+            let (username, slug) = item.username_and_slug().unwrap();
+            assert_eq!(item.author.as_deref(), Some(username));
+
+            let post = client
+                .posts_api
+                .get_post_details(username, slug)
+                .await
+                .unwrap();
+            assert_eq!(post.title, item.title);
+        }
+    }
+
+    #[test]
+    fn channel_metadata() {
+        let feed: RssFeed = feed("").parse().unwrap();
+
+        assert_eq!(feed.title.as_deref(), Some("TabNews"));
+        assert_eq!(feed.link.as_deref(), Some("https://www.tabnews.com.br"));
+        assert_eq!(feed.language.as_deref(), Some("pt"));
+        assert_eq!(
+            feed.last_build_date,
+            Some(UNIX_EPOCH + Duration::from_secs(1_672_531_200))
+        );
+        assert!(feed.items.is_empty());
+    }
+
+    #[test]
+    fn item_fields() {
+        let feed: RssFeed = feed(
+            r#"<item>
+                <title><![CDATA[Pergunta & resposta]]></title>
+                <link>https://www.tabnews.com.br/fadiinho/pergunta?utm=rss</link>
+                <guid isPermaLink="true">https://www.tabnews.com.br/fadiinho/pergunta</guid>
+                <pubDate>Sat, 31 Dec 2022 21:00:00 -0300</pubDate>
+                <dc:creator>fadiinho</dc:creator>
+                <description>Um &lt;b&gt;resumo&lt;/b&gt;</description>
+                <enclosure url="https://www.tabnews.com.br/image.png" type="image/png" />
+            </item>"#,
+        )
+        .parse()
+        .unwrap();
+
+        let item = &feed.items[0];
+
+        assert_eq!(item.title.as_deref(), Some("Pergunta & resposta"));
+        assert_eq!(item.author.as_deref(), Some("fadiinho"));
+        assert_eq!(item.description.as_deref(), Some("Um <b>resumo</b>"));
+        assert_eq!(
+            item.pub_date,
+            Some(UNIX_EPOCH + Duration::from_secs(1_672_531_200))
+        );
+        assert_eq!(item.username_and_slug(), Some(("fadiinho", "pergunta")));
+    }
+
+    #[test]
+    fn malformed_entries() {
+        let feed: RssFeed = feed(
+            r#"<item>
+                <title>Sem data nem link</title>
+                <pubDate>ontem</pubDate>
+            </item>
+            <item>
+                <title>Link fora do padrão</title>
+                <link>https://www.tabnews.com.br/fadiinho</link>
+                <guid isPermaLink="false">fadiinho/sem-dominio</guid>
+                <pubDate>Sun, 32 Jan 2023 00:00:00 GMT</pubDate>
+            </item>
+            <item>
+                <guid>https://www.tabnews.com.br/fadiinho/so-guid</guid>
+                <pubDate>1 Jan 23 00:00 UT</pubDate>
+                <description>   </description>
+            </item>"#,
+        )
+        .parse()
+        .unwrap();
+
+        assert_eq!(feed.items.len(), 3);
+
+        assert_eq!(feed.items[0].pub_date, None);
+        assert_eq!(feed.items[0].username_and_slug(), None);
+
+        assert_eq!(feed.items[1].pub_date, None);
+        assert_eq!(feed.items[1].username_and_slug(), None);
+
+        assert_eq!(
+            feed.items[2].pub_date,
+            Some(UNIX_EPOCH + Duration::from_secs(1_672_531_200))
+        );
+        assert_eq!(feed.items[2].description, None);
+        assert_eq!(
+            feed.items[2].username_and_slug(),
+            Some(("fadiinho", "so-guid"))
+        );
+    }
+
+    #[test]
+    fn unknown_entities() {
+        let feed: RssFeed = feed(
+            r#"<item>
+                <title>A &nbsp;&eacute; B</title>
+            </item>
+            <item>
+                <title>Entidade &inexistente; aqui</title>
+                <link>https://www.tabnews.com.br/fadiinho/entidade</link>
+            </item>
+            <item>
+                <title>Normal &amp; certo</title>
+            </item>"#,
+        )
+        .parse()
+        .unwrap();
+
+        assert_eq!(feed.items.len(), 3);
+        assert_eq!(feed.items[0].title.as_deref(), Some("A \u{a0}é B"));
+        assert_eq!(
+            feed.items[1].title.as_deref(),
+            Some("Entidade &inexistente; aqui")
+        );
+        assert_eq!(
+            feed.items[1].username_and_slug(),
+            Some(("fadiinho", "entidade"))
+        );
+        assert_eq!(feed.items[2].title.as_deref(), Some("Normal & certo"));
+    }
+
+    #[test]
+    fn out_of_range_dates() {
+        let dates = [
+            "Fri, 01 Jan 9223372036854775807 00:00:00 GMT",
+            "Sat, 01 Jan 10000 00:00:00 GMT",
+            "Mon, 01 Jan 0 00:00:00 GMT",
+            "Fri, 31 Feb 2023 00:00:00 GMT",
+            "Wed, 29 Feb 2023 00:00:00 GMT",
+            "Mon, 31 Apr 2023 00:00:00 GMT",
+            "Thu, 29 Feb 2024 00:00:00 GMT",
+        ];
+        let items: String = dates
+            .iter()
+            .map(|date| format!("<item><pubDate>{}</pubDate></item>", date))
+            .collect();
+
+        let feed: RssFeed = feed(&items).parse().unwrap();
+        let pub_dates: Vec<_> = feed.items.iter().map(|item| item.pub_date).collect();
+
+        assert_eq!(pub_dates[..6], [None; 6]);
+        assert_eq!(
+            pub_dates[6],
+            Some(UNIX_EPOCH + Duration::from_secs(1_709_164_800))
+        );
+    }
+
+    #[test]
+    fn invalid_documents() {
+        let unclosed = "<rss><channel><title>TabNews</title>";
+        assert!(matches!(unclosed.parse::<RssFeed>(), Err(Error::Feed(_))));
+
+        let mismatched = "<rss><channel><item></channel></rss>";
+        assert!(matches!(mismatched.parse::<RssFeed>(), Err(Error::Feed(_))));
+
+        let html = "<html><body>Manutenção</body></html>";
+        assert!(matches!(html.parse::<RssFeed>(), Err(Error::Feed(_))));
+    }
+}
@@ -0,0 +1,205 @@
+#[cfg(test)]
+mod text_tests {
+    use std::time::{Duration, Instant};
+
+    use tabnews::models::content::Content;
+    use tabnews::testing::FakeServer;
+    use tabnews::TabnewsClient;
+
+    fn content(body: &str) -> Content {
+        let mut content = Content::default();
+        content.set_body(body);
+
+        content
+    }
+
+    #[test]
+    fn plain_text() {
+        let content = content(
+            "# Título ##\n\
+             \n\
+             Texto com **negrito**, _itálico_, ~~riscado~~ e `código`.\n\
+             Continua na linha de baixo com snake_case e 2 * 3.\n\
+             \n\
+             > Citação com [link](https://example.com \"título\") e \\*asteriscos\\*\n\
+             \n\
+             - [x] Tarefa feita\n\
+             1. Primeiro item\n\
+             \n\
+             ---\n\
+             \n\
+             | Método | Rota |\n\
+             | --- | --- |\n\
+             | GET | /contents |\n\
+             \n\
+             ```rust\n\
+             fn main() {}\n\
+             ```\n\
+             \n\
+             Custa R$ 10 e a área é $\\pi r^2$. <br/> Fim &amp; até logo\n\
+             \n\
+             [ref]: https://example.com/ref",
+        );
+
+        assert_eq!(
+            content.plain_text(),
+            "Título\n\
+             \n\
+             Texto com negrito, itálico, riscado e código.\n\
+             Continua na linha de baixo com snake_case e 2 * 3.\n\
+             \n\
+             Citação com link e *asteriscos*\n\
+             \n\
+             Tarefa feita\n\
+             Primeiro item\n\
+             \n\
+             Método Rota\n\
+             GET /contents\n\
+             \n\
+             fn main() {}\n\
+             \n\
+             Custa R$ 10 e a área é \\pi r^2. Fim & até logo"
+        );
+    }
+
+    #[test]
+    fn excerpt_at_word_boundary() {
+        let post = content("Um **wrapper** pra API do [TabNews](https://www.tabnews.com.br)");
+
+        assert_eq!(post.excerpt(100), "Um wrapper pra API do TabNews");
+        assert_eq!(post.excerpt(29), "Um wrapper pra API do TabNews");
+        assert_eq!(post.excerpt(28), "Um wrapper pra API do…");
+        assert_eq!(post.excerpt(3), "Um…");
+        assert_eq!(post.excerpt(2), "U…");
+        assert_eq!(post.excerpt(0), "");
+        assert!(post.excerpt(20).chars().count() <= 20);
+
+        let accents = content("Programação é ótimo");
+        assert_eq!(accents.excerpt(13), "Programação…");
+    }
+
+    #[test]
+    fn words_and_reading_time() {
+        let empty = Content::default();
+        assert_eq!(empty.word_count(), 0);
+        assert_eq!(empty.reading_time(), Duration::ZERO);
+
+        let short = content("# Olá\n\nUm **wrapper** — pra [API](https://example.com)!");
+        assert_eq!(short.word_count(), 5);
+        assert_eq!(short.reading_time(), Duration::from_secs(2));
+
+        let long = content(&"palavra ".repeat(500));
+        assert_eq!(long.word_count(), 500);
+        assert_eq!(long.reading_time(), Duration::from_secs(150));
+    }
+
+    #[test]
+    fn first_image_and_link() {
+        let post = content(
+            "Use `https://no-codigo.example` e veja:\n\
+             \n\
+             [![Logo](https://example.com/logo.png)](https://example.com/projeto)\n\
+             \n\
+             <img alt=\"capa\" src='https://example.com/capa.png'>",
+        );
+
+        assert_eq!(
+            post.first_image().as_deref(),
+            Some("https://example.com/logo.png")
+        );
+        assert_eq!(
+            post.first_link().as_deref(),
+            Some("https://example.com/projeto")
+        );
+
+        let html = content("<a href=\"https://example.com/a\">a</a> <img src=x.png>");
+        assert_eq!(html.first_link().as_deref(), Some("https://example.com/a"));
+        assert_eq!(html.first_image().as_deref(), Some("x.png"));
+
+        let bare = content("Veja em https://example.com/bare_link. Ou <https://example.com/auto>");
+        assert_eq!(
+            bare.first_link().as_deref(),
+            Some("https://example.com/bare_link")
+        );
+        assert_eq!(
+            bare.plain_text(),
+            "Veja em https://example.com/bare_link. Ou https://example.com/auto"
+        );
+        assert_eq!(bare.first_image(), None);
+
+        assert_eq!(Content::default().first_link(), None);
+    }
+
+    #[test]
+    fn deeply_nested_links() {
+        let depth = 20_000;
+        let post = content(&format!(
+            "{}texto{}\n\n{}imagem{}",
+            "[".repeat(depth),
+            "](a)".repeat(depth),
+            "![".repeat(depth),
+            "](b.png)".repeat(depth)
+        ));
+
+        // The stack of a tokio worker
+        let handle = std::thread::Builder::new()
+            .stack_size(2 * 1024 * 1024)
+            .spawn(move || {
+                assert!(post.plain_text().contains("texto"));
+                assert_eq!(post.first_link().as_deref(), Some("a"));
+                assert_eq!(post.first_image().as_deref(), Some("b.png"));
+                assert!(post.excerpt(280).chars().count() <= 280);
+            })
+            .unwrap();
+
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn long_runs_of_brackets() {
+        let started = Instant::now();
+        let post = content(&"[".repeat(50_000));
+
+        assert_eq!(post.plain_text(), "[".repeat(50_000));
+        assert_eq!(post.excerpt(5), "[[[[…");
+        assert_eq!(post.first_link(), None);
+
+        let post = content(&"[a ".repeat(50_000));
+        assert_eq!(post.word_count(), 50_000);
+
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn posts_and_comments() {
+        let server = FakeServer::start();
+        let client = TabnewsClient::default();
+        client.http_client.set_host(server.url());
+
+        let post = client
+            .posts_api
+            .get_post_details("fadiinho", "tabnews-rs")
+            .await
+            .unwrap();
+
+        assert_eq!(post.plain_text(), "Um wrapper pra API do TabNews.");
+        assert_eq!(
+            post.first_link().as_deref(),
+            Some("https://www.tabnews.com.br")
+        );
+        assert_eq!(post.excerpt(15), "Um wrapper pra…");
+
+        let comments = client
+            .posts_api
+            .get_post_comments("GabrielSozinho", "documentacao-da-api-do-tabnews")
+            .await
+            .unwrap();
+
+        assert!(!comments.is_empty());
+        for comment in &comments {
+            assert!(comment.word_count() > 0);
+            assert!(comment.reading_time() > Duration::ZERO);
+            assert!(!comment.excerpt(10).contains('*'));
+        }
+    }
+}
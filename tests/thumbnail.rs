@@ -0,0 +1,200 @@
+#[cfg(test)]
+mod thumbnail_tests {
+    use std::path::PathBuf;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use futures::TryStreamExt;
+    use tabnews::internal::http_client::middleware::{Middleware, Next};
+    use tabnews::internal::http_client::transport::BoxFuture;
+    use tabnews::internal::http_client::{HttpRequest, HttpResponse};
+    use tabnews::models::content::Content;
+    use tabnews::models::thumbnail::Thumbnail;
+    use tabnews::testing::FakeServer;
+    use tabnews::{Error, TabnewsClient};
+
+    const DOCUMENTATION: (&str, &str) = ("GabrielSozinho", "documentacao-da-api-do-tabnews");
+
+    /// Keeps every request in flight for a while, recording how many
+    /// were in flight at the same time
+    #[derive(Default)]
+    struct InFlight {
+        current: AtomicUsize,
+        max: AtomicUsize,
+    }
+
+    impl Middleware for InFlight {
+        fn handle<'a>(
+            &'a self,
+            request: HttpRequest,
+            next: Next<'a>,
+        ) -> BoxFuture<'a, tabnews::Result<HttpResponse>> {
+            Box::pin(async move {
+                let current = self.current.fetch_add(1, Ordering::SeqCst) + 1;
+                self.max.fetch_max(current, Ordering::SeqCst);
+
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                let response = next.run(request).await;
+
+                self.current.fetch_sub(1, Ordering::SeqCst);
+
+                response
+            })
+        }
+    }
+
+    fn fake_client() -> (TabnewsClient, FakeServer) {
+        let server = FakeServer::start();
+        let client = TabnewsClient::default();
+        client.http_client.set_host(server.url());
+
+        (client, server)
+    }
+
+    fn temp_file(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("tabnews-{}-{}", std::process::id(), name))
+    }
+
+    /// Smallest JPEG header with a 64x32 baseline frame
+    fn jpeg() -> Vec<u8> {
+        let mut bytes = vec![0xff, 0xd8];
+        bytes.extend([0xff, 0xe0, 0x00, 0x10]);
+        bytes.extend(b"JFIF\0");
+        bytes.extend([0x01, 0x01, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00]);
+        bytes.extend([0xff, 0xff, 0xc0, 0x00, 0x11, 0x08, 0x00, 0x20, 0x00, 0x40]);
+        bytes.extend([0x03, 0x01, 0x22, 0x00, 0x02, 0x11, 0x01, 0x03, 0x11, 0x01]);
+        bytes.extend([0xff, 0xd9]);
+
+        bytes
+    }
+
+    #[tokio::test]
+    async fn get_post_thumbnail() {
+        let (client, _server) = fake_client();
+
+        let thumbnail = client
+            .posts_api
+            .get_post_thumbnail(DOCUMENTATION.0, DOCUMENTATION.1)
+            .await
+            .unwrap();
+
+        assert_eq!(thumbnail.content_type(), Some("image/png"));
+        assert_eq!(thumbnail.dimensions(), Some((1, 1)));
+
+        let path = temp_file("thumbnail.png");
+        thumbnail.save_to(&path).unwrap();
+        let saved = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(saved, thumbnail.bytes());
+    }
+
+    #[test]
+    fn jpeg_dimensions() {
+        let thumbnail = Thumbnail::new(jpeg(), Some("image/jpeg".to_owned()));
+
+        assert_eq!(thumbnail.dimensions(), Some((64, 32)));
+    }
+
+    #[test]
+    fn unknown_or_truncated_images() {
+        assert_eq!(Thumbnail::new(b"GIF89a".to_vec(), None).dimensions(), None);
+        assert_eq!(
+            Thumbnail::new(b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR\0\0".to_vec(), None).dimensions(),
+            None
+        );
+        assert_eq!(
+            Thumbnail::new(jpeg()[..20].to_vec(), None).dimensions(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn stream_post_thumbnail() {
+        let (client, _server) = fake_client();
+
+        let expected = client
+            .posts_api
+            .get_post_thumbnail(DOCUMENTATION.0, DOCUMENTATION.1)
+            .await
+            .unwrap();
+
+        let stream = client
+            .posts_api
+            .stream_post_thumbnail(DOCUMENTATION.0, DOCUMENTATION.1)
+            .await
+            .unwrap();
+
+        assert_eq!(stream.content_type(), Some("image/png"));
+        assert_eq!(stream.content_length(), Some(expected.bytes().len() as u64));
+
+        let chunks: Vec<Vec<u8>> = stream.try_collect().await.unwrap();
+        assert_eq!(chunks.concat(), expected.bytes());
+
+        let path = temp_file("streamed.png");
+        let written = client
+            .posts_api
+            .stream_post_thumbnail(DOCUMENTATION.0, DOCUMENTATION.1)
+            .await
+            .unwrap()
+            .save_to(&path)
+            .await
+            .unwrap();
+        let saved = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(written, saved.len() as u64);
+        assert_eq!(saved, expected.bytes());
+    }
+
+    #[tokio::test]
+    async fn stream_post_thumbnail_not_found() {
+        let (client, _server) = fake_client();
+
+        let response = client
+            .posts_api
+            .stream_post_thumbnail("fadiinho", "nao-existe")
+            .await;
+
+        assert!(matches!(response, Err(Error::Http { status: 404, .. })));
+    }
+
+    #[tokio::test]
+    async fn get_thumbnails_with_bounded_concurrency() {
+        let (client, server) = fake_client();
+
+        {
+            let mut state = server.state();
+            let owner = state.user_by_username("fadiinho").unwrap().id.clone();
+
+            for index in 0..6 {
+                let slug = format!("post-{}", index);
+                state.add_content(&owner, None, Some(&slug), Some(&slug), "corpo");
+            }
+        }
+
+        let in_flight = Arc::new(InFlight::default());
+        client.http_client.add_middleware(Arc::clone(&in_flight));
+
+        let mut posts: Vec<Content> = client
+            .posts_api
+            .get_posts_by_user("fadiinho", None)
+            .await
+            .unwrap()
+            .into_items();
+        posts.push(Content::default());
+
+        let thumbnails = client.posts_api.get_thumbnails(&posts, 2).await;
+
+        assert_eq!(thumbnails.len(), posts.len());
+        assert!(thumbnails[..posts.len() - 1]
+            .iter()
+            .all(|thumbnail| thumbnail.as_ref().unwrap().dimensions() == Some((1, 1))));
+        assert!(matches!(
+            thumbnails.last(),
+            Some(Err(Error::InvalidInput(_)))
+        ));
+        assert_eq!(in_flight.max.load(Ordering::SeqCst), 2);
+    }
+}
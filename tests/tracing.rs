@@ -0,0 +1,180 @@
+#[cfg(test)]
+mod tracing_tests {
+    use std::io;
+    use std::sync::{Arc, Mutex, PoisonError};
+    use std::time::Duration;
+
+    use reqwest::{
+        header::{HeaderValue, COOKIE},
+        Method, StatusCode,
+    };
+    use serde_json::json;
+    use tabnews::internal::http_client::{
+        mock::MockTransport, retry::RetryPolicy, HttpClient, HttpRequest,
+    };
+    use tabnews::testing::{FakeServer, SEEDED_PASSWORD};
+    use tabnews::TabnewsClient;
+    use tracing::subscriber::DefaultGuard;
+
+    #[derive(Clone, Default)]
+    struct Logs(Arc<Mutex<Vec<u8>>>);
+
+    impl Logs {
+        fn capture(&self) -> DefaultGuard {
+            let logs = self.clone();
+            let subscriber = tracing_subscriber::fmt()
+                .with_max_level(tracing::Level::TRACE)
+                .with_ansi(false)
+                .with_writer(move || logs.clone())
+                .finish();
+
+            tracing::subscriber::set_default(subscriber)
+        }
+
+        fn text(&self) -> String {
+            let logs = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+
+            String::from_utf8_lossy(&logs).into_owned()
+        }
+    }
+
+    impl io::Write for Logs {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .extend_from_slice(buf);
+
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn fake_client() -> (TabnewsClient, FakeServer) {
+        let server = FakeServer::start();
+        let client = TabnewsClient::default();
+        client.http_client.set_host(server.url());
+
+        (client, server)
+    }
+
+    #[tokio::test]
+    async fn request_span_has_status_and_request_id() {
+        let logs = Logs::default();
+        let _guard = logs.capture();
+        let (client, _server) = fake_client();
+
+        client.users_api.get_user("fadiinho").await.unwrap();
+
+        let text = logs.text();
+
+        assert!(text.contains("tabnews.request"), "{}", text);
+        assert!(text.contains("method=GET"), "{}", text);
+        assert!(text.contains("path=/users/fadiinho"), "{}", text);
+        assert!(text.contains("status=200"), "{}", text);
+        assert!(
+            text.contains("request_id=00000000-0000-4000-9000-"),
+            "{}",
+            text
+        );
+    }
+
+    #[tokio::test]
+    async fn failed_request_is_logged() {
+        let logs = Logs::default();
+        let _guard = logs.capture();
+        let (client, _server) = fake_client();
+
+        client.users_api.get_user("missing").await.unwrap_err();
+
+        let text = logs.text();
+
+        assert!(text.contains("status=404"), "{}", text);
+        assert!(text.contains("request failed"), "{}", text);
+    }
+
+    #[tokio::test]
+    async fn retries_are_recorded() {
+        let logs = Logs::default();
+        let _guard = logs.capture();
+
+        let transport = Arc::new(MockTransport::default());
+        transport
+            .push_json(StatusCode::SERVICE_UNAVAILABLE, &json!({}))
+            .unwrap();
+        transport.push_json(StatusCode::OK, &json!([])).unwrap();
+
+        let client =
+            HttpClient::with_transport("http://tabnews.test/api/v1".to_owned(), transport.clone());
+        client.set_retry_policy(RetryPolicy::default().base_delay(Duration::from_millis(1)));
+
+        client.get("/contents".to_owned()).await.unwrap();
+
+        let text = logs.text();
+
+        assert!(text.contains("retrying request"), "{}", text);
+        assert!(text.contains("retries=1"), "{}", text);
+    }
+
+    #[tokio::test]
+    async fn login_is_logged_without_secrets() {
+        let logs = Logs::default();
+        let _guard = logs.capture();
+        let (client, _server) = fake_client();
+
+        let session = client
+            .auth_api
+            .login(
+                "fadiinho@tabnews.test".to_owned(),
+                SEEDED_PASSWORD.to_owned(),
+            )
+            .await
+            .unwrap();
+        client.user_api.get_current_user().await.unwrap();
+        client.http_client.clear_session();
+
+        let text = logs.text();
+
+        assert!(text.contains("logged in"), "{}", text);
+        assert!(text.contains("session cleared"), "{}", text);
+        assert!(!text.contains(&session.token), "{}", text);
+        assert!(!text.contains(SEEDED_PASSWORD), "{}", text);
+        assert!(!text.contains("fadiinho@tabnews.test"), "{}", text);
+        assert!(!client.is_logged());
+    }
+
+    #[test]
+    fn debug_output_is_redacted() {
+        let request = HttpRequest::new(Method::POST, "http://tabnews.test/sessions".to_owned())
+            .header(COOKIE, HeaderValue::from_static("session_id=secret-token"))
+            .json(&json!({ "email": "email@example.com", "password": "secret-password" }))
+            .unwrap();
+
+        let debug = format!("{:?}", request);
+
+        assert!(debug.contains("email@example.com"));
+        assert!(!debug.contains("secret-token"));
+        assert!(!debug.contains("secret-password"));
+    }
+
+    #[test]
+    fn trace_configuration() {
+        let logs = Logs::default();
+        let _guard = logs.capture();
+
+        let client = TabnewsClient::default();
+        client.trace_configuration();
+
+        let text = logs.text();
+
+        assert!(
+            text.contains("host=https://www.tabnews.com.br/api/v1"),
+            "{}",
+            text
+        );
+        assert!(text.contains("logged=false"), "{}", text);
+    }
+}
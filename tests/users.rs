@@ -1,18 +1,30 @@
 #[cfg(test)]
 mod users_tests {
+    use std::sync::Arc;
+
+    use tabnews::internal::http_client::HttpClient;
     use tabnews::internal::users::UsersApi;
+    use tabnews::testing::FakeServer;
+
+    fn fake_users_api() -> (UsersApi, FakeServer) {
+        let server = FakeServer::start();
+        let client = Arc::new(HttpClient::default());
+        client.set_host(server.url());
+
+        (UsersApi::new(client), server)
+    }
 
     #[tokio::test]
     #[should_panic]
     async fn list_all_users() {
-        let users_api = UsersApi::default();
+        let (users_api, _server) = fake_users_api();
 
         users_api.list_all_users().await.unwrap();
     }
 
     #[tokio::test]
     async fn get_user() {
-        let users_api = UsersApi::default();
+        let (users_api, _server) = fake_users_api();
 
         let response = users_api.get_user("fadiinho").await.unwrap();
 